@@ -0,0 +1,53 @@
+/// A built-in location for astronomical calculations
+#[derive(Debug, Clone, Copy)]
+pub struct City {
+    pub name: &'static str,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub timezone: &'static str,
+}
+
+const fn city(name: &'static str, latitude: f64, longitude: f64, timezone: &'static str) -> City {
+    City {
+        name,
+        latitude,
+        longitude,
+        timezone,
+    }
+}
+
+/// Major cities plus a few high-latitude sites for polar day/night
+pub const CITIES: [City; 26] = [
+    city("New York", 40.7128, -74.0060, "America/New_York"),
+    city("Chicago", 41.8781, -87.6298, "America/Chicago"),
+    city("Denver", 39.7392, -104.9903, "America/Denver"),
+    city("Los Angeles", 34.0522, -118.2437, "America/Los_Angeles"),
+    city("Anchorage", 61.2181, -149.9003, "America/Anchorage"),
+    city("Utqiagvik", 71.2906, -156.7886, "America/Anchorage"),
+    city("Honolulu", 21.3069, -157.8583, "Pacific/Honolulu"),
+    city("Toronto", 43.6532, -79.3832, "America/Toronto"),
+    city("Mexico City", 19.4326, -99.1332, "America/Mexico_City"),
+    city("Sao Paulo", -23.5505, -46.6333, "America/Sao_Paulo"),
+    city("London", 51.5074, -0.1278, "Europe/London"),
+    city("Paris", 48.8566, 2.3522, "Europe/Paris"),
+    city("Berlin", 52.5200, 13.4050, "Europe/Berlin"),
+    city("Moscow", 55.7558, 37.6173, "Europe/Moscow"),
+    city("Reykjavik", 64.1466, -21.9426, "Atlantic/Reykjavik"),
+    city("Tromso", 69.6492, 18.9553, "Europe/Oslo"),
+    city("Cairo", 30.0444, 31.2357, "Africa/Cairo"),
+    city("Johannesburg", -26.2041, 28.0473, "Africa/Johannesburg"),
+    city("Dubai", 25.2048, 55.2708, "Asia/Dubai"),
+    city("Mumbai", 19.0760, 72.8777, "Asia/Kolkata"),
+    city("Singapore", 1.3521, 103.8198, "Asia/Singapore"),
+    city("Hong Kong", 22.3193, 114.1694, "Asia/Hong_Kong"),
+    city("Tokyo", 35.6762, 139.6503, "Asia/Tokyo"),
+    city("Sydney", -33.8688, 151.2093, "Australia/Sydney"),
+    city("Auckland", -36.8485, 174.7633, "Pacific/Auckland"),
+    city("McMurdo", -77.8419, 166.6863, "Antarctica/McMurdo"),
+];
+
+/// Look up a built-in city by name, ignoring case
+pub fn find_city(name: &str) -> Option<&'static City> {
+    let name = name.trim();
+    CITIES.iter().find(|c| c.name.eq_ignore_ascii_case(name))
+}
@@ -0,0 +1,131 @@
+use chrono::NaiveDateTime;
+
+use super::moon::{phases_between, LunationArgs, Phase};
+
+/// Kind of eclipse
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EclipseKind {
+    Solar,
+    Lunar,
+}
+
+/// An eclipse found at a new or full moon
+#[derive(Debug, Clone)]
+pub struct Eclipse {
+    pub kind: EclipseKind,
+    /// "total", "annular", "hybrid" or "partial" for solar eclipses;
+    /// "total", "partial" or "penumbral" for lunar eclipses
+    pub eclipse_type: &'static str,
+    /// Approximate instant of greatest eclipse, in UTC
+    pub instant: NaiveDateTime,
+    /// Umbral magnitude for lunar eclipses
+    pub magnitude: Option<f64>,
+}
+
+/// Classify the syzygy of lunation `k` (Meeus, Astronomical Algorithms ch. 54)
+fn classify(k: f64, kind: EclipseKind) -> Option<(&'static str, Option<f64>)> {
+    let LunationArgs { e, m, mp, f, omega, .. } = LunationArgs::new(k);
+
+    if f.sin().abs() > 0.36 {
+        return None;
+    }
+
+    let f1 = f - 0.02665_f64.to_radians() * omega.sin();
+    let p = 0.2070 * e * m.sin() + 0.0024 * e * (2.0 * m).sin() - 0.0392 * mp.sin()
+        + 0.0116 * (2.0 * mp).sin()
+        - 0.0073 * e * (mp + m).sin()
+        + 0.0067 * e * (mp - m).sin()
+        + 0.0118 * (2.0 * f1).sin();
+    let q = 5.2207 - 0.0048 * e * m.cos() + 0.0020 * e * (2.0 * m).cos() - 0.3299 * mp.cos()
+        - 0.0060 * e * (mp + m).cos()
+        + 0.0041 * e * (mp - m).cos();
+    let w = f1.cos().abs();
+    let gamma = (p * f1.cos() + q * f1.sin()) * (1.0 - 0.0048 * w);
+    let u = 0.0059 + 0.0046 * e * m.cos() - 0.0182 * mp.cos() + 0.0004 * (2.0 * mp).cos()
+        - 0.0005 * (m + mp).cos();
+
+    match kind {
+        EclipseKind::Solar => {
+            let g = gamma.abs();
+            if g > 1.5433 + u {
+                return None;
+            }
+            let eclipse_type = if g > 0.9972 + u.abs() {
+                "partial"
+            } else if u < 0.0 {
+                "total"
+            } else if u > 0.0047 || u >= 0.00464 * (1.0 - gamma * gamma).max(0.0).sqrt() {
+                "annular"
+            } else {
+                "hybrid"
+            };
+            Some((eclipse_type, None))
+        }
+        EclipseKind::Lunar => {
+            let penumbral = (1.5573 + u - gamma.abs()) / 0.5450;
+            let umbral = (1.0128 - u - gamma.abs()) / 0.5450;
+            if penumbral < 0.0 {
+                return None;
+            }
+            let eclipse_type = if umbral >= 1.0 {
+                "total"
+            } else if umbral > 0.0 {
+                "partial"
+            } else {
+                "penumbral"
+            };
+            Some((eclipse_type, Some(umbral)))
+        }
+    }
+}
+
+/// Solar and lunar eclipses between two UTC instants, in order
+pub fn eclipses_between(from: NaiveDateTime, to: NaiveDateTime) -> Vec<Eclipse> {
+    let mut eclipses: Vec<Eclipse> = [(Phase::New, EclipseKind::Solar), (Phase::Full, EclipseKind::Lunar)]
+        .into_iter()
+        .flat_map(|(phase, kind)| {
+            phases_between(from, to, phase)
+                .into_iter()
+                .filter_map(move |(k, instant)| {
+                    let (eclipse_type, magnitude) = classify(k, kind)?;
+                    Some(Eclipse {
+                        kind,
+                        eclipse_type,
+                        instant,
+                        magnitude,
+                    })
+                })
+        })
+        .collect();
+
+    eclipses.sort_by_key(|e| e.instant);
+    eclipses
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn utc(y: i32, m: u32, d: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_eclipses_2026() {
+        let eclipses = eclipses_between(utc(2026, 1, 1), utc(2027, 1, 1));
+        let summary: Vec<(EclipseKind, &str, String)> = eclipses
+            .iter()
+            .map(|e| (e.kind, e.eclipse_type, e.instant.format("%m-%d").to_string()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (EclipseKind::Solar, "annular", "02-17".to_string()),
+                (EclipseKind::Lunar, "total", "03-03".to_string()),
+                (EclipseKind::Solar, "total", "08-12".to_string()),
+                (EclipseKind::Lunar, "partial", "08-28".to_string()),
+            ]
+        );
+    }
+}
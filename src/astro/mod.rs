@@ -0,0 +1,6 @@
+pub mod cities;
+pub mod eclipse;
+pub mod moon;
+pub mod seasons;
+pub mod sun;
+pub mod time;
@@ -0,0 +1,289 @@
+use chrono::NaiveDateTime;
+
+use super::time::{julian_day_utc, utc_from_jde};
+
+/// Principal lunar phases
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Phase {
+    New,
+    FirstQuarter,
+    Full,
+    LastQuarter,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 4] = [Self::New, Self::FirstQuarter, Self::Full, Self::LastQuarter];
+
+    /// Fraction of a lunation at which the phase occurs
+    fn offset(self) -> f64 {
+        match self {
+            Self::New => 0.0,
+            Self::FirstQuarter => 0.25,
+            Self::Full => 0.5,
+            Self::LastQuarter => 0.75,
+        }
+    }
+
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::New => "new_moon",
+            Self::FirstQuarter => "first_quarter",
+            Self::Full => "full_moon",
+            Self::LastQuarter => "last_quarter",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::New => "New Moon",
+            Self::FirstQuarter => "First Quarter",
+            Self::Full => "Full Moon",
+            Self::LastQuarter => "Last Quarter",
+        }
+    }
+}
+
+/// Mean synodic month in days
+pub const SYNODIC_MONTH: f64 = 29.530588861;
+
+/// Fundamental arguments for lunation `k` (Meeus, Astronomical Algorithms ch. 49)
+pub struct LunationArgs {
+    pub t: f64,
+    /// Eccentricity correction factor
+    pub e: f64,
+    /// Sun's mean anomaly (radians)
+    pub m: f64,
+    /// Moon's mean anomaly (radians)
+    pub mp: f64,
+    /// Moon's argument of latitude (radians)
+    pub f: f64,
+    /// Longitude of the ascending node (radians)
+    pub omega: f64,
+    /// Mean phase JDE
+    pub mean_jde: f64,
+}
+
+impl LunationArgs {
+    pub fn new(k: f64) -> Self {
+        let t = k / 1236.85;
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let t4 = t3 * t;
+
+        Self {
+            t,
+            e: 1.0 - 0.002516 * t - 0.0000074 * t2,
+            m: (2.5534 + 29.10535670 * k - 0.0000014 * t2 - 0.00000011 * t3).to_radians(),
+            mp: (201.5643 + 385.81693528 * k + 0.0107582 * t2 + 0.00001238 * t3
+                - 0.000000058 * t4)
+                .to_radians(),
+            f: (160.7108 + 390.67050284 * k - 0.0016118 * t2 - 0.00000227 * t3
+                + 0.000000011 * t4)
+                .to_radians(),
+            omega: (124.7746 - 1.56375588 * k + 0.0020672 * t2 + 0.00000215 * t3).to_radians(),
+            mean_jde: 2451550.09766 + SYNODIC_MONTH * k + 0.00015437 * t2 - 0.000000150 * t3
+                + 0.00000000073 * t4,
+        }
+    }
+}
+
+/// Lunation number of the given phase nearest a Julian day
+pub fn lunation_near(julian_day: f64, phase: Phase) -> f64 {
+    ((julian_day - 2451550.09766) / SYNODIC_MONTH - phase.offset()).round() + phase.offset()
+}
+
+/// JDE (TT) of a phase for lunation `k`, where `k` is an integer plus the
+/// phase offset
+pub fn phase_jde(k: f64, phase: Phase) -> f64 {
+    let LunationArgs { t, e, m, mp, f, omega, mean_jde } = LunationArgs::new(k);
+    let sin = f64::sin;
+
+    let correction = match phase {
+        Phase::New | Phase::Full => {
+            let (c1, c2, c3, c4, c5, c6, c7) = if phase == Phase::New {
+                (-0.40720, 0.17241, 0.01608, 0.01039, 0.00739, -0.00514, 0.00208)
+            } else {
+                (-0.40614, 0.17302, 0.01614, 0.01043, 0.00734, -0.00515, 0.00209)
+            };
+            c1 * sin(mp) + c2 * e * sin(m) + c3 * sin(2.0 * mp) + c4 * sin(2.0 * f)
+                + c5 * e * sin(mp - m)
+                + c6 * e * sin(mp + m)
+                + c7 * e * e * sin(2.0 * m)
+                - 0.00111 * sin(mp - 2.0 * f)
+                - 0.00057 * sin(mp + 2.0 * f)
+                + 0.00056 * e * sin(2.0 * mp + m)
+                - 0.00042 * sin(3.0 * mp)
+                + 0.00042 * e * sin(m + 2.0 * f)
+                + 0.00038 * e * sin(m - 2.0 * f)
+                - 0.00024 * e * sin(2.0 * mp - m)
+                - 0.00017 * sin(omega)
+                - 0.00007 * sin(mp + 2.0 * m)
+                + 0.00004 * sin(2.0 * mp - 2.0 * f)
+                + 0.00004 * sin(3.0 * m)
+                + 0.00003 * sin(mp + m - 2.0 * f)
+                + 0.00003 * sin(2.0 * mp + 2.0 * f)
+                - 0.00003 * sin(mp + m + 2.0 * f)
+                + 0.00003 * sin(mp - m + 2.0 * f)
+                - 0.00002 * sin(mp - m - 2.0 * f)
+                - 0.00002 * sin(3.0 * mp + m)
+                + 0.00002 * sin(4.0 * mp)
+        }
+        Phase::FirstQuarter | Phase::LastQuarter => {
+            let base = -0.62801 * sin(mp) + 0.17172 * e * sin(m) - 0.01183 * e * sin(mp + m)
+                + 0.00862 * sin(2.0 * mp)
+                + 0.00804 * sin(2.0 * f)
+                + 0.00454 * e * sin(mp - m)
+                + 0.00204 * e * e * sin(2.0 * m)
+                - 0.00180 * sin(mp - 2.0 * f)
+                - 0.00070 * sin(mp + 2.0 * f)
+                - 0.00040 * sin(3.0 * mp)
+                - 0.00034 * e * sin(2.0 * mp - m)
+                + 0.00032 * e * sin(m + 2.0 * f)
+                + 0.00032 * e * sin(m - 2.0 * f)
+                - 0.00028 * e * e * sin(mp + 2.0 * m)
+                + 0.00027 * e * sin(2.0 * mp + m)
+                - 0.00017 * sin(omega)
+                - 0.00005 * sin(mp - m - 2.0 * f)
+                + 0.00004 * sin(2.0 * mp + 2.0 * f)
+                - 0.00004 * sin(mp + m + 2.0 * f)
+                + 0.00004 * sin(mp - 2.0 * m)
+                + 0.00003 * sin(mp + m - 2.0 * f)
+                + 0.00003 * sin(3.0 * m)
+                + 0.00002 * sin(2.0 * mp - 2.0 * f)
+                + 0.00002 * sin(mp - m + 2.0 * f)
+                - 0.00002 * sin(3.0 * mp + m);
+            let w = 0.00306 - 0.00038 * e * m.cos() + 0.00026 * mp.cos()
+                - 0.00002 * (mp - m).cos()
+                + 0.00002 * (mp + m).cos()
+                + 0.00002 * (2.0 * f).cos();
+            if phase == Phase::FirstQuarter {
+                base + w
+            } else {
+                base - w
+            }
+        }
+    };
+
+    mean_jde + correction + planetary_correction(k, t)
+}
+
+/// Additional corrections from planetary arguments, common to all phases
+fn planetary_correction(k: f64, t: f64) -> f64 {
+    const TERMS: [(f64, f64, f64); 14] = [
+        (0.000325, 299.77, 0.107408),
+        (0.000165, 251.88, 0.016321),
+        (0.000164, 251.83, 26.651886),
+        (0.000126, 349.42, 36.412478),
+        (0.000110, 84.66, 18.206239),
+        (0.000062, 141.74, 53.303771),
+        (0.000060, 207.14, 2.453732),
+        (0.000056, 154.84, 7.306860),
+        (0.000047, 34.52, 27.261239),
+        (0.000042, 207.19, 0.121824),
+        (0.000040, 291.34, 1.844379),
+        (0.000037, 161.72, 24.198154),
+        (0.000035, 239.56, 25.513099),
+        (0.000023, 331.55, 3.592518),
+    ];
+
+    TERMS
+        .iter()
+        .enumerate()
+        .map(|(i, &(coefficient, base, rate))| {
+            // Only A1 has a T² term
+            let t_term = if i == 0 { -0.009173 * t * t } else { 0.0 };
+            coefficient * (base + rate * k + t_term).to_radians().sin()
+        })
+        .sum()
+}
+
+/// UTC instants of a phase between two instants, in order
+pub fn phases_between(
+    from: NaiveDateTime,
+    to: NaiveDateTime,
+    phase: Phase,
+) -> Vec<(f64, NaiveDateTime)> {
+    let mut k = lunation_near(julian_day_utc(from), phase) - 1.0;
+    let mut results = Vec::new();
+
+    while let Some(instant) = utc_from_jde(phase_jde(k, phase)).filter(|i| *i <= to) {
+        if instant >= from {
+            results.push((k, instant));
+        }
+        k += 1.0;
+    }
+
+    results
+}
+
+/// Illuminated fraction of the Moon's disc (0-1) and whether it is waxing,
+/// at a UTC instant (Meeus ch. 48, low-precision phase angle)
+pub fn illumination(datetime: NaiveDateTime) -> (f64, bool) {
+    let t = (julian_day_utc(datetime) - 2451545.0) / 36525.0;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let t4 = t3 * t;
+
+    let d = 297.8501921 + 445267.1114034 * t - 0.0018819 * t2 + t3 / 545868.0 - t4 / 113065000.0;
+    let m = 357.5291092 + 35999.0502909 * t - 0.0001536 * t2 + t3 / 24490000.0;
+    let mp = 134.9633964 + 477198.8675055 * t + 0.0087414 * t2 + t3 / 69699.0 - t4 / 14712000.0;
+
+    let (dr, mr, mpr) = (d.to_radians(), m.to_radians(), mp.to_radians());
+    let phase_angle = 180.0 - d - 6.289 * mpr.sin() + 2.100 * mr.sin()
+        - 1.274 * (2.0 * dr - mpr).sin()
+        - 0.658 * (2.0 * dr).sin()
+        - 0.214 * (2.0 * mpr).sin()
+        - 0.110 * dr.sin();
+
+    let fraction = (1.0 + phase_angle.to_radians().cos()) / 2.0;
+    (fraction, d.rem_euclid(360.0) < 180.0)
+}
+
+/// Descriptive phase name for an illuminated fraction
+pub fn phase_name(fraction: f64, waxing: bool) -> &'static str {
+    match (fraction, waxing) {
+        (f, _) if f < 0.02 => "New Moon",
+        (f, _) if f > 0.98 => "Full Moon",
+        (f, true) if (0.45..=0.55).contains(&f) => "First Quarter",
+        (f, false) if (0.45..=0.55).contains(&f) => "Last Quarter",
+        (f, true) if f < 0.45 => "Waxing Crescent",
+        (_, true) => "Waxing Gibbous",
+        (f, false) if f > 0.55 => "Waning Gibbous",
+        (_, false) => "Waning Crescent",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn utc(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, mo, d).unwrap().and_hms_opt(h, mi, 0).unwrap()
+    }
+
+    #[test]
+    fn test_meeus_new_moon_example() {
+        // Meeus example 49.a: 1977 February 18, 3h37m42s TD
+        let jde = phase_jde(-283.0, Phase::New);
+        assert!((jde - 2443192.65118).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_full_moons_2026() {
+        let fulls = phases_between(utc(2026, 1, 1, 0, 0), utc(2026, 3, 31, 0, 0), Phase::Full);
+        assert_eq!(fulls.len(), 3);
+        // 2026-01-03 10:03 UTC
+        assert!((fulls[0].1 - utc(2026, 1, 3, 10, 3)).num_minutes().abs() <= 2);
+    }
+
+    #[test]
+    fn test_illumination() {
+        let (full, _) = illumination(utc(2026, 1, 3, 10, 3));
+        assert!(full > 0.99);
+        let (quarter, waxing) = illumination(utc(2026, 1, 26, 4, 47));
+        assert!((quarter - 0.5).abs() < 0.05);
+        assert!(waxing);
+        assert_eq!(phase_name(quarter, waxing), "First Quarter");
+    }
+}
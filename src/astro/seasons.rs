@@ -0,0 +1,120 @@
+use chrono::NaiveDateTime;
+
+use super::time::utc_from_jde;
+
+/// Equinoxes and solstices, named for the northern-hemisphere month
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Season {
+    MarchEquinox,
+    JuneSolstice,
+    SeptemberEquinox,
+    DecemberSolstice,
+}
+
+impl Season {
+    pub const ALL: [Season; 4] = [
+        Self::MarchEquinox,
+        Self::JuneSolstice,
+        Self::SeptemberEquinox,
+        Self::DecemberSolstice,
+    ];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::MarchEquinox => "march_equinox",
+            Self::JuneSolstice => "june_solstice",
+            Self::SeptemberEquinox => "september_equinox",
+            Self::DecemberSolstice => "december_solstice",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::MarchEquinox => "March Equinox",
+            Self::JuneSolstice => "June Solstice",
+            Self::SeptemberEquinox => "September Equinox",
+            Self::DecemberSolstice => "December Solstice",
+        }
+    }
+
+    /// Mean instant polynomial coefficients for years 1000-3000
+    fn mean_coefficients(self) -> [f64; 5] {
+        match self {
+            Self::MarchEquinox => [2451623.80984, 365242.37404, 0.05169, -0.00411, -0.00057],
+            Self::JuneSolstice => [2451716.56767, 365241.62603, 0.00325, 0.00888, -0.00030],
+            Self::SeptemberEquinox => [2451810.21715, 365242.01767, -0.11575, 0.00337, 0.00078],
+            Self::DecemberSolstice => [2451900.05952, 365242.74049, -0.06223, -0.00823, 0.00032],
+        }
+    }
+}
+
+/// Periodic terms (A, B, C) from Meeus, Astronomical Algorithms table 27.C
+const PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1934.136),
+    (203.0, 337.23, 32964.467),
+    (199.0, 342.08, 20.186),
+    (182.0, 27.85, 445267.112),
+    (156.0, 73.14, 45036.886),
+    (136.0, 171.52, 22518.443),
+    (77.0, 222.54, 65928.934),
+    (74.0, 296.72, 3034.906),
+    (70.0, 243.58, 9037.513),
+    (58.0, 119.81, 33718.147),
+    (52.0, 297.17, 150.678),
+    (50.0, 21.02, 2281.226),
+    (45.0, 247.54, 29929.562),
+    (44.0, 325.15, 31555.956),
+    (29.0, 60.93, 4443.417),
+    (18.0, 155.12, 67555.328),
+    (17.0, 288.79, 4562.452),
+    (16.0, 198.04, 62894.029),
+    (14.0, 199.76, 31436.921),
+    (12.0, 95.39, 14577.848),
+    (12.0, 287.11, 31931.756),
+    (12.0, 320.81, 34777.259),
+    (9.0, 227.73, 1222.114),
+    (8.0, 15.45, 16859.074),
+];
+
+/// JDE (TT) of an equinox or solstice (Meeus ch. 27)
+pub fn season_jde(year: i32, season: Season) -> f64 {
+    let y = (year as f64 - 2000.0) / 1000.0;
+    let c = season.mean_coefficients();
+    let jde0 = c[0] + c[1] * y + c[2] * y.powi(2) + c[3] * y.powi(3) + c[4] * y.powi(4);
+
+    let t = (jde0 - 2451545.0) / 36525.0;
+    let w = (35999.373 * t - 2.47).to_radians();
+    let delta_lambda = 1.0 + 0.0334 * w.cos() + 0.0007 * (2.0 * w).cos();
+    let s: f64 = PERIODIC_TERMS
+        .iter()
+        .map(|&(a, b, c)| a * (b + c * t).to_radians().cos())
+        .sum();
+
+    jde0 + 0.00001 * s / delta_lambda
+}
+
+/// UTC instant of an equinox or solstice
+pub fn season_instant(year: i32, season: Season) -> Option<NaiveDateTime> {
+    utc_from_jde(season_jde(year, season))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_seasons_2026() {
+        let expected = [
+            (Season::MarchEquinox, (3, 20, 14, 46)),
+            (Season::JuneSolstice, (6, 21, 8, 24)),
+            (Season::SeptemberEquinox, (9, 23, 0, 5)),
+            (Season::DecemberSolstice, (12, 21, 20, 50)),
+        ];
+        for (season, (m, d, h, mi)) in expected {
+            let expected = NaiveDate::from_ymd_opt(2026, m, d).unwrap().and_hms_opt(h, mi, 0).unwrap();
+            let actual = season_instant(2026, season).unwrap();
+            assert!((actual - expected).num_minutes().abs() <= 2, "{:?} at {}", season, actual);
+        }
+    }
+}
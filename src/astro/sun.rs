@@ -0,0 +1,146 @@
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+
+/// Zenith angles (degrees) for sunrise/sunset and the three twilights.
+/// Sunrise allows for refraction and the solar disc radius.
+pub const SUNRISE_ZENITH: f64 = 90.833;
+pub const CIVIL_ZENITH: f64 = 96.0;
+pub const NAUTICAL_ZENITH: f64 = 102.0;
+pub const ASTRONOMICAL_ZENITH: f64 = 108.0;
+
+/// How the sun crosses a zenith angle on a given day
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Crossing {
+    /// Morning and evening crossing times, in UTC
+    Times(NaiveDateTime, NaiveDateTime),
+    /// The sun stays above the zenith angle all day (e.g., polar day)
+    AlwaysAbove,
+    /// The sun stays below the zenith angle all day (e.g., polar night)
+    AlwaysBelow,
+}
+
+/// Julian day number at 00:00 UTC on a date
+pub fn julian_day(date: NaiveDate) -> f64 {
+    let epoch = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+    (date - epoch).num_days() as f64 + 2451544.5
+}
+
+/// Julian centuries since J2000.0
+fn julian_century(julian_day: f64) -> f64 {
+    (julian_day - 2451545.0) / 36525.0
+}
+
+/// Solar declination (radians) and equation of time (minutes), following
+/// the NOAA solar calculator
+fn solar_position(t: f64) -> (f64, f64) {
+    let mean_long = (280.46646 + t * (36000.76983 + t * 0.0003032)).rem_euclid(360.0);
+    let mean_anom = 357.52911 + t * (35999.05029 - 0.0001537 * t);
+    let eccentricity = 0.016708634 - t * (0.000042037 + 0.0000001267 * t);
+
+    let m = mean_anom.to_radians();
+    let center = m.sin() * (1.914602 - t * (0.004817 + 0.000014 * t))
+        + (2.0 * m).sin() * (0.019993 - 0.000101 * t)
+        + (3.0 * m).sin() * 0.000289;
+
+    let omega = (125.04 - 1934.136 * t).to_radians();
+    let apparent_long = (mean_long + center - 0.00569 - 0.00478 * omega.sin()).to_radians();
+
+    let mean_obliquity =
+        23.0 + (26.0 + (21.448 - t * (46.815 + t * (0.00059 - t * 0.001813))) / 60.0) / 60.0;
+    let obliquity = (mean_obliquity + 0.00256 * omega.cos()).to_radians();
+
+    let declination = (obliquity.sin() * apparent_long.sin()).asin();
+
+    let y = (obliquity / 2.0).tan().powi(2);
+    let l0 = mean_long.to_radians();
+    let eq_time = 4.0
+        * (y * (2.0 * l0).sin() - 2.0 * eccentricity * m.sin()
+            + 4.0 * eccentricity * y * m.sin() * (2.0 * l0).cos()
+            - 0.5 * y * y * (4.0 * l0).sin()
+            - 1.25 * eccentricity * eccentricity * (2.0 * m).sin())
+        .to_degrees();
+
+    (declination, eq_time)
+}
+
+/// Minutes after 00:00 UTC at which the sun transits for a longitude (east positive)
+fn noon_minutes(jd: f64, longitude: f64) -> f64 {
+    let mut minutes = 720.0 - 4.0 * longitude;
+    for _ in 0..2 {
+        let (_, eq_time) = solar_position(julian_century(jd + minutes / 1440.0));
+        minutes = 720.0 - 4.0 * longitude - eq_time;
+    }
+    minutes
+}
+
+fn to_utc(date: NaiveDate, minutes: f64) -> NaiveDateTime {
+    date.and_hms_opt(0, 0, 0).unwrap() + Duration::milliseconds((minutes * 60000.0).round() as i64)
+}
+
+/// Solar noon in UTC
+pub fn solar_noon(date: NaiveDate, longitude: f64) -> NaiveDateTime {
+    to_utc(date, noon_minutes(julian_day(date), longitude))
+}
+
+/// When the sun crosses a zenith angle on a date, for a latitude and
+/// longitude in degrees (north and east positive)
+pub fn crossing(date: NaiveDate, latitude: f64, longitude: f64, zenith: f64) -> Crossing {
+    let jd = julian_day(date);
+    let lat = latitude.to_radians();
+    let noon = noon_minutes(jd, longitude);
+
+    let mut times = [noon, noon];
+    for (i, sign) in [-1.0, 1.0].into_iter().enumerate() {
+        for _ in 0..3 {
+            let (declination, eq_time) = solar_position(julian_century(jd + times[i] / 1440.0));
+            let cos_hour_angle = zenith.to_radians().cos() / (lat.cos() * declination.cos())
+                - lat.tan() * declination.tan();
+
+            if cos_hour_angle > 1.0 {
+                return Crossing::AlwaysBelow;
+            }
+            if cos_hour_angle < -1.0 {
+                return Crossing::AlwaysAbove;
+            }
+
+            let hour_angle = cos_hour_angle.acos().to_degrees();
+            times[i] = 720.0 - 4.0 * longitude - eq_time + sign * 4.0 * hour_angle;
+        }
+    }
+
+    Crossing::Times(to_utc(date, times[0]), to_utc(date, times[1]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(date: NaiveDate, h: u32, m: u32) -> NaiveDateTime {
+        date.and_hms_opt(h, m, 0).unwrap()
+    }
+
+    fn assert_close(actual: NaiveDateTime, expected: NaiveDateTime) {
+        let diff = (actual - expected).num_seconds().abs();
+        assert!(diff <= 120, "{} is not within 2 minutes of {}", actual, expected);
+    }
+
+    #[test]
+    fn test_london_summer_solstice() {
+        let date = NaiveDate::from_ymd_opt(2026, 6, 21).unwrap();
+        let Crossing::Times(rise, set) = crossing(date, 51.5074, -0.1278, SUNRISE_ZENITH) else {
+            panic!("expected sunrise and sunset");
+        };
+        // 04:43 and 21:21 BST
+        assert_close(rise, utc(date, 3, 43));
+        assert_close(set, utc(date, 20, 21));
+        assert_close(solar_noon(date, -0.1278), utc(date, 12, 2));
+    }
+
+    #[test]
+    fn test_polar_day_and_night() {
+        let summer = NaiveDate::from_ymd_opt(2026, 6, 21).unwrap();
+        let winter = NaiveDate::from_ymd_opt(2026, 12, 21).unwrap();
+        assert_eq!(crossing(summer, 69.6492, 18.9553, SUNRISE_ZENITH), Crossing::AlwaysAbove);
+        assert_eq!(crossing(winter, 69.6492, 18.9553, SUNRISE_ZENITH), Crossing::AlwaysBelow);
+        assert!(matches!(crossing(winter, 69.6492, 18.9553, CIVIL_ZENITH), Crossing::Times(..)));
+    }
+}
@@ -0,0 +1,51 @@
+use chrono::{DateTime, Datelike, NaiveDateTime};
+
+/// Julian day of the Unix epoch
+const UNIX_EPOCH_JD: f64 = 2440587.5;
+
+/// Julian day of a UTC instant
+pub fn julian_day_utc(datetime: NaiveDateTime) -> f64 {
+    datetime.and_utc().timestamp_millis() as f64 / 86_400_000.0 + UNIX_EPOCH_JD
+}
+
+/// UTC instant of a Julian day
+pub fn utc_from_julian_day(julian_day: f64) -> Option<NaiveDateTime> {
+    let millis = ((julian_day - UNIX_EPOCH_JD) * 86_400_000.0).round() as i64;
+    DateTime::from_timestamp_millis(millis).map(|dt| dt.naive_utc())
+}
+
+/// Approximate ΔT = TT - UT in seconds (Espenak & Meeus polynomials)
+pub fn delta_t_seconds(year: f64) -> f64 {
+    let t = year - 2000.0;
+    if (2005.0..2050.0).contains(&year) {
+        62.92 + 0.32217 * t + 0.005589 * t * t
+    } else if (1986.0..2005.0).contains(&year) {
+        63.86 + 0.3345 * t - 0.060374 * t.powi(2) + 0.0017275 * t.powi(3)
+            + 0.000651814 * t.powi(4)
+            + 0.00002373599 * t.powi(5)
+    } else if (2050.0..2150.0).contains(&year) {
+        -20.0 + 32.0 * ((year - 1820.0) / 100.0).powi(2) - 0.5628 * (2150.0 - year)
+    } else {
+        -20.0 + 32.0 * ((year - 1820.0) / 100.0).powi(2)
+    }
+}
+
+/// Convert a Julian Ephemeris Day (TT) to a UTC instant
+pub fn utc_from_jde(jde: f64) -> Option<NaiveDateTime> {
+    let approx = utc_from_julian_day(jde)?;
+    let year = approx.year() as f64 + (approx.ordinal() as f64 - 0.5) / 365.25;
+    utc_from_julian_day(jde - delta_t_seconds(year) / 86400.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_julian_day_round_trip() {
+        let noon = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+        assert_eq!(julian_day_utc(noon), 2451545.0);
+        assert_eq!(utc_from_julian_day(2451545.0), Some(noon));
+    }
+}
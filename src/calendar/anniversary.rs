@@ -0,0 +1,68 @@
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, NaiveDate};
+
+/// When a February 29 birthday or anniversary falls in common years
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LeapDayPolicy {
+    /// The last day of February (e.g. New Zealand, most software)
+    #[default]
+    February28,
+    /// The day after February 28 (e.g. England and Wales, Hong Kong)
+    March1,
+}
+
+impl LeapDayPolicy {
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name.trim().to_lowercase().replace([' ', '-'], "_").as_str() {
+            "feb28" | "feb_28" | "february_28" | "february28" => Ok(Self::February28),
+            "mar1" | "mar_1" | "march_1" | "march1" => Ok(Self::March1),
+            _ => Err(anyhow!("Unknown leap day policy '{}'. Valid policies: february_28, march_1", name)),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::February28 => "february_28",
+            Self::March1 => "march_1",
+        }
+    }
+}
+
+/// The anniversary of `origin` in `year`
+pub fn anniversary(origin: NaiveDate, year: i32, policy: LeapDayPolicy) -> Option<NaiveDate> {
+    origin.with_year(year).or_else(|| match policy {
+        // Only February 29 has no counterpart in some years
+        LeapDayPolicy::February28 => NaiveDate::from_ymd_opt(year, 2, 28),
+        LeapDayPolicy::March1 => NaiveDate::from_ymd_opt(year, 3, 1),
+    })
+}
+
+/// Completed years from `origin` to `date`; negative before `origin`
+pub fn years_completed(origin: NaiveDate, date: NaiveDate, policy: LeapDayPolicy) -> i32 {
+    let mut years = date.year() - origin.year();
+    if anniversary(origin, date.year(), policy).is_some_and(|this_year| date < this_year) {
+        years -= 1;
+    }
+    years
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_leap_day_anniversaries() {
+        let born = date(2008, 2, 29);
+        assert_eq!(anniversary(born, 2026, LeapDayPolicy::February28), Some(date(2026, 2, 28)));
+        assert_eq!(anniversary(born, 2026, LeapDayPolicy::March1), Some(date(2026, 3, 1)));
+        assert_eq!(anniversary(born, 2028, LeapDayPolicy::March1), Some(date(2028, 2, 29)));
+
+        assert_eq!(years_completed(born, date(2026, 2, 28), LeapDayPolicy::February28), 18);
+        assert_eq!(years_completed(born, date(2026, 2, 28), LeapDayPolicy::March1), 17);
+        assert_eq!(years_completed(date(1990, 6, 15), date(2026, 6, 14), LeapDayPolicy::default()), 35);
+    }
+}
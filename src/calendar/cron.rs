@@ -0,0 +1,830 @@
+//! Cron schedules: standard 5-field expressions (minute hour day-of-month
+//! month day-of-week), 6-field ones with seconds first, and Quartz ones
+//! (seconds first, an optional year, `?`, and Sunday = 1). `L`, `W` and `#`,
+//! month and weekday names, and the @daily-style macros work in every
+//! dialect.
+//!
+//! As in Vixie cron, when day of month and day of week are both restricted
+//! a day matching either runs. Times skipped by a DST change run when the
+//! clocks go forward; repeated times run once, or twice when the hour field
+//! is a wildcard.
+
+use anyhow::{anyhow, Result};
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike,
+    Weekday,
+};
+
+use crate::calendar::fiscal::month_name;
+use crate::calendar::recurrence::{weekday_code, ByDay, Frequency, Recurrence};
+
+/// Days searched for runs before a schedule is taken never to run
+const MAX_DAYS: usize = 366 * 100;
+/// Longest DST gap looked past, in minutes
+const MAX_GAP_MINUTES: i64 = 24 * 60;
+const WEEKDAYS: [&str; 7] = ["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"];
+const ORDINALS: [&str; 5] = ["first", "second", "third", "fourth", "fifth"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CronDialect {
+    /// minute hour day-of-month month day-of-week
+    Standard,
+    /// Seconds, then the standard fields
+    Seconds,
+    /// Seconds first, an optional year, `?` and Sunday = 1
+    Quartz,
+}
+
+impl CronDialect {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Standard => "standard",
+            Self::Seconds => "seconds",
+            Self::Quartz => "quartz",
+        }
+    }
+}
+
+/// One comma-separated entry of a field
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Item {
+    /// `*`, `?` or `*/step`
+    Every { step: u32 },
+    /// `a`, `a-b`, `a-b/step`, or `a/step` (open) running to the maximum
+    Range { start: u32, end: u32, step: u32, open: bool },
+    /// Day of month `L` or `L-offset`
+    Last { offset: u32 },
+    /// Day of month `LW`
+    LastWeekday,
+    /// Day of month `nW`
+    NearestWeekday(u32),
+    /// Day of week `nL`, from Sunday = 0
+    LastOf(u32),
+    /// Day of week `n#k`, from Sunday = 0
+    Nth(u32, u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Second,
+    Minute,
+    Hour,
+    DayOfMonth,
+    Month,
+    DayOfWeek,
+    Year,
+}
+
+impl Kind {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Second => "second",
+            Self::Minute => "minute",
+            Self::Hour => "hour",
+            Self::DayOfMonth => "day of month",
+            Self::Month => "month",
+            Self::DayOfWeek => "day of week",
+            Self::Year => "year",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Field {
+    kind: Kind,
+    text: String,
+    items: Vec<Item>,
+    /// Values allowed by `Every` and `Range` items, indexed by value
+    allowed: Vec<bool>,
+    /// The number for Sunday in a day of week field
+    base: u32,
+}
+
+impl Field {
+    /// `*` or `?`, optionally with a step; Vixie cron's test for an
+    /// unrestricted day field
+    fn is_star(&self) -> bool {
+        self.text.starts_with(['*', '?'])
+    }
+
+    fn is_any(&self) -> bool {
+        self.text == "*" || self.text == "?"
+    }
+
+    fn values(&self) -> impl Iterator<Item = u32> + '_ {
+        self.allowed.iter().enumerate().filter(|(_, allowed)| **allowed).map(|(value, _)| value as u32)
+    }
+
+    /// The values, when the field lists single values only
+    fn plain(&self) -> Option<Vec<u32>> {
+        self.items
+            .iter()
+            .map(|item| match *item {
+                Item::Range { start, end, .. } if start == end => Some(start),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// How a DST change moved a run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DstShift {
+    /// The local time was skipped when clocks went forward; it runs as they do
+    Gap,
+    /// The local time happened twice when clocks went back; it runs the first
+    /// time only
+    RepeatedOnce,
+    /// The local time happened twice and runs both times
+    Repeated { second: bool },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CronRun {
+    pub at: DateTime<FixedOffset>,
+    /// The local time the schedule names
+    pub scheduled: NaiveDateTime,
+    pub dst: Option<DstShift>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CronSchedule {
+    pub dialect: CronDialect,
+    seconds: Field,
+    minutes: Field,
+    hours: Field,
+    days_of_month: Field,
+    months: Field,
+    days_of_week: Field,
+    years: Option<Field>,
+}
+
+impl CronSchedule {
+    pub fn parse(text: &str) -> Result<Self> {
+        let text = text.trim();
+        let expanded = match text.to_lowercase().as_str() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            other if other.starts_with('@') => {
+                return Err(anyhow!(
+                    "Unsupported macro '{}'. Supported: @yearly, @annually, @monthly, @weekly, @daily, @midnight, @hourly",
+                    text
+                ))
+            }
+            _ => text,
+        };
+        let parts: Vec<&str> = expanded.split_whitespace().collect();
+        let (dialect, fields) = match parts.len() {
+            5 => (CronDialect::Standard, ["0"].iter().chain(&parts).copied().collect::<Vec<_>>()),
+            6 if parts[3] == "?" || parts[5] == "?" => (CronDialect::Quartz, parts),
+            6 => (CronDialect::Seconds, parts),
+            7 => (CronDialect::Quartz, parts),
+            n => {
+                return Err(anyhow!(
+                    "Expected 5 fields (minute hour day-of-month month day-of-week), 6 with seconds first, \
+                     or 7 with a Quartz year; found {}",
+                    n
+                ))
+            }
+        };
+        // Quartz numbers Sunday 1 to Saturday 7; cron uses 0 to 6, and 7 for Sunday too
+        let base = if dialect == CronDialect::Quartz { 1 } else { 0 };
+
+        Ok(Self {
+            dialect,
+            seconds: parse_field(fields[0], Kind::Second, base)?,
+            minutes: parse_field(fields[1], Kind::Minute, base)?,
+            hours: parse_field(fields[2], Kind::Hour, base)?,
+            days_of_month: parse_field(fields[3], Kind::DayOfMonth, base)?,
+            months: parse_field(fields[4], Kind::Month, base)?,
+            days_of_week: parse_field(fields[5], Kind::DayOfWeek, base)?,
+            years: fields.get(6).map(|year| parse_field(year, Kind::Year, base)).transpose()?,
+        })
+    }
+
+    /// Each field's name and text, seconds first
+    pub fn fields(&self) -> Vec<(&'static str, &str)> {
+        [&self.seconds, &self.minutes, &self.hours, &self.days_of_month, &self.months, &self.days_of_week]
+            .into_iter()
+            .chain(&self.years)
+            .map(|field| (field.kind.name(), field.text.as_str()))
+            .collect()
+    }
+
+    /// Whether the schedule runs at all on a date
+    pub fn runs_on(&self, date: NaiveDate) -> bool {
+        if !self.months.allowed[date.month() as usize] {
+            return false;
+        }
+        if let Some(years) = &self.years {
+            if !years.allowed.get(date.year() as usize).copied().unwrap_or(false) {
+                return false;
+            }
+        }
+        let (by_day, by_weekday) = (self.day_of_month_matches(date), self.day_of_week_matches(date));
+        if self.days_of_month.is_star() || self.days_of_week.is_star() {
+            by_day && by_weekday
+        } else {
+            by_day || by_weekday
+        }
+    }
+
+    fn day_of_month_matches(&self, date: NaiveDate) -> bool {
+        let (day, last) = (date.day(), last_day(date));
+        self.days_of_month.allowed[day as usize]
+            || self.days_of_month.items.iter().any(|item| match *item {
+                Item::Last { offset } => day + offset == last,
+                Item::LastWeekday => date == weekday_on_or_before(date.with_day(last).unwrap()),
+                Item::NearestWeekday(n) => n <= last && date == nearest_weekday(date.with_day(n).unwrap(), last),
+                _ => false,
+            })
+    }
+
+    fn day_of_week_matches(&self, date: NaiveDate) -> bool {
+        let weekday = date.weekday().num_days_from_sunday();
+        self.weekday_allowed(weekday)
+            || self.days_of_week.items.iter().any(|item| match *item {
+                Item::LastOf(day) => day == weekday && date.day() + 7 > last_day(date),
+                Item::Nth(day, n) => day == weekday && (date.day() - 1) / 7 + 1 == n,
+                _ => false,
+            })
+    }
+
+    /// Whether a weekday, from Sunday = 0, is listed by number or range
+    fn weekday_allowed(&self, weekday: u32) -> bool {
+        let allowed = &self.days_of_week.allowed;
+        match self.dialect {
+            CronDialect::Quartz => allowed[weekday as usize + 1],
+            _ => allowed[weekday as usize] || (weekday == 0 && allowed[7]),
+        }
+    }
+
+    /// Every time of day the schedule names, in order
+    fn times(&self) -> Vec<NaiveTime> {
+        let mut times = Vec::new();
+        for hour in self.hours.values() {
+            for minute in self.minutes.values() {
+                for second in self.seconds.values() {
+                    times.push(NaiveTime::from_hms_opt(hour, minute, second).unwrap());
+                }
+            }
+        }
+        times
+    }
+
+    /// Runs on one local date, in order
+    fn day_runs<Z: TimeZone>(&self, zone: &Z, date: NaiveDate, times: &[NaiveTime]) -> Vec<CronRun> {
+        let both_times = self.hours.is_star();
+        let mut runs = Vec::new();
+        for time in times {
+            let scheduled = date.and_time(*time);
+            let run = |at: DateTime<Z>, dst| CronRun { at: at.fixed_offset(), scheduled, dst };
+            match zone.from_local_datetime(&scheduled) {
+                LocalResult::Single(at) => runs.push(run(at, None)),
+                LocalResult::Ambiguous(first, second) if both_times => {
+                    runs.push(run(first, Some(DstShift::Repeated { second: false })));
+                    runs.push(run(second, Some(DstShift::Repeated { second: true })));
+                }
+                LocalResult::Ambiguous(first, _) => runs.push(run(first, Some(DstShift::RepeatedOnce))),
+                LocalResult::None => {
+                    let minute = scheduled.with_second(0).unwrap();
+                    if let Some(at) = (1..=MAX_GAP_MINUTES)
+                        .find_map(|m| zone.from_local_datetime(&(minute + Duration::minutes(m))).earliest())
+                    {
+                        runs.push(run(at, Some(DstShift::Gap)));
+                    }
+                }
+            }
+        }
+        // A skipped time that lands on a scheduled one runs once, unshifted
+        runs.sort_by_key(|run| (run.at, run.dst.is_some()));
+        runs.dedup_by_key(|run| run.at);
+        runs
+    }
+
+    /// The first `count` runs after `after`, in `zone`
+    pub fn next_runs<Z: TimeZone>(&self, zone: &Z, after: DateTime<FixedOffset>, count: usize) -> Vec<CronRun> {
+        let times = self.times();
+        let last_year = self.years.as_ref().and_then(|years| years.values().last());
+        let day = after.with_timezone(zone).date_naive();
+        let start = day.checked_sub_signed(Duration::days(1)).unwrap_or(day);
+        let mut runs = Vec::new();
+        for date in start.iter_days().take(MAX_DAYS) {
+            if last_year.is_some_and(|year| date.year() > year as i32) || runs.len() >= count {
+                break;
+            }
+            if self.runs_on(date) {
+                runs.extend(self.day_runs(zone, date, &times).into_iter().filter(|run| run.at > after));
+            }
+        }
+        runs.truncate(count);
+        runs
+    }
+
+    /// The last `count` runs before `before`, latest first
+    pub fn previous_runs<Z: TimeZone>(&self, zone: &Z, before: DateTime<FixedOffset>, count: usize) -> Vec<CronRun> {
+        let times = self.times();
+        let first_year = self.years.as_ref().and_then(|years| years.values().next());
+        let day = before.with_timezone(zone).date_naive();
+        let start = day.checked_add_signed(Duration::days(1)).unwrap_or(day);
+        let mut runs = Vec::new();
+        for date in start.iter_days().rev().take(MAX_DAYS) {
+            if first_year.is_some_and(|year| date.year() < year as i32) || runs.len() >= count {
+                break;
+            }
+            if self.runs_on(date) {
+                runs.extend(self.day_runs(zone, date, &times).into_iter().rev().filter(|run| run.at < before));
+            }
+        }
+        runs.truncate(count);
+        runs
+    }
+
+    /// The schedule in English, e.g. "At 09:30, Monday through Friday"
+    pub fn describe(&self) -> String {
+        let mut parts = self.describe_time();
+        let days = [&self.days_of_month, &self.days_of_week]
+            .into_iter()
+            .filter(|field| !field.is_any())
+            .map(describe_field)
+            .collect::<Vec<_>>();
+        if !days.is_empty() {
+            let or = !self.days_of_month.is_star() && !self.days_of_week.is_star();
+            parts.push(days.join(if or { " or " } else { ", " }));
+        }
+        parts.extend([Some(&self.months), self.years.as_ref()].into_iter().flatten().filter(|f| !f.is_any()).map(describe_field));
+
+        let text = parts.join(", ");
+        let mut chars = text.chars();
+        chars.next().map(|first| first.to_uppercase().chain(chars).collect()).unwrap_or(text)
+    }
+
+    fn describe_time(&self) -> Vec<String> {
+        if let (Some(seconds), Some(minutes), Some(hours)) = (self.seconds.plain(), self.minutes.plain(), self.hours.plain()) {
+            if seconds.len() * minutes.len() * hours.len() <= 6 {
+                let with_seconds = seconds.iter().any(|s| *s != 0);
+                let mut times = Vec::new();
+                for hour in &hours {
+                    for minute in &minutes {
+                        for second in &seconds {
+                            times.push(match with_seconds {
+                                true => format!("{:02}:{:02}:{:02}", hour, minute, second),
+                                false => format!("{:02}:{:02}", hour, minute),
+                            });
+                        }
+                    }
+                }
+                return vec![format!("at {}", list(&times))];
+            }
+        }
+        let mut parts = Vec::new();
+        if self.seconds.plain() != Some(vec![0]) {
+            parts.push(describe_field(&self.seconds));
+        }
+        // "every second" already covers every minute
+        if !self.minutes.is_any() || parts.is_empty() {
+            parts.push(describe_field(&self.minutes));
+        }
+        if !self.hours.is_any() {
+            parts.push(describe_field(&self.hours));
+        }
+        parts
+    }
+
+    /// The schedule as a recurrence rule, with notes on what the rule
+    /// cannot express; fields with one value are left to the rule's start
+    pub fn to_recurrence(&self) -> (Recurrence, Vec<String>) {
+        let mut notes = Vec::new();
+        let mut by_day = Vec::new();
+        if !self.days_of_week.is_any() {
+            by_day.extend((0..7).filter(|day| self.weekday_allowed(*day)).map(|day| ByDay { nth: None, weekday: weekday(day) }));
+            for item in &self.days_of_week.items {
+                match *item {
+                    Item::LastOf(day) => by_day.push(ByDay { nth: Some(-1), weekday: weekday(day) }),
+                    Item::Nth(day, n) => by_day.push(ByDay { nth: Some(n as i32), weekday: weekday(day) }),
+                    _ => {}
+                }
+            }
+        }
+        let mut by_month_day: Vec<i32> = Vec::new();
+        if !self.days_of_month.is_any() {
+            by_month_day.extend(self.days_of_month.values().map(|day| day as i32));
+            for item in &self.days_of_month.items {
+                match *item {
+                    Item::Last { offset } => by_month_day.push(-(offset as i32) - 1),
+                    Item::LastWeekday => notes.push("'LW' (the last weekday of the month) has no RRULE form without BYSETPOS; it is left out".to_string()),
+                    Item::NearestWeekday(day) => {
+                        notes.push(format!("'{}W' (the weekday nearest day {}) has no RRULE form; day {} is used", day, day, day));
+                        by_month_day.push(day as i32);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        if !by_day.is_empty() && !by_month_day.is_empty() && !self.days_of_month.is_star() && !self.days_of_week.is_star() {
+            notes.push("cron runs on days matching the day of month or the day of week; an RRULE needs both to match".to_string());
+        }
+        if let Some(years) = self.years.as_ref().filter(|years| !years.is_any()) {
+            notes.push(format!("RRULEs cannot limit years ('{}'); it runs every year", years.text));
+        }
+
+        let frequency = if !by_month_day.is_empty() || by_day.iter().any(|day| day.nth.is_some()) {
+            Frequency::Monthly
+        } else if !by_day.is_empty() {
+            Frequency::Weekly
+        } else {
+            Frequency::Daily
+        };
+        let several = |field: &Field| {
+            let values: Vec<u32> = field.values().collect();
+            if values.len() > 1 { values } else { Vec::new() }
+        };
+        let mut rule = Recurrence::new(frequency);
+        rule.by_day = by_day;
+        rule.by_month_day = by_month_day;
+        if !self.months.is_any() {
+            rule.by_month = self.months.values().collect();
+        }
+        rule.by_hour = several(&self.hours);
+        rule.by_minute = several(&self.minutes);
+        rule.by_second = several(&self.seconds);
+        (rule, notes)
+    }
+}
+
+/// A cron expression for a rule starting at `start`, with notes on what
+/// cron cannot express; Quartz syntax is used when `L` or `#` is needed
+pub fn cron_for(rule: &Recurrence, start: NaiveDateTime) -> (String, Vec<String>) {
+    let mut notes = Vec::new();
+    if rule.interval > 1 {
+        let unit = rule.frequency.unit();
+        notes.push(format!("cron cannot repeat every {} {}s; this runs every {}", rule.interval, unit, unit));
+    }
+    if let Some(count) = rule.count {
+        notes.push(format!("cron schedules never end; COUNT={} is left out", count));
+    }
+    if let Some(until) = rule.until {
+        notes.push(format!("cron schedules never end; UNTIL={} is left out", until.format("%Y-%m-%d %H:%M:%S")));
+    }
+    let join = |values: &[u32], default: u32| match values {
+        [] => default.to_string(),
+        values => values.iter().map(u32::to_string).collect::<Vec<_>>().join(","),
+    };
+    let seconds = join(&rule.by_second, start.second());
+    let minutes = join(&rule.by_minute, start.minute());
+    let hours = join(&rule.by_hour, start.hour());
+
+    let mut quartz = false;
+    let (mut days, mut weekdays, mut months) = (Vec::new(), Vec::new(), Vec::new());
+    for day in &rule.by_month_day {
+        match *day {
+            day if day > 0 => days.push(day.to_string()),
+            -1 => days.push("L".to_string()),
+            day => days.push(format!("L-{}", -day - 1)),
+        }
+        quartz |= *day < 0;
+    }
+    let nth_allowed = rule.frequency == Frequency::Monthly || !rule.by_month.is_empty();
+    for by_day in &rule.by_day {
+        let name = WEEKDAYS[by_day.weekday.num_days_from_sunday() as usize][..3].to_uppercase();
+        // Quartz numbers days from Sunday = 1
+        let number = by_day.weekday.num_days_from_sunday() + 1;
+        match by_day.nth {
+            None => weekdays.push(name),
+            Some(-1) if nth_allowed => weekdays.push(format!("{}L", number)),
+            Some(n @ 1..=5) if nth_allowed => weekdays.push(format!("{}#{}", number, n)),
+            Some(n) => notes.push(format!("cron has no form for BYDAY={}{}; it is left out", n, weekday_code(by_day.weekday))),
+        }
+        quartz |= by_day.nth.is_some() && nth_allowed;
+    }
+    if days.is_empty() && weekdays.is_empty() {
+        match rule.frequency {
+            Frequency::Daily => {}
+            Frequency::Weekly => weekdays.push(WEEKDAYS[start.weekday().num_days_from_sunday() as usize][..3].to_uppercase()),
+            Frequency::Monthly => days.push(start.day().to_string()),
+            Frequency::Yearly => {
+                days.push(start.day().to_string());
+                if rule.by_month.is_empty() {
+                    months.push(start.month().to_string());
+                }
+            }
+        }
+    }
+    months.extend(rule.by_month.iter().map(u32::to_string));
+    if !days.is_empty() && !weekdays.is_empty() {
+        if quartz {
+            // Quartz allows only one day field; keep the one that needs Quartz
+            let nth = rule.by_day.iter().any(|by_day| by_day.nth.is_some());
+            let (field, dropped) = if nth { ("day of month", &mut days) } else { ("day of week", &mut weekdays) };
+            notes.push(format!("Quartz cron takes a day of month or a day of week, not both; the {} {} is left out", field, dropped.join(",")));
+            dropped.clear();
+        } else {
+            notes.push("cron runs on days matching the day of month or the day of week, where the RRULE needs both".to_string());
+        }
+    }
+
+    let field = |values: &[String]| if values.is_empty() { "*".to_string() } else { values.join(",") };
+    let (days, weekdays, months) = (field(&days), field(&weekdays), field(&months));
+    let expression = if quartz {
+        // Quartz wants '?' in whichever day field is unused
+        let (days, weekdays) = match days.as_str() {
+            "*" => ("?".to_string(), weekdays),
+            _ => (days, "?".to_string()),
+        };
+        format!("{} {} {} {} {} {}", seconds, minutes, hours, days, months, weekdays)
+    } else if seconds != "0" {
+        format!("{} {} {} {} {} {}", seconds, minutes, hours, days, months, weekdays)
+    } else {
+        format!("{} {} {} {} {}", minutes, hours, days, months, weekdays)
+    };
+    (expression, notes)
+}
+
+fn parse_field(text: &str, kind: Kind, base: u32) -> Result<Field> {
+    let (min, max) = match kind {
+        Kind::Second | Kind::Minute => (0, 59),
+        Kind::Hour => (0, 23),
+        Kind::DayOfMonth => (1, 31),
+        Kind::Month => (1, 12),
+        Kind::DayOfWeek => (base, 7),
+        Kind::Year => (1970, 2099),
+    };
+    let invalid = |part: &str, why: &str| anyhow!("Invalid {} field '{}' at '{}': {}", kind.name(), text, part, why);
+    let value = |token: &str| -> Result<u32> {
+        let upper = token.to_uppercase();
+        let named = match kind {
+            Kind::Month => (1..=12).find(|m| month_name(*m)[..3].eq_ignore_ascii_case(&upper)),
+            Kind::DayOfWeek => (0..7).find(|d| WEEKDAYS[*d as usize][..3].eq_ignore_ascii_case(&upper)).map(|d| d + base),
+            _ => None,
+        };
+        let value = match named {
+            Some(value) => value,
+            None => token.parse().map_err(|_| invalid(token, "not a number or name"))?,
+        };
+        if value < min || value > max {
+            return Err(invalid(token, &format!("must be {} to {}", min, max)));
+        }
+        Ok(value)
+    };
+    // Day of week from Sunday = 0
+    let weekday = |token: &str| value(token).map(|day| (day - base) % 7);
+    let step = |token: &str| match token.parse::<u32>() {
+        Ok(step) if step > 0 => Ok(step),
+        _ => Err(invalid(token, "the step must be a positive number")),
+    };
+
+    let mut items = Vec::new();
+    for part in text.split(',') {
+        let upper = part.to_uppercase();
+        let item = match (kind, upper.as_str()) {
+            (_, "*") => Item::Every { step: 1 },
+            (Kind::DayOfMonth | Kind::DayOfWeek, "?") => Item::Every { step: 1 },
+            (_, "?") => return Err(invalid(part, "'?' is only for day of month or day of week")),
+            (_, every) if every.starts_with("*/") => Item::Every { step: step(&every[2..])? },
+            (Kind::DayOfMonth, "L") => Item::Last { offset: 0 },
+            (Kind::DayOfMonth, "LW" | "WL") => Item::LastWeekday,
+            (Kind::DayOfMonth, last) if last.starts_with("L-") => match last[2..].parse::<u32>() {
+                Ok(offset) if offset < 31 => Item::Last { offset },
+                _ => return Err(invalid(part, "expected L-n with n below 31")),
+            },
+            (Kind::DayOfMonth, nearest) if nearest.len() > 1 && nearest.ends_with('W') => {
+                Item::NearestWeekday(value(&nearest[..nearest.len() - 1])?)
+            }
+            // Quartz reads a bare L here as the last day of the week, Saturday
+            (Kind::DayOfWeek, "L") => Item::Range { start: 6 + base, end: 6 + base, step: 1, open: false },
+            (Kind::DayOfWeek, last) if last.len() > 1 && last.ends_with('L') => Item::LastOf(weekday(&last[..last.len() - 1])?),
+            (Kind::DayOfWeek, nth) if nth.contains('#') => {
+                let (day, n) = nth.split_once('#').unwrap();
+                match n.parse::<u32>() {
+                    Ok(n @ 1..=5) => Item::Nth(weekday(day)?, n),
+                    _ => return Err(invalid(part, "expected day#n with n from 1 to 5")),
+                }
+            }
+            _ => {
+                let (range, step) = match part.split_once('/') {
+                    Some((range, by)) => (range, Some(step(by)?)),
+                    None => (part, None),
+                };
+                let (start, end) = match range.split_once('-') {
+                    Some((start, end)) => (value(start)?, value(end)?),
+                    None => (value(range)?, if step.is_some() { max } else { value(range)? }),
+                };
+                if start > end {
+                    return Err(invalid(part, "ranges must run from low to high"));
+                }
+                Item::Range { start, end, step: step.unwrap_or(1), open: step.is_some() && !range.contains('-') }
+            }
+        };
+        items.push(item);
+    }
+
+    let mut allowed = vec![false; max as usize + 1];
+    for item in &items {
+        let (start, end, step) = match *item {
+            Item::Every { step } => (min, max, step),
+            Item::Range { start, end, step, .. } => (start, end, step),
+            _ => continue,
+        };
+        for value in (start..=end).step_by(step as usize) {
+            allowed[value as usize] = true;
+        }
+    }
+    Ok(Field { kind, text: text.to_string(), items, allowed, base })
+}
+
+/// A field in English, e.g. "every 15 minutes" or "Monday through Friday"
+fn describe_field(field: &Field) -> String {
+    let (unit, plural, preposition, suffix) = match field.kind {
+        Kind::Second => ("second", "seconds", "at", ""),
+        Kind::Minute => ("minute", "minutes", "at", ""),
+        Kind::Hour => ("hour", "hours", "at", ""),
+        Kind::DayOfMonth => ("day", "days", "on", " of the month"),
+        Kind::Month => ("month", "months", "in", ""),
+        Kind::DayOfWeek => ("day", "days", "on", ""),
+        Kind::Year => ("year", "years", "in", ""),
+    };
+    let name = |value: u32| match field.kind {
+        Kind::Month => capitalize(month_name(value)),
+        Kind::DayOfWeek => WEEKDAYS[((value - field.base) % 7) as usize].to_string(),
+        _ => value.to_string(),
+    };
+    let named = matches!(field.kind, Kind::Month | Kind::DayOfWeek | Kind::Year);
+
+    let mut plain = Vec::new();
+    let mut phrases = Vec::new();
+    for item in &field.items {
+        match *item {
+            Item::Every { step: 1 } => phrases.push(format!("every {}", unit)),
+            Item::Every { step } => phrases.push(format!("every {} {}", step, plural)),
+            Item::Range { start, end, .. } if start == end => plain.push(name(start)),
+            Item::Range { start, end, step: 1, .. } if named => phrases.push(format!("{} through {}", name(start), name(end))),
+            Item::Range { start, end, step: 1, .. } => {
+                phrases.push(format!("every {} from {} through {}{}", unit, name(start), name(end), suffix))
+            }
+            Item::Range { start, step, open: true, .. } if named => {
+                phrases.push(format!("every {} {} starting {} {}", step, plural, preposition, name(start)))
+            }
+            Item::Range { start, step, open: true, .. } => {
+                phrases.push(format!("every {} {} starting {} {} {}", step, plural, preposition, unit, name(start)))
+            }
+            Item::Range { start, end, step, .. } => {
+                phrases.push(format!("every {} {} from {} through {}{}", step, plural, name(start), name(end), suffix))
+            }
+            Item::Last { offset: 0 } => phrases.push("on the last day of the month".to_string()),
+            Item::Last { offset } => phrases.push(format!(
+                "{} {} before the last day of the month",
+                offset,
+                if offset == 1 { "day" } else { "days" }
+            )),
+            Item::LastWeekday => phrases.push("on the last weekday of the month".to_string()),
+            Item::NearestWeekday(day) => phrases.push(format!("on the weekday nearest day {} of the month", day)),
+            Item::LastOf(day) => phrases.push(format!("on the last {} of the month", WEEKDAYS[day as usize])),
+            Item::Nth(day, n) => {
+                phrases.push(format!("on the {} {} of the month", ORDINALS[n as usize - 1], WEEKDAYS[day as usize]))
+            }
+        }
+    }
+    if !plain.is_empty() {
+        let label = match (named, plain.len()) {
+            (true, _) => String::new(),
+            (false, 1) => format!("{} ", unit),
+            (false, _) => format!("{} ", plural),
+        };
+        phrases.insert(0, format!("{} {}{}{}", preposition, label, list(&plain), suffix));
+    }
+    list(&phrases)
+}
+
+/// "a", "a and b" or "a, b and c"
+pub fn list(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [only] => only.clone(),
+        [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+    }
+}
+
+/// A weekday from Sunday = 0
+fn weekday(day: u32) -> Weekday {
+    Weekday::try_from((day as u8 + 6) % 7).unwrap()
+}
+
+pub fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars.next().map(|first| first.to_uppercase().chain(chars).collect()).unwrap_or_default()
+}
+
+fn last_day(date: NaiveDate) -> u32 {
+    let (year, month) = if date.month() == 12 { (date.year() + 1, 1) } else { (date.year(), date.month() + 1) };
+    // Only December of the last supported year has no next month
+    NaiveDate::from_ymd_opt(year, month, 1).and_then(|next| next.pred_opt()).map_or(31, |last| last.day())
+}
+
+fn weekday_on_or_before(date: NaiveDate) -> NaiveDate {
+    match date.weekday().num_days_from_monday() {
+        5 => shift(date, -1),
+        6 => shift(date, -2),
+        _ => date,
+    }
+}
+
+/// The weekday closest to `date` within its month, as for Quartz `nW`
+fn nearest_weekday(date: NaiveDate, last: u32) -> NaiveDate {
+    match (date.weekday().num_days_from_monday(), date.day()) {
+        (5, 1) => shift(date, 2),
+        (5, _) => shift(date, -1),
+        (6, day) if day == last => shift(date, -2),
+        (6, _) => shift(date, 1),
+        _ => date,
+    }
+}
+
+/// `date` moved by `days`, or left as it is past the ends of the supported dates
+fn shift(date: NaiveDate, days: i64) -> NaiveDate {
+    date.checked_add_signed(Duration::days(days)).unwrap_or(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono_tz::America::New_York;
+
+    fn at(text: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(text).unwrap()
+    }
+
+    fn times(runs: &[CronRun]) -> Vec<String> {
+        runs.iter().map(|run| run.at.format("%Y-%m-%d %H:%M%:z").to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_and_describe() {
+        let cases = [
+            ("30 9 * * 1-5", "At 09:30, Monday through Friday"),
+            ("*/15 9-17 * * MON,WED", "Every 15 minutes, every hour from 9 through 17, on Monday and Wednesday"),
+            ("0 0 1,15 * 5", "At 00:00, on days 1 and 15 of the month or on Friday"),
+            ("0 0 12 ? * 6#3", "At 12:00, on the third Friday of the month"),
+            ("0 0 18 L * ? 2027", "At 18:00, on the last day of the month, in 2027"),
+            ("0 30 8 15W * ?", "At 08:30, on the weekday nearest day 15 of the month"),
+            ("@monthly", "At 00:00, on day 1 of the month"),
+            ("* * * * * *", "Every second"),
+        ];
+        for (expression, description) in cases {
+            assert_eq!(CronSchedule::parse(expression).unwrap().describe(), description, "{}", expression);
+        }
+        assert_eq!(CronSchedule::parse("0 0 ? * 1").unwrap().dialect, CronDialect::Standard);
+        assert_eq!(CronSchedule::parse("0 0 0 ? * 1").unwrap().dialect, CronDialect::Quartz);
+
+        assert!(CronSchedule::parse("60 * * * *").is_err());
+        assert!(CronSchedule::parse("* * * *").is_err());
+        assert!(CronSchedule::parse("0 0 * * 1#6").is_err());
+        assert!(CronSchedule::parse("0 ? * * *").is_err());
+    }
+
+    #[test]
+    fn test_runs_across_dst() {
+        // Quartz Sunday = 1: the second Sunday of March is when US clocks go forward
+        let quartz = CronSchedule::parse("0 0 12 ? MAR 1#2").unwrap();
+        assert_eq!(times(&quartz.next_runs(&New_York, at("2026-01-01T00:00:00Z"), 1)), ["2026-03-08 12:00-04:00"]);
+
+        let daily = CronSchedule::parse("30 2 * * *").unwrap();
+        let runs = daily.next_runs(&New_York, at("2026-03-07T00:00:00-05:00"), 3);
+        assert_eq!(times(&runs), ["2026-03-07 02:30-05:00", "2026-03-08 03:00-04:00", "2026-03-09 02:30-04:00"]);
+        assert_eq!(runs[1].dst, Some(DstShift::Gap));
+
+        // 01:30 happens twice on 1 November; a fixed hour runs once, a wildcard hour twice
+        let once = CronSchedule::parse("30 1 * * *").unwrap().next_runs(&New_York, at("2026-11-01T00:00:00-04:00"), 2);
+        assert_eq!(times(&once), ["2026-11-01 01:30-04:00", "2026-11-02 01:30-05:00"]);
+        assert_eq!(once[0].dst, Some(DstShift::RepeatedOnce));
+        let hourly = CronSchedule::parse("30 * * * *").unwrap().next_runs(&New_York, at("2026-11-01T00:45:00-04:00"), 3);
+        assert_eq!(times(&hourly), ["2026-11-01 01:30-04:00", "2026-11-01 01:30-05:00", "2026-11-01 02:30-05:00"]);
+
+        let previous = daily.previous_runs(&New_York, at("2026-03-09T00:00:00-04:00"), 2);
+        assert_eq!(times(&previous), ["2026-03-08 03:00-04:00", "2026-03-07 02:30-05:00"]);
+        assert!(CronSchedule::parse("0 0 30 2 *").unwrap().next_runs(&New_York, at("2026-01-01T00:00:00Z"), 1).is_empty());
+
+        // The search stops at the ends of the supported dates
+        let noon = |date: NaiveDate| date.and_hms_opt(12, 0, 0).unwrap().and_utc().fixed_offset();
+        assert!(daily.next_runs(&chrono::Utc, noon(NaiveDate::MAX), 2).is_empty());
+        assert!(daily.previous_runs(&chrono::Utc, noon(NaiveDate::MIN), 2).len() <= 1);
+    }
+
+    #[test]
+    fn test_cron_for_round_trip() {
+        let start = NaiveDate::from_ymd_opt(2026, 11, 2).unwrap().and_hms_opt(9, 0, 0).unwrap();
+        let rule = Recurrence::parse("FREQ=MONTHLY;BYDAY=1MO;BYMONTHDAY=15").unwrap();
+        let (expression, notes) = cron_for(&rule, start);
+        assert_eq!(expression, "0 0 9 ? * 2#1");
+        assert_eq!(notes, ["Quartz cron takes a day of month or a day of week, not both; the day of month 15 is left out"]);
+        let cron = CronSchedule::parse(&expression).unwrap();
+        assert_eq!(cron.dialect, CronDialect::Quartz);
+        let runs = cron.next_runs(&New_York, at("2026-11-01T00:00:00Z"), 2);
+        assert_eq!(times(&runs), ["2026-11-02 09:00-05:00", "2026-12-07 09:00-05:00"]);
+
+        let rule = Recurrence::parse("FREQ=MONTHLY;BYMONTHDAY=-1;BYDAY=FR").unwrap();
+        assert_eq!(cron_for(&rule, start).0, "0 0 9 L * ?");
+    }
+}
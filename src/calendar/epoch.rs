@@ -0,0 +1,292 @@
+//! Raw timestamps: counts of seconds, ticks or days since an epoch, as
+//! written by Unix, Windows, .NET, spreadsheets, Mac HFS+, NTP and GPS.
+//! Values pass through whole nanoseconds since 1970-01-01, so no precision
+//! is lost in either direction.
+//!
+//! Excel serials count wall-clock days, so they are local time rather than
+//! UTC. Like Lotus 1-2-3 they include 29 February 1900, which never existed:
+//! serials before 60 are one day later than a plain count from 1899-12-30.
+//! GPS time does not repeat leap seconds, so it runs ahead of UTC.
+
+use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+const NANOS_PER_DAY: i128 = 86_400 * NANOS_PER_SECOND;
+/// Most decimal places read in a value
+const MAX_PLACES: u32 = 15;
+/// Months whose first day began after a leap second, since GPS time started
+const LEAP_SECONDS: [(i32, u32); 18] = [
+    (1981, 7), (1982, 7), (1983, 7), (1985, 7), (1988, 1), (1990, 1), (1991, 1), (1992, 7), (1993, 7),
+    (1994, 7), (1996, 1), (1997, 7), (1999, 1), (2006, 1), (2009, 1), (2012, 7), (2015, 7), (2017, 1),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimestampUnit {
+    UnixSeconds,
+    UnixMillis,
+    UnixMicros,
+    UnixNanos,
+    FileTime,
+    DotNetTicks,
+    Excel,
+    MacHfs,
+    Ntp,
+    Gps,
+}
+
+pub const UNITS: [TimestampUnit; 10] = [
+    TimestampUnit::UnixSeconds,
+    TimestampUnit::UnixMillis,
+    TimestampUnit::UnixMicros,
+    TimestampUnit::UnixNanos,
+    TimestampUnit::FileTime,
+    TimestampUnit::DotNetTicks,
+    TimestampUnit::Excel,
+    TimestampUnit::MacHfs,
+    TimestampUnit::Ntp,
+    TimestampUnit::Gps,
+];
+
+impl TimestampUnit {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::UnixSeconds => "unix_s",
+            Self::UnixMillis => "unix_ms",
+            Self::UnixMicros => "unix_us",
+            Self::UnixNanos => "unix_ns",
+            Self::FileTime => "filetime",
+            Self::DotNetTicks => "dotnet_ticks",
+            Self::Excel => "excel",
+            Self::MacHfs => "mac_hfs",
+            Self::Ntp => "ntp",
+            Self::Gps => "gps",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::UnixSeconds => "Seconds since 1970-01-01 UTC",
+            Self::UnixMillis => "Milliseconds since 1970-01-01 UTC",
+            Self::UnixMicros => "Microseconds since 1970-01-01 UTC",
+            Self::UnixNanos => "Nanoseconds since 1970-01-01 UTC",
+            Self::FileTime => "Windows FILETIME: 100-nanosecond intervals since 1601-01-01 UTC",
+            Self::DotNetTicks => ".NET DateTime ticks: 100-nanosecond intervals since 0001-01-01",
+            Self::Excel => "Excel/Lotus serial date: local days since 1899-12-30, counting 1900-02-29",
+            Self::MacHfs => "Mac HFS+ time: seconds since 1904-01-01 UTC",
+            Self::Ntp => "NTP time: seconds since 1900-01-01 UTC",
+            Self::Gps => "GPS time: seconds since 1980-01-06 without leap seconds",
+        }
+    }
+
+    /// A unit name, or a common alias such as "ms", "ticks" or "lotus"
+    pub fn parse(name: &str) -> Option<Self> {
+        let unit = match name.trim().to_lowercase().replace(['-', ' ', '.'], "_").as_str() {
+            "unix_s" | "unix" | "s" | "sec" | "secs" | "seconds" | "epoch" => Self::UnixSeconds,
+            "unix_ms" | "ms" | "millis" | "milliseconds" => Self::UnixMillis,
+            "unix_us" | "us" | "µs" | "micros" | "microseconds" => Self::UnixMicros,
+            "unix_ns" | "ns" | "nanos" | "nanoseconds" => Self::UnixNanos,
+            "filetime" | "windows" | "win32" | "windows_filetime" => Self::FileTime,
+            "dotnet_ticks" | "ticks" | "dotnet" | "_net" | "_net_ticks" => Self::DotNetTicks,
+            "excel" | "lotus" | "serial" | "excel_serial" | "oadate" => Self::Excel,
+            "mac_hfs" | "hfs" | "hfs+" | "mac" => Self::MacHfs,
+            "ntp" => Self::Ntp,
+            "gps" => Self::Gps,
+            _ => return None,
+        };
+        Some(unit)
+    }
+
+    /// The unit a number most likely is from its size, for dates near now:
+    /// serials are small, then seconds, milliseconds, microseconds,
+    /// FILETIMEs, ticks and nanoseconds grow in turn
+    pub fn detect(mantissa: i128, scale: u32) -> Self {
+        let size = (mantissa as f64 / 10f64.powi(scale as i32)).abs();
+        match size {
+            size if size < 1e6 => Self::Excel,
+            size if size < 1e11 => Self::UnixSeconds,
+            size if size < 1e14 => Self::UnixMillis,
+            size if size < 1e17 => Self::UnixMicros,
+            size if size < 3e17 => Self::FileTime,
+            size if size < 1e18 => Self::DotNetTicks,
+            _ => Self::UnixNanos,
+        }
+    }
+
+    /// Whether the unit counts local wall-clock time rather than UTC
+    pub fn is_local(self) -> bool {
+        self == Self::Excel
+    }
+
+    fn nanos_per_unit(self) -> i128 {
+        match self {
+            Self::UnixMillis => 1_000_000,
+            Self::UnixMicros => 1_000,
+            Self::UnixNanos => 1,
+            Self::FileTime | Self::DotNetTicks => 100,
+            Self::Excel => NANOS_PER_DAY,
+            Self::UnixSeconds | Self::MacHfs | Self::Ntp | Self::Gps => NANOS_PER_SECOND,
+        }
+    }
+
+    /// Nanoseconds from 1970-01-01 to the epoch
+    fn epoch(self) -> i128 {
+        let (year, month, day) = match self {
+            Self::FileTime => (1601, 1, 1),
+            Self::DotNetTicks => (1, 1, 1),
+            Self::Excel => (1899, 12, 30),
+            Self::MacHfs => (1904, 1, 1),
+            Self::Ntp => (1900, 1, 1),
+            Self::Gps => (1980, 1, 6),
+            _ => (1970, 1, 1),
+        };
+        unix_nanos(NaiveDate::from_ymd_opt(year, month, day).unwrap())
+    }
+
+    /// Nanoseconds since 1970-01-01 for `mantissa / 10^scale` of this unit,
+    /// on a local clock for local units
+    pub fn to_nanos(self, mantissa: i128, scale: u32) -> Result<i128> {
+        let per = self.nanos_per_unit();
+        let nanos = mantissa
+            .checked_mul(per)
+            .map(|n| n.div_euclid(10i128.pow(scale)))
+            .ok_or_else(|| anyhow!("The value is too large"))?;
+        let offset = match self {
+            Self::Excel if nanos < 0 => return Err(anyhow!("Excel serials start at 0, which Excel shows as 1900-01-00")),
+            Self::Excel if (60 * per..61 * per).contains(&nanos) => {
+                return Err(anyhow!("Excel serial 60 is 1900-02-29, a date that never existed (the Lotus 1-2-3 leap-year bug)"))
+            }
+            // Serials before the false 29 February count from a day later
+            Self::Excel if nanos < 60 * per => nanos + per,
+            Self::Gps => nanos - gps_leap_seconds(nanos) * NANOS_PER_SECOND,
+            _ => nanos,
+        };
+        Ok(self.epoch() + offset)
+    }
+
+    /// The value of this unit at `nanos` since 1970-01-01 as exact decimal
+    /// text, or `None` before an Excel serial can express
+    pub fn format_nanos(self, nanos: i128) -> Option<String> {
+        let per = self.nanos_per_unit();
+        let offset = nanos - self.epoch();
+        let offset = match self {
+            Self::Excel if offset < 61 * per => Some(offset - per).filter(|offset| *offset >= 0)?,
+            Self::Gps => offset + utc_leap_seconds(nanos) * NANOS_PER_SECOND,
+            _ => offset,
+        };
+        Some(decimal(offset, per))
+    }
+}
+
+/// A number as a mantissa and its decimal places: "1760862000.5",
+/// "-12", "1_760_862_000" or hexadecimal such as "0x01DC3F..."
+pub fn parse_number(text: &str) -> Option<(i128, u32)> {
+    let text = text.trim().replace('_', "");
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(&text)),
+    };
+    let (mantissa, scale) = match digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+        // from_str_radix would take a second sign after the prefix
+        Some(hex) if hex.chars().all(|c| c.is_ascii_hexdigit()) => (i128::from_str_radix(hex, 16).ok()?, 0),
+        Some(_) => return None,
+        None => {
+            let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+            let valid = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+            if whole.is_empty() && fraction.is_empty() || !valid(whole) || !valid(fraction) || fraction.len() > MAX_PLACES as usize {
+                return None;
+            }
+            (format!("{}{}", whole, fraction).parse().ok()?, fraction.len() as u32)
+        }
+    };
+    Some((if negative { -mantissa } else { mantissa }, scale))
+}
+
+fn unix_nanos(date: NaiveDate) -> i128 {
+    date.and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp() as i128 * NANOS_PER_SECOND
+}
+
+/// Leap seconds between GPS and UTC at a UTC instant
+fn utc_leap_seconds(nanos: i128) -> i128 {
+    LEAP_SECONDS.iter().filter(|(year, month)| unix_nanos(NaiveDate::from_ymd_opt(*year, *month, 1).unwrap()) <= nanos).count()
+        as i128
+}
+
+/// Leap seconds between GPS and UTC at a GPS time, in nanoseconds since the GPS epoch
+fn gps_leap_seconds(gps: i128) -> i128 {
+    let epoch = TimestampUnit::Gps.epoch();
+    (1..=LEAP_SECONDS.len() as i128)
+        .zip(LEAP_SECONDS)
+        .filter(|(count, (year, month))| {
+            unix_nanos(NaiveDate::from_ymd_opt(*year, *month, 1).unwrap()) - epoch + count * NANOS_PER_SECOND <= gps
+        })
+        .count() as i128
+}
+
+/// `nanos / per` as exact decimal text, or to 14 places for days
+fn decimal(nanos: i128, per: i128) -> String {
+    let sign = if nanos < 0 { "-" } else { "" };
+    let (whole, rest) = (nanos.abs() / per, nanos.abs() % per);
+    if rest == 0 {
+        return format!("{}{}", sign, whole);
+    }
+    // Exact for powers of ten; a day in nanoseconds needs 14 places to tell nanoseconds apart
+    let digits = per.to_string().len() as u32;
+    let places = if 10i128.pow(digits - 1) == per { digits - 1 } else { digits };
+    let fraction = format!("{:0width$}", rest * 10i128.pow(places) / per, width = places as usize);
+    format!("{}{}.{}", sign, whole, fraction.trim_end_matches('0'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nanos(text: &str) -> i128 {
+        chrono::DateTime::parse_from_rfc3339(text).unwrap().timestamp_nanos_opt().unwrap() as i128
+    }
+
+    #[test]
+    fn test_units_round_trip() {
+        let at = nanos("2026-10-19T09:30:00.25Z");
+        let cases = [
+            (TimestampUnit::UnixSeconds, "1792402200.25"),
+            (TimestampUnit::UnixMillis, "1792402200250"),
+            (TimestampUnit::UnixNanos, "1792402200250000000"),
+            (TimestampUnit::FileTime, "134368758002500000"),
+            (TimestampUnit::DotNetTicks, "639279990002500000"),
+            (TimestampUnit::Excel, "46314.39583622685185"),
+            (TimestampUnit::MacHfs, "3875247000.25"),
+            (TimestampUnit::Ntp, "4001391000.25"),
+            (TimestampUnit::Gps, "1476437418.25"),
+        ];
+        for (unit, value) in cases {
+            assert_eq!(unit.format_nanos(at).as_deref(), Some(value), "{}", unit.name());
+            let (mantissa, scale) = parse_number(value).unwrap();
+            let back = unit.to_nanos(mantissa, scale).unwrap();
+            assert!((back - at).abs() < 1_000, "{}", unit.name());
+            // Other counts of seconds are the size of Unix seconds
+            let detected = TimestampUnit::detect(mantissa, scale);
+            let seconds = matches!(unit, TimestampUnit::MacHfs | TimestampUnit::Ntp | TimestampUnit::Gps);
+            assert_eq!(detected, if seconds { TimestampUnit::UnixSeconds } else { unit }, "{}", unit.name());
+        }
+    }
+
+    #[test]
+    fn test_excel_leap_year_bug_and_numbers() {
+        let date = |y, m, d| unix_nanos(NaiveDate::from_ymd_opt(y, m, d).unwrap());
+        let excel = TimestampUnit::Excel;
+        assert_eq!(excel.to_nanos(1, 0).unwrap(), date(1900, 1, 1));
+        assert_eq!(excel.to_nanos(59, 0).unwrap(), date(1900, 2, 28));
+        assert_eq!(excel.to_nanos(61, 0).unwrap(), date(1900, 3, 1));
+        assert!(excel.to_nanos(60, 0).is_err());
+        assert_eq!(excel.format_nanos(date(1900, 2, 28)).as_deref(), Some("59"));
+        assert_eq!(excel.format_nanos(date(1899, 12, 30)), None);
+
+        assert_eq!(parse_number("-1_500.25"), Some((-150025, 2)));
+        assert_eq!(parse_number("0x10"), Some((16, 0)));
+        assert_eq!(parse_number("12a"), None);
+        assert_eq!(parse_number("0x-10"), None);
+        assert_eq!(parse_number("-0x-80000000000000000000000000000000"), None);
+        assert_eq!(decimal(-1_500_000_000, NANOS_PER_SECOND), "-1.5");
+    }
+}
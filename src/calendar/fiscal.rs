@@ -0,0 +1,217 @@
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, NaiveDate};
+
+use crate::types::duration::add_months;
+
+/// Environment variable naming the fiscal calendar used when parsing
+/// expressions like "FY27 Q2" without an explicit calendar
+pub const FISCAL_CALENDAR_ENV: &str = "UCM_FISCAL_CALENDAR";
+
+/// Named fiscal calendar profiles and their starting month
+pub const FISCAL_PROFILES: [(&str, u32); 6] = [
+    ("calendar", 1),
+    ("us_federal", 10),
+    ("uk", 4),
+    ("japan", 4),
+    ("australia", 7),
+    ("india", 4),
+];
+
+/// A fiscal calendar whose year starts on the first day of a given month
+#[derive(Debug, Clone, PartialEq)]
+pub struct FiscalCalendar {
+    pub name: String,
+    pub start_month: u32,
+    /// Whether a fiscal year is named after the calendar year it ends in
+    /// (US federal FY2027 starts 2026-10-01) or the year it starts in
+    /// (Japanese 2026年度 starts 2026-04-01)
+    pub named_by_end_year: bool,
+}
+
+/// Where a date falls within its fiscal year
+#[derive(Debug, Clone)]
+pub struct FiscalDate {
+    pub fiscal_year: i32,
+    pub quarter: u32,
+    pub period: u32,
+    pub week: u32,
+    pub year_start: NaiveDate,
+    pub year_end: NaiveDate,
+    pub quarter_start: NaiveDate,
+    pub quarter_end: NaiveDate,
+}
+
+impl FiscalCalendar {
+    /// Look up a named profile, or build a custom calendar from a month
+    /// name or number (e.g., "october", "oct", "10")
+    pub fn from_name(name: &str) -> Result<Self> {
+        let key = name.trim().to_lowercase().replace([' ', '-'], "_");
+        let key = match key.as_str() {
+            "us" | "us_government" | "federal" => "us_federal",
+            "gregorian" | "calendar_year" => "calendar",
+            other => other,
+        };
+
+        if let Some(&(profile, start_month)) = FISCAL_PROFILES.iter().find(|(p, _)| *p == key) {
+            return Ok(Self {
+                name: profile.to_string(),
+                start_month,
+                named_by_end_year: profile != "japan",
+            });
+        }
+
+        let start_month = parse_month(key).ok_or_else(|| {
+            anyhow!(
+                "Unknown fiscal calendar '{}'. Use a profile ({}) or a starting month (e.g., 'october', '10')",
+                name,
+                FISCAL_PROFILES.map(|(p, _)| p).join(", ")
+            )
+        })?;
+
+        Ok(Self {
+            name: format!("custom_{}", month_name(start_month)),
+            start_month,
+            named_by_end_year: true,
+        })
+    }
+
+    /// The calendar named by `UCM_FISCAL_CALENDAR`, falling back to calendar years
+    pub fn default_profile() -> Self {
+        std::env::var(FISCAL_CALENDAR_ENV)
+            .ok()
+            .and_then(|name| Self::from_name(&name).ok())
+            .unwrap_or_else(|| Self::from_name("calendar").unwrap())
+    }
+
+    /// First day of the given fiscal year
+    pub fn year_start(&self, fiscal_year: i32) -> Option<NaiveDate> {
+        let start_year = if self.named_by_end_year && self.start_month != 1 {
+            fiscal_year - 1
+        } else {
+            fiscal_year
+        };
+        NaiveDate::from_ymd_opt(start_year, self.start_month, 1)
+    }
+
+    /// Fiscal year containing a date
+    pub fn fiscal_year(&self, date: NaiveDate) -> i32 {
+        let start_year = if date.month() >= self.start_month {
+            date.year()
+        } else {
+            date.year() - 1
+        };
+
+        if self.named_by_end_year && self.start_month != 1 {
+            start_year + 1
+        } else {
+            start_year
+        }
+    }
+
+    /// First and last day of a fiscal quarter (1-4)
+    pub fn quarter_range(&self, fiscal_year: i32, quarter: u32) -> Result<(NaiveDate, NaiveDate)> {
+        if !(1..=4).contains(&quarter) {
+            return Err(anyhow!("Fiscal quarter must be 1-4, got {}", quarter));
+        }
+        self.month_range(fiscal_year, (quarter - 1) * 3, 3)
+    }
+
+    /// First and last day of a fiscal period (1-12)
+    pub fn period_range(&self, fiscal_year: i32, period: u32) -> Result<(NaiveDate, NaiveDate)> {
+        if !(1..=12).contains(&period) {
+            return Err(anyhow!("Fiscal period must be 1-12, got {}", period));
+        }
+        self.month_range(fiscal_year, period - 1, 1)
+    }
+
+    /// First and last day of a fiscal year
+    pub fn year_range(&self, fiscal_year: i32) -> Result<(NaiveDate, NaiveDate)> {
+        self.month_range(fiscal_year, 0, 12)
+    }
+
+    fn month_range(&self, fiscal_year: i32, offset: u32, months: u32) -> Result<(NaiveDate, NaiveDate)> {
+        let range = self.year_start(fiscal_year).and_then(|year_start| {
+            let start = add_months(year_start, offset as i32)?;
+            let end = add_months(start, months as i32)?.pred_opt()?;
+            Some((start, end))
+        });
+        range.ok_or_else(|| anyhow!("Fiscal year {} is out of range", fiscal_year))
+    }
+
+    /// Locate a date within its fiscal year
+    pub fn locate(&self, date: NaiveDate) -> Result<FiscalDate> {
+        let fiscal_year = self.fiscal_year(date);
+        let (year_start, year_end) = self.year_range(fiscal_year)?;
+        let period = (date.month() + 12 - self.start_month) % 12 + 1;
+        let quarter = (period - 1) / 3 + 1;
+        let (quarter_start, quarter_end) = self.quarter_range(fiscal_year, quarter)?;
+
+        Ok(FiscalDate {
+            fiscal_year,
+            quarter,
+            period,
+            week: ((date - year_start).num_days() / 7) as u32 + 1,
+            year_start,
+            year_end,
+            quarter_start,
+            quarter_end,
+        })
+    }
+}
+
+/// Parse a month name, three-letter abbreviation or number
+pub fn parse_month(name: &str) -> Option<u32> {
+    if let Ok(month) = name.parse::<u32>() {
+        return (1..=12).contains(&month).then_some(month);
+    }
+
+    (1..=12).find(|&m| {
+        let full = month_name(m);
+        name == full || (name.len() >= 3 && full.starts_with(name))
+    })
+}
+
+/// Lowercase English month name for 1-12
+pub fn month_name(month: u32) -> &'static str {
+    [
+        "january", "february", "march", "april", "may", "june", "july", "august",
+        "september", "october", "november", "december",
+    ][(month - 1) as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_us_federal_fiscal_year() {
+        let calendar = FiscalCalendar::from_name("us_federal").unwrap();
+        let fiscal = calendar.locate(date(2026, 10, 18)).unwrap();
+        assert_eq!(fiscal.fiscal_year, 2027);
+        assert_eq!(fiscal.quarter, 1);
+        assert_eq!(fiscal.period, 1);
+        assert_eq!(fiscal.week, 3);
+        assert_eq!(fiscal.year_end, date(2027, 9, 30));
+    }
+
+    #[test]
+    fn test_japan_named_by_start_year() {
+        let calendar = FiscalCalendar::from_name("japan").unwrap();
+        let fiscal = calendar.locate(date(2027, 2, 1)).unwrap();
+        assert_eq!(fiscal.fiscal_year, 2026);
+        assert_eq!(fiscal.quarter, 4);
+        assert_eq!(fiscal.period, 11);
+    }
+
+    #[test]
+    fn test_custom_start_month() {
+        let calendar = FiscalCalendar::from_name("Feb").unwrap();
+        assert_eq!(calendar.start_month, 2);
+        assert_eq!(calendar.quarter_range(2027, 2).unwrap(), (date(2026, 5, 1), date(2026, 7, 31)));
+        assert!(FiscalCalendar::from_name("lunar").is_err());
+    }
+}
@@ -0,0 +1,124 @@
+use anyhow::{anyhow, Result};
+use chrono::{NaiveTime, Weekday};
+
+use crate::parser::natural::parse_time_of_day;
+
+/// The hours of the day and days of the week free slots are looked for in
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkingHours {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+    pub days: Vec<Weekday>,
+}
+
+impl Default for WorkingHours {
+    /// 09:00-17:00, Monday to Friday
+    fn default() -> Self {
+        Self {
+            start: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            days: vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri],
+        }
+    }
+}
+
+impl WorkingHours {
+    /// Hours like "08:30-16:00" and days like "mon-fri" or "sun,tue,thu",
+    /// each defaulting to the standard working week
+    pub fn parse(hours: Option<&str>, days: Option<&str>) -> Result<Self> {
+        let mut profile = Self::default();
+        if let Some(hours) = hours {
+            (profile.start, profile.end) = parse_time_range(hours)?;
+        }
+        if let Some(days) = days {
+            profile.days = parse_weekdays(days)?;
+        }
+        Ok(profile)
+    }
+}
+
+/// "09:00-17:00", "9am-5pm" or "12:00 - 13:00"
+pub fn parse_time_range(text: &str) -> Result<(NaiveTime, NaiveTime)> {
+    let invalid = || anyhow!("Invalid time range '{}'; expected e.g. '09:00-17:00' or '9am-5pm'", text);
+    let (start, end) = text.split_once('-').ok_or_else(invalid)?;
+    let start = parse_time_of_day(start).ok_or_else(invalid)?;
+    let end = parse_time_of_day(end).ok_or_else(invalid)?;
+    if end <= start {
+        return Err(anyhow!("Time range '{}' must end after it starts", text));
+    }
+    Ok((start, end))
+}
+
+/// "mon-fri", "sun-thu" (ranges wrap around the week) or "mon,wed,fri"
+pub fn parse_weekdays(text: &str) -> Result<Vec<Weekday>> {
+    let weekday = |name: &str| {
+        name.trim().parse::<Weekday>().map_err(|_| anyhow!("Invalid weekday '{}'; expected e.g. 'mon-fri'", name.trim()))
+    };
+    let mut days = Vec::new();
+    for part in text.split(',').filter(|part| !part.trim().is_empty()) {
+        match part.split_once('-') {
+            Some((first, last)) => {
+                let (mut day, last) = (weekday(first)?, weekday(last)?);
+                days.push(day);
+                while day != last {
+                    day = day.succ();
+                    days.push(day);
+                }
+            }
+            None => days.push(weekday(part)?),
+        }
+    }
+    if days.is_empty() {
+        return Err(anyhow!("No weekdays in '{}'", text));
+    }
+    Ok(days)
+}
+
+/// Sort intervals and join those that overlap or touch
+pub fn merge<T: Ord + Copy>(mut intervals: Vec<(T, T)>) -> Vec<(T, T)> {
+    intervals.sort();
+    let mut merged: Vec<(T, T)> = Vec::new();
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// The parts of `free` not covered by `busy`, which must be merged
+pub fn subtract<T: Ord + Copy>(free: &[(T, T)], busy: &[(T, T)]) -> Vec<(T, T)> {
+    let mut result = Vec::new();
+    for &(start, end) in free {
+        let mut cursor = start;
+        for &(busy_start, busy_end) in busy.iter().filter(|(s, e)| *s < end && *e > start) {
+            if busy_start > cursor {
+                result.push((cursor, busy_start));
+            }
+            cursor = cursor.max(busy_end);
+        }
+        if cursor < end {
+            result.push((cursor, end));
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_working_hours_and_intervals() {
+        let hours = WorkingHours::parse(Some("8:30-4pm"), Some("sun-tue,thu")).unwrap();
+        assert_eq!(hours.start, NaiveTime::from_hms_opt(8, 30, 0).unwrap());
+        assert_eq!(hours.days, [Weekday::Sun, Weekday::Mon, Weekday::Tue, Weekday::Thu]);
+        assert!(parse_time_range("17:00-09:00").is_err());
+        assert!(parse_weekdays("mon-funday").is_err());
+
+        let busy = merge(vec![(10, 12), (11, 13), (15, 16), (16, 17)]);
+        assert_eq!(busy, [(10, 13), (15, 17)]);
+        assert_eq!(subtract(&[(9, 18)], &busy), [(9, 10), (13, 15), (17, 18)]);
+    }
+}
@@ -0,0 +1,296 @@
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+/// How a holiday's date is determined each year
+#[derive(Debug, Clone, Copy)]
+enum Rule {
+    /// A fixed month and day
+    Fixed(u32, u32),
+    /// The nth weekday of a month; a negative n counts from the end
+    NthWeekday(u32, Weekday, i32),
+    /// The last weekday strictly before a month and day
+    WeekdayBefore(u32, u32, Weekday),
+    /// Days relative to Easter Sunday
+    Easter(i64),
+}
+
+/// What happens when a holiday falls on a weekend
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Observance {
+    /// No substitute day
+    None,
+    /// Saturday moves to Friday, Sunday to Monday (US federal)
+    NearestWeekday,
+    /// Moves to the next weekday not already taken by another holiday
+    NextFreeWeekday,
+}
+
+#[derive(Debug)]
+struct HolidayRule {
+    name: &'static str,
+    rule: Rule,
+    observance: Observance,
+    /// First year the holiday was observed
+    since: i32,
+}
+
+const fn holiday(name: &'static str, rule: Rule, observance: Observance) -> HolidayRule {
+    HolidayRule {
+        name,
+        rule,
+        observance,
+        since: i32::MIN,
+    }
+}
+
+const fn holiday_since(name: &'static str, rule: Rule, observance: Observance, since: i32) -> HolidayRule {
+    HolidayRule {
+        name,
+        rule,
+        observance,
+        since,
+    }
+}
+
+/// A country's public holiday calendar
+#[derive(Debug)]
+pub struct Country {
+    pub code: &'static str,
+    pub name: &'static str,
+    rules: &'static [HolidayRule],
+}
+
+/// A holiday in a given year
+#[derive(Debug, Clone, PartialEq)]
+pub struct Holiday {
+    pub name: &'static str,
+    pub date: NaiveDate,
+    /// Substitute day off when the holiday falls on a weekend
+    pub observed: Option<NaiveDate>,
+}
+
+use Observance::{NearestWeekday, NextFreeWeekday};
+use Rule::{Easter, Fixed, NthWeekday, WeekdayBefore};
+
+pub const COUNTRIES: [Country; 4] = [
+    Country {
+        code: "US",
+        name: "United States (federal)",
+        rules: &[
+            holiday("New Year's Day", Fixed(1, 1), NearestWeekday),
+            holiday("Martin Luther King Jr. Day", NthWeekday(1, Weekday::Mon, 3), Observance::None),
+            holiday("Washington's Birthday", NthWeekday(2, Weekday::Mon, 3), Observance::None),
+            holiday("Memorial Day", NthWeekday(5, Weekday::Mon, -1), Observance::None),
+            holiday_since("Juneteenth", Fixed(6, 19), NearestWeekday, 2021),
+            holiday("Independence Day", Fixed(7, 4), NearestWeekday),
+            holiday("Labor Day", NthWeekday(9, Weekday::Mon, 1), Observance::None),
+            holiday("Columbus Day", NthWeekday(10, Weekday::Mon, 2), Observance::None),
+            holiday("Veterans Day", Fixed(11, 11), NearestWeekday),
+            holiday("Thanksgiving Day", NthWeekday(11, Weekday::Thu, 4), Observance::None),
+            holiday("Christmas Day", Fixed(12, 25), NearestWeekday),
+        ],
+    },
+    Country {
+        code: "GB",
+        name: "United Kingdom (England and Wales)",
+        rules: &[
+            holiday("New Year's Day", Fixed(1, 1), NextFreeWeekday),
+            holiday("Good Friday", Easter(-2), Observance::None),
+            holiday("Easter Monday", Easter(1), Observance::None),
+            holiday("Early May Bank Holiday", NthWeekday(5, Weekday::Mon, 1), Observance::None),
+            holiday("Spring Bank Holiday", NthWeekday(5, Weekday::Mon, -1), Observance::None),
+            holiday("Summer Bank Holiday", NthWeekday(8, Weekday::Mon, -1), Observance::None),
+            holiday("Christmas Day", Fixed(12, 25), NextFreeWeekday),
+            holiday("Boxing Day", Fixed(12, 26), NextFreeWeekday),
+        ],
+    },
+    Country {
+        code: "CA",
+        name: "Canada (federal)",
+        rules: &[
+            holiday("New Year's Day", Fixed(1, 1), NextFreeWeekday),
+            holiday("Good Friday", Easter(-2), Observance::None),
+            holiday("Victoria Day", WeekdayBefore(5, 25, Weekday::Mon), Observance::None),
+            holiday("Canada Day", Fixed(7, 1), NextFreeWeekday),
+            holiday("Labour Day", NthWeekday(9, Weekday::Mon, 1), Observance::None),
+            holiday_since(
+                "National Day for Truth and Reconciliation",
+                Fixed(9, 30),
+                NextFreeWeekday,
+                2021,
+            ),
+            holiday("Thanksgiving", NthWeekday(10, Weekday::Mon, 2), Observance::None),
+            holiday("Remembrance Day", Fixed(11, 11), NextFreeWeekday),
+            holiday("Christmas Day", Fixed(12, 25), NextFreeWeekday),
+            holiday("Boxing Day", Fixed(12, 26), NextFreeWeekday),
+        ],
+    },
+    Country {
+        code: "AU",
+        name: "Australia (national)",
+        rules: &[
+            holiday("New Year's Day", Fixed(1, 1), NextFreeWeekday),
+            holiday("Australia Day", Fixed(1, 26), NextFreeWeekday),
+            holiday("Good Friday", Easter(-2), Observance::None),
+            holiday("Easter Monday", Easter(1), Observance::None),
+            holiday("Anzac Day", Fixed(4, 25), Observance::None),
+            holiday("Christmas Day", Fixed(12, 25), NextFreeWeekday),
+            holiday("Boxing Day", Fixed(12, 26), NextFreeWeekday),
+        ],
+    },
+];
+
+/// Look up a country by ISO 3166 code ("UK" is accepted for "GB")
+pub fn find_country(code: &str) -> Result<&'static Country> {
+    let upper = code.trim().to_uppercase();
+    let upper = if upper == "UK" { "GB".to_string() } else { upper };
+    COUNTRIES.iter().find(|c| c.code == upper).ok_or_else(|| {
+        let codes: Vec<&str> = COUNTRIES.iter().map(|c| c.code).collect();
+        anyhow!("Unknown country '{}'. Supported: {}", code, codes.join(", "))
+    })
+}
+
+/// Easter Sunday in the Gregorian calendar (anonymous Gregorian algorithm),
+/// or None outside the supported years
+pub fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+impl Rule {
+    fn date(self, year: i32) -> Option<NaiveDate> {
+        match self {
+            Fixed(month, day) => NaiveDate::from_ymd_opt(year, month, day),
+            NthWeekday(month, weekday, n) if n > 0 => {
+                NaiveDate::from_weekday_of_month_opt(year, month, weekday, n as u8)
+            }
+            NthWeekday(month, weekday, _) => {
+                let (next_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+                let mut date = NaiveDate::from_ymd_opt(next_year, next_month, 1)?.pred_opt()?;
+                while date.weekday() != weekday {
+                    date = date.pred_opt()?;
+                }
+                Some(date)
+            }
+            WeekdayBefore(month, day, weekday) => {
+                let mut date = NaiveDate::from_ymd_opt(year, month, day)?.pred_opt()?;
+                while date.weekday() != weekday {
+                    date = date.pred_opt()?;
+                }
+                Some(date)
+            }
+            Easter(offset) => easter_sunday(year)?.checked_add_signed(Duration::days(offset)),
+        }
+    }
+}
+
+impl Country {
+    /// Whether a date is a public holiday or the observed day off for one
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        // A New Year's Day on Saturday can be observed on December 31
+        [date.year(), date.year() + 1].iter().any(|&year| {
+            self.holidays(year)
+                .iter()
+                .any(|holiday| holiday.date == date || holiday.observed == Some(date))
+        })
+    }
+
+    /// Holidays for a year, in date order
+    pub fn holidays(&self, year: i32) -> Vec<Holiday> {
+        let mut holidays: Vec<(Holiday, Observance)> = self
+            .rules
+            .iter()
+            .filter(|rule| year >= rule.since)
+            .filter_map(|rule| {
+                let date = rule.rule.date(year)?;
+                Some((Holiday { name: rule.name, date, observed: None }, rule.observance))
+            })
+            .collect();
+        holidays.sort_by_key(|(holiday, _)| holiday.date);
+
+        // Substitutes are assigned in date order so that e.g. Christmas on a
+        // Saturday takes Monday and Boxing Day takes Tuesday
+        let mut taken: Vec<NaiveDate> = holidays
+            .iter()
+            .filter(|(holiday, _)| !is_weekend(holiday.date))
+            .map(|(holiday, _)| holiday.date)
+            .collect();
+        for (holiday, observance) in holidays.iter_mut() {
+            if !is_weekend(holiday.date) {
+                continue;
+            }
+            holiday.observed = match observance {
+                Observance::None => None,
+                NearestWeekday if holiday.date.weekday() == Weekday::Sat => holiday.date.pred_opt(),
+                NearestWeekday => holiday.date.succ_opt(),
+                NextFreeWeekday => {
+                    let mut date = holiday.date.succ_opt();
+                    while let Some(day) = date.filter(|day| is_weekend(*day) || taken.contains(day)) {
+                        date = day.succ_opt();
+                    }
+                    taken.extend(date);
+                    date
+                }
+            };
+        }
+
+        holidays.into_iter().map(|(holiday, _)| holiday).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_easter_sunday() {
+        assert_eq!(easter_sunday(2024), Some(date(2024, 3, 31)));
+        assert_eq!(easter_sunday(2026), Some(date(2026, 4, 5)));
+        assert_eq!(easter_sunday(2038), Some(date(2038, 4, 25)));
+        assert_eq!(easter_sunday(NaiveDate::MAX.year() + 1), None);
+        // The last day also looks at the holidays of the year after
+        assert!(!find_country("GB").unwrap().is_holiday(NaiveDate::MAX));
+    }
+
+    #[test]
+    fn test_us_holidays_2026() {
+        let holidays = find_country("us").unwrap().holidays(2026);
+        assert_eq!(holidays.len(), 11);
+        let independence = holidays.iter().find(|h| h.name == "Independence Day").unwrap();
+        assert_eq!(independence.observed, Some(date(2026, 7, 3)));
+        let thanksgiving = holidays.iter().find(|h| h.name == "Thanksgiving Day").unwrap();
+        assert_eq!(thanksgiving.date, date(2026, 11, 26));
+        assert_eq!(holidays[3].date, date(2026, 5, 25));
+    }
+
+    #[test]
+    fn test_uk_christmas_substitutes() {
+        let holidays = find_country("UK").unwrap().holidays(2027);
+        let christmas = holidays.iter().find(|h| h.name == "Christmas Day").unwrap();
+        let boxing = holidays.iter().find(|h| h.name == "Boxing Day").unwrap();
+        assert_eq!(christmas.observed, Some(date(2027, 12, 27)));
+        assert_eq!(boxing.observed, Some(date(2027, 12, 28)));
+        assert!(find_country("XX").is_err());
+    }
+}
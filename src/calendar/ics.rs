@@ -0,0 +1,283 @@
+//! iCalendar (RFC 5545) files, read and written as [`Event`]s.
+//!
+//! Zoned times are written with a TZID naming the IANA zone and no
+//! VTIMEZONE component, which calendar clients resolve by name. Reading
+//! likewise expects IANA TZIDs; VTIMEZONE definitions and EXDATEs are
+//! ignored.
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+
+use crate::calendar::recurrence::Recurrence;
+use crate::store::Event;
+
+/// Longest content line in octets before it is folded
+const LINE_LIMIT: usize = 75;
+
+/// A VCALENDAR with one VEVENT per event; events without a time zone use
+/// `default_timezone`, or floating local time when that is `None` too
+pub fn write_calendar(events: &[Event], default_timezone: Option<&str>, stamp: DateTime<Utc>) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//UCM//Universal Calendar Manager//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+    for event in events {
+        let timezone = event.timezone.as_deref().or(default_timezone);
+        let time = |name: &str, value: chrono::NaiveDateTime| match (event.all_day, timezone) {
+            (true, _) => format!("{};VALUE=DATE:{}", name, value.format("%Y%m%d")),
+            (false, Some(tz)) => format!("{};TZID={}:{}", name, tz, value.format("%Y%m%dT%H%M%S")),
+            (false, None) => format!("{}:{}", name, value.format("%Y%m%dT%H%M%S")),
+        };
+
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}@ucm", event.id));
+        lines.push(format!("DTSTAMP:{}", stamp.format("%Y%m%dT%H%M%SZ")));
+        lines.push(format!("SUMMARY:{}", escape(&event.title)));
+        lines.push(time("DTSTART", event.start));
+        lines.push(time("DTEND", event.end));
+        if let Some(rule) = &event.recurrence {
+            lines.push(format!("RRULE:{}", rule));
+        }
+        if !event.tags.is_empty() {
+            let tags: Vec<String> = event.tags.iter().map(|tag| escape(tag)).collect();
+            lines.push(format!("CATEGORIES:{}", tags.join(",")));
+        }
+        if let Some(note) = &event.note {
+            lines.push(format!("DESCRIPTION:{}", escape(note)));
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold(line) + "\r\n").collect()
+}
+
+/// The VEVENTs of an iCalendar file; cancelled and transparent (free)
+/// events are left out
+pub fn read_calendar(text: &str) -> Result<Vec<Event>> {
+    // Unfold continuation lines, which start with a space or tab
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+
+    let mut events = Vec::new();
+    let mut current: Option<Vec<(String, String, String)>> = None;
+    for (index, line) in lines.iter().enumerate() {
+        let Some((name_params, value)) = line.split_once(':') else {
+            continue;
+        };
+        let (name, params) = name_params.split_once(';').unwrap_or((name_params, ""));
+        match (name.to_uppercase().as_str(), value.trim()) {
+            ("BEGIN", "VEVENT") => current = Some(Vec::new()),
+            ("END", "VEVENT") => {
+                let properties = current.take().ok_or_else(|| anyhow!("Line {}: END:VEVENT without BEGIN", index + 1))?;
+                if let Some(event) = read_event(&properties, events.len() + 1)
+                    .map_err(|e| anyhow!("Event ending on line {}: {}", index + 1, e))?
+                {
+                    events.push(event);
+                }
+            }
+            (name, _) => {
+                if let Some(properties) = current.as_mut() {
+                    properties.push((name.to_string(), params.to_string(), value.to_string()));
+                }
+            }
+        }
+    }
+    Ok(events)
+}
+
+fn read_event(properties: &[(String, String, String)], number: usize) -> Result<Option<Event>> {
+    let get = |name: &str| properties.iter().find(|(n, _, _)| n == name);
+    let value = |name: &str| get(name).map(|(_, _, value)| value.trim());
+    if value("STATUS").is_some_and(|s| s.eq_ignore_ascii_case("CANCELLED"))
+        || value("TRANSP").is_some_and(|t| t.eq_ignore_ascii_case("TRANSPARENT"))
+    {
+        return Ok(None);
+    }
+
+    let (_, params, start) = get("DTSTART").ok_or_else(|| anyhow!("no DTSTART"))?;
+    let (start, all_day, timezone) = read_time(params, start)?;
+    let end = match (get("DTEND"), value("DURATION")) {
+        (Some((_, params, end)), _) => read_time(params, end)?.0,
+        (None, Some(duration)) => {
+            start.checked_add_signed(read_duration(duration)?).ok_or_else(|| anyhow!("DURATION '{}' ends too late", duration))?
+        }
+        (None, None) if all_day => start.checked_add_signed(Duration::days(1)).ok_or_else(|| anyhow!("DTSTART is too late"))?,
+        (None, None) => start,
+    };
+    let recurrence = value("RRULE").map(|rule| Recurrence::parse_rrule(rule).map(|r| r.to_rrule())).transpose()?;
+    let tags = value("CATEGORIES")
+        .map(|tags| tags.split(',').map(|tag| unescape(tag).trim().to_lowercase()).filter(|t| !t.is_empty()).collect())
+        .unwrap_or_default();
+
+    Ok(Some(Event {
+        id: value("UID").map_or_else(|| format!("ics-{}", number), str::to_string),
+        title: value("SUMMARY").map(unescape).unwrap_or_default(),
+        start,
+        end,
+        all_day,
+        timezone,
+        recurrence,
+        tags,
+        note: value("DESCRIPTION").map(unescape),
+    }))
+}
+
+/// A DATE or DATE-TIME value with whether it is a date, and its zone: the
+/// TZID parameter, or UTC for times ending in Z
+pub fn read_time(params: &str, value: &str) -> Result<(NaiveDateTime, bool, Option<String>)> {
+    let value = value.trim();
+    let param = |key: &str| {
+        params.split(';').find_map(|param| {
+            let (name, value) = param.split_once('=')?;
+            name.eq_ignore_ascii_case(key).then(|| value.trim_matches('"'))
+        })
+    };
+    if param("VALUE").is_some_and(|v| v.eq_ignore_ascii_case("DATE")) || value.len() == 8 {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").map_err(|_| anyhow!("invalid date '{}'", value))?;
+        return Ok((date.into(), true, None));
+    }
+    let (local, utc) = match value.strip_suffix('Z') {
+        Some(local) => (local, true),
+        None => (value, false),
+    };
+    let time = NaiveDateTime::parse_from_str(local, "%Y%m%dT%H%M%S").map_err(|_| anyhow!("invalid date-time '{}'", value))?;
+    let timezone = param("TZID").map(str::to_string).or_else(|| utc.then(|| "UTC".to_string()));
+    Ok((time, false, timezone))
+}
+
+/// A DURATION value such as PT1H30M or P1D
+fn read_duration(value: &str) -> Result<Duration> {
+    let invalid = || anyhow!("invalid DURATION '{}'", value);
+    let (sign, body) = match value.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let body = body.strip_prefix('P').ok_or_else(invalid)?;
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    for c in body.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'T' => {}
+            'W' | 'D' | 'H' | 'M' | 'S' => {
+                let n: i64 = number.parse().map_err(|_| invalid())?;
+                number.clear();
+                let part = match c {
+                    'W' => Duration::try_weeks(n),
+                    'D' => Duration::try_days(n),
+                    'H' => Duration::try_hours(n),
+                    'M' => Duration::try_minutes(n),
+                    _ => Duration::try_seconds(n),
+                };
+                total = part.and_then(|part| total.checked_add(&part)).ok_or_else(invalid)?;
+            }
+            _ => return Err(invalid()),
+        }
+    }
+    Ok(total * sign)
+}
+
+fn unescape(text: &str) -> String {
+    let mut out = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n' | 'N') => out.push('\n'),
+                Some(other) => out.push(other),
+                None => {}
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Escape a TEXT value
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Split a line into 75-octet pieces joined by CRLF and a space, never
+/// inside a UTF-8 character
+fn fold(line: &str) -> String {
+    let mut out = String::new();
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > LINE_LIMIT {
+            out.push_str("\r\n ");
+            // The leading space counts towards the next line
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone};
+
+    #[test]
+    fn test_write_calendar() {
+        let start = NaiveDate::from_ymd_opt(2026, 10, 20).unwrap().and_hms_opt(9, 0, 0).unwrap();
+        let event = Event {
+            id: "evt-1".to_string(),
+            title: "Standup; daily, short".to_string(),
+            start,
+            end: start + chrono::Duration::minutes(15),
+            all_day: false,
+            timezone: Some("Europe/London".to_string()),
+            recurrence: Some("FREQ=WEEKLY;BYDAY=MO,TU".to_string()),
+            tags: vec!["work".to_string()],
+            note: Some("x".repeat(100)),
+        };
+        let ics = write_calendar(&[event], None, Utc.with_ymd_and_hms(2026, 10, 18, 0, 0, 0).unwrap());
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.contains("SUMMARY:Standup\\; daily\\, short\r\n"));
+        assert!(ics.contains("DTSTART;TZID=Europe/London:20261020T090000\r\n"));
+        assert!(ics.contains("RRULE:FREQ=WEEKLY;BYDAY=MO,TU\r\n"));
+        assert!(ics.lines().all(|line| line.len() <= LINE_LIMIT));
+        assert!(ics.contains("\r\n xxxx"));
+
+        let events = read_calendar(&ics).unwrap();
+        assert_eq!(events[0].title, "Standup; daily, short");
+        assert_eq!(events[0].note.as_deref().map(str::len), Some(100));
+        assert_eq!(events[0].timezone.as_deref(), Some("Europe/London"));
+    }
+
+    #[test]
+    fn test_read_calendar() {
+        let ics = "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nUID:a\r\nSUMMARY:Flight\r\nDTSTART:20261020T083000Z\r\n\
+                   DURATION:PT2H15M\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20261021\r\n\
+                   END:VEVENT\r\nBEGIN:VEVENT\r\nDTSTART:20261022T090000\r\nTRANSP:TRANSPARENT\r\n\
+                   END:VEVENT\r\nEND:VCALENDAR\r\n";
+        let events = read_calendar(ics).unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].timezone.as_deref(), Some("UTC"));
+        assert_eq!((events[0].end - events[0].start).num_minutes(), 135);
+        assert!(events[1].all_day);
+        assert_eq!(events[1].id, "ics-2");
+
+        assert!(read_calendar("BEGIN:VEVENT\r\nSUMMARY:x\r\nEND:VEVENT\r\n").is_err());
+        assert!(read_duration("P99999999999999W").is_err());
+        let late = "BEGIN:VEVENT\r\nDTSTART:20261020T083000Z\r\nDURATION:P99999999D\r\nEND:VEVENT\r\n";
+        assert!(read_calendar(late).is_err());
+    }
+}
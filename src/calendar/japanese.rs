@@ -0,0 +1,275 @@
+use std::sync::OnceLock;
+
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, NaiveDate};
+use serde::Deserialize;
+
+use crate::types::UcmError;
+
+/// Environment variable pointing at a JSON file of additional eras
+pub const ERAS_FILE_ENV: &str = "UCM_ERAS_FILE";
+
+/// A Japanese imperial era (gengō)
+#[derive(Debug, Clone, Deserialize)]
+pub struct Era {
+    /// Romanized name without macrons (e.g., "Reiwa", "Showa")
+    pub name: String,
+    /// Kanji name (e.g., "令和")
+    pub kanji: String,
+    /// Single-letter abbreviation used in short dates (e.g., "R" in "R8.10.18")
+    pub abbreviation: String,
+    /// First Gregorian day of the era
+    pub start: NaiveDate,
+}
+
+/// A date expressed in a Japanese era
+#[derive(Debug, Clone)]
+pub struct EraDate<'a> {
+    pub era: &'a Era,
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl EraDate<'_> {
+    /// Format as "令和8年10月18日", using 元年 for the first year
+    pub fn to_kanji_string(&self) -> String {
+        let year = if self.year == 1 {
+            "元".to_string()
+        } else {
+            self.year.to_string()
+        };
+        format!("{}{}年{}月{}日", self.era.kanji, year, self.month, self.day)
+    }
+
+    /// Format as "R8.10.18"
+    pub fn to_short_string(&self) -> String {
+        format!(
+            "{}{}.{}.{}",
+            self.era.abbreviation, self.year, self.month, self.day
+        )
+    }
+}
+
+/// Built-in eras from Meiji through Reiwa.
+///
+/// Meiji starts on its 1868-10-23 proclamation; dates before Japan adopted
+/// the Gregorian calendar (1873-01-01) are treated as proleptic Gregorian.
+fn builtin_eras() -> Vec<Era> {
+    vec![
+        builtin("Meiji", "明治", "M", 1868, 10, 23),
+        builtin("Taisho", "大正", "T", 1912, 7, 30),
+        builtin("Showa", "昭和", "S", 1926, 12, 25),
+        builtin("Heisei", "平成", "H", 1989, 1, 8),
+        builtin("Reiwa", "令和", "R", 2019, 5, 1),
+    ]
+}
+
+fn builtin(name: &str, kanji: &str, abbreviation: &str, year: i32, month: u32, day: u32) -> Era {
+    Era {
+        name: name.to_string(),
+        kanji: kanji.to_string(),
+        abbreviation: abbreviation.to_string(),
+        start: NaiveDate::from_ymd_opt(year, month, day).unwrap(),
+    }
+}
+
+/// Append registered eras to the built-in table.
+///
+/// Registered eras must begin after the last built-in era so that the
+/// historical table cannot be rewritten.
+fn merge_eras(mut eras: Vec<Era>, registered: Vec<Era>) -> Result<Vec<Era>> {
+    let last_start = eras.last().map(|e| e.start);
+
+    for era in registered {
+        if Some(era.start) <= last_start {
+            return Err(anyhow!(
+                "Registered era '{}' must start after {}",
+                era.name,
+                last_start.unwrap()
+            ));
+        }
+        if era.name.is_empty() || era.kanji.is_empty() || era.abbreviation.is_empty() {
+            return Err(anyhow!(
+                "Registered era starting {} needs a name, kanji and abbreviation",
+                era.start
+            ));
+        }
+        eras.push(era);
+    }
+
+    eras.sort_by_key(|e| e.start);
+    Ok(eras)
+}
+
+fn load_registered_eras(path: &str) -> Result<Vec<Era>> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| anyhow!("Could not read era file '{}': {}", path, e))?;
+    serde_json::from_str(&contents)
+        .map_err(|e| anyhow!("Invalid era file '{}': {}", path, e))
+}
+
+/// All known eras, oldest first.
+///
+/// Future eras can be registered without a code change by pointing
+/// `UCM_ERAS_FILE` at a JSON array such as
+/// `[{"name": "Example", "kanji": "例", "abbreviation": "X", "start": "2050-01-01"}]`.
+pub fn eras() -> &'static [Era] {
+    static ERAS: OnceLock<Vec<Era>> = OnceLock::new();
+
+    ERAS.get_or_init(|| {
+        let builtin = builtin_eras();
+        let Ok(path) = std::env::var(ERAS_FILE_ENV) else {
+            return builtin;
+        };
+
+        match load_registered_eras(&path).and_then(|r| merge_eras(builtin_eras(), r)) {
+            Ok(eras) => eras,
+            Err(e) => {
+                eprintln!("UCM: ignoring {}: {}", ERAS_FILE_ENV, e);
+                builtin
+            }
+        }
+    })
+}
+
+/// Convert a Gregorian date to its Japanese era date.
+///
+/// Returns `None` for dates before the Meiji era.
+pub fn to_era_date(date: NaiveDate) -> Option<EraDate<'static>> {
+    let era = eras().iter().rev().find(|e| e.start <= date)?;
+
+    Some(EraDate {
+        era,
+        year: date.year() - era.start.year() + 1,
+        month: date.month(),
+        day: date.day(),
+    })
+}
+
+/// Convert an era year/month/day to a Gregorian date, checking that the
+/// date actually falls within the era.
+pub fn from_era_date(era: &Era, year: i32, month: u32, day: u32) -> Result<NaiveDate> {
+    if year < 1 {
+        return Err(anyhow!("Era year must be 1 or greater, got {}", year));
+    }
+
+    let gregorian_year = era
+        .start
+        .year()
+        .checked_add(year - 1)
+        .filter(|year| (NaiveDate::MIN.year()..=NaiveDate::MAX.year()).contains(year))
+        .ok_or_else(|| {
+            let input = format!("{} {}", era.name, year);
+            UcmError::out_of_range(&input, &format!("{} is outside the supported date range", input))
+        })?;
+    let date = NaiveDate::from_ymd_opt(gregorian_year, month, day).ok_or_else(|| {
+        anyhow!("Invalid date: {} year {} month {} day {}", era.name, year, month, day)
+    })?;
+
+    check_in_era(era, date)?;
+    Ok(date)
+}
+
+/// First day of an era year: January 1, or the era's start date in year 1
+pub fn era_year_start(era: &Era, year: i32) -> Result<NaiveDate> {
+    if year == 1 {
+        return Ok(era.start);
+    }
+    from_era_date(era, year, 1, 1)
+}
+
+fn check_in_era(era: &Era, date: NaiveDate) -> Result<()> {
+    if date < era.start {
+        return Err(anyhow!(
+            "{} is before the {} era, which began {}",
+            date,
+            era.name,
+            era.start
+        ));
+    }
+
+    if let Some(next) = eras().iter().find(|e| e.start > era.start) {
+        if date >= next.start {
+            return Err(anyhow!(
+                "{} is after the {} era, which ended {}",
+                date,
+                era.name,
+                next.start.pred_opt().unwrap()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Look up an era by kanji, romanized name (with or without macrons) or abbreviation
+pub fn find_era(name: &str) -> Option<&'static Era> {
+    let folded: String = name
+        .trim()
+        .chars()
+        .map(|c| match c {
+            'ō' | 'ô' | 'Ō' | 'Ô' => 'o',
+            'ū' | 'û' | 'Ū' | 'Û' => 'u',
+            _ => c,
+        })
+        .collect();
+
+    eras().iter().find(|e| {
+        e.kanji == folded
+            || e.name.eq_ignore_ascii_case(&folded)
+            || e.abbreviation.eq_ignore_ascii_case(&folded)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_to_era_date() {
+        let era_date = to_era_date(date(2026, 10, 18)).unwrap();
+        assert_eq!(era_date.era.name, "Reiwa");
+        assert_eq!(era_date.year, 8);
+        assert_eq!(era_date.to_kanji_string(), "令和8年10月18日");
+        assert_eq!(era_date.to_short_string(), "R8.10.18");
+    }
+
+    #[test]
+    fn test_era_boundaries() {
+        assert_eq!(to_era_date(date(2019, 4, 30)).unwrap().era.name, "Heisei");
+        assert_eq!(to_era_date(date(2019, 5, 1)).unwrap().era.name, "Reiwa");
+        assert_eq!(to_era_date(date(1989, 1, 7)).unwrap().year, 64);
+        assert!(to_era_date(date(1868, 1, 1)).is_none());
+    }
+
+    #[test]
+    fn test_from_era_date_out_of_range() {
+        let heisei = find_era("平成").unwrap();
+        assert_eq!(from_era_date(heisei, 31, 4, 30).unwrap(), date(2019, 4, 30));
+        assert!(from_era_date(heisei, 31, 5, 1).is_err());
+        assert!(from_era_date(find_era("Shōwa").unwrap(), 1, 12, 1).is_err());
+
+        let error = from_era_date(find_era("Reiwa").unwrap(), i32::MAX, 1, 1).unwrap_err();
+        assert_eq!(error.downcast::<UcmError>().unwrap().error, crate::types::ErrorKind::OutOfRange);
+    }
+
+    #[test]
+    fn test_merge_registered_era() {
+        let future = Era {
+            name: "Example".to_string(),
+            kanji: "例".to_string(),
+            abbreviation: "X".to_string(),
+            start: date(2050, 1, 1),
+        };
+        let merged = merge_eras(builtin_eras(), vec![future.clone()]).unwrap();
+        assert_eq!(merged.last().unwrap().name, "Example");
+
+        let backdated = Era { start: date(2000, 1, 1), ..future };
+        assert!(merge_eras(builtin_eras(), vec![backdated]).is_err());
+    }
+}
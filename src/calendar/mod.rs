@@ -0,0 +1,14 @@
+pub mod anniversary;
+pub mod cron;
+pub mod epoch;
+pub mod fiscal;
+pub mod free_busy;
+pub mod holidays;
+pub mod ics;
+pub mod japanese;
+pub mod nth;
+pub mod period;
+pub mod recurrence;
+pub mod schedule;
+pub mod retail;
+pub mod week;
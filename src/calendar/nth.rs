@@ -0,0 +1,118 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+use crate::calendar::holidays::Country;
+
+/// Longest search, in days, for "the nth Monday after" style queries
+const MAX_SEARCH_DAYS: i64 = 366 * 10;
+
+/// Which days count when finding the nth day
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DayKind {
+    Weekday(Weekday),
+    /// Monday to Friday, excluding public holidays when a country is given
+    BusinessDay,
+    Day,
+}
+
+impl DayKind {
+    pub fn matches(self, date: NaiveDate, holidays: Option<&Country>) -> bool {
+        match self {
+            Self::Weekday(weekday) => date.weekday() == weekday,
+            Self::BusinessDay => {
+                !matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+                    && !holidays.is_some_and(|country| country.is_holiday(date))
+            }
+            Self::Day => true,
+        }
+    }
+
+    pub fn name(self) -> String {
+        match self {
+            Self::Weekday(weekday) => format!("{:?}", weekday),
+            Self::BusinessDay => "business day".to_string(),
+            Self::Day => "day".to_string(),
+        }
+    }
+}
+
+/// The nth matching day between `start` and `end` inclusive; n = 1 is the
+/// first, n = -1 the last, n = -2 the second to last
+pub fn nth_in_range(
+    start: NaiveDate,
+    end: NaiveDate,
+    n: i32,
+    matches: impl Fn(NaiveDate) -> bool,
+) -> Option<NaiveDate> {
+    let mut days = start.iter_days().take_while(|date| *date <= end).filter(|date| matches(*date));
+    match n {
+        0 => None,
+        n if n > 0 => days.nth(n as usize - 1),
+        n => {
+            let days: Vec<NaiveDate> = days.collect();
+            days.len().checked_sub(n.unsigned_abs() as usize).map(|i| days[i])
+        }
+    }
+}
+
+/// The nth matching day after `date` (or before it, for negative n);
+/// `inclusive` lets `date` itself count
+pub fn nth_from(date: NaiveDate, n: i32, inclusive: bool, matches: impl Fn(NaiveDate) -> bool) -> Option<NaiveDate> {
+    if n == 0 {
+        return None;
+    }
+    let step = Duration::days(n.signum() as i64);
+    let mut current = if inclusive { date } else { date.checked_add_signed(step)? };
+    let mut found = 0;
+    for _ in 0..MAX_SEARCH_DAYS {
+        if matches(current) {
+            found += 1;
+            if found == n.abs() {
+                return Some(current);
+            }
+        }
+        current = current.checked_add_signed(step)?;
+    }
+    None
+}
+
+/// "1st", "22nd", "last" or "2nd to last"
+pub fn ordinal_name(n: i32) -> String {
+    match n {
+        -1 => "last".to_string(),
+        n if n < -1 => format!("{} to last", ordinal_name(-n)),
+        n => {
+            let suffix = match (n % 10, n % 100) {
+                (_, 11..=13) => "th",
+                (1, _) => "st",
+                (2, _) => "nd",
+                (3, _) => "rd",
+                _ => "th",
+            };
+            format!("{}{}", n, suffix)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::holidays::find_country;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_nth_in_range() {
+        let thursday = |d: NaiveDate| DayKind::Weekday(Weekday::Thu).matches(d, None);
+        assert_eq!(nth_in_range(date(2026, 11, 1), date(2026, 11, 30), 3, thursday), Some(date(2026, 11, 19)));
+        assert_eq!(nth_in_range(date(2026, 11, 1), date(2026, 11, 30), -1, thursday), Some(date(2026, 11, 26)));
+        assert_eq!(nth_in_range(date(2026, 11, 1), date(2026, 11, 30), 5, thursday), None);
+
+        let us = find_country("US").unwrap();
+        let business = |d: NaiveDate| DayKind::BusinessDay.matches(d, Some(us));
+        // 2026-12-31 is a Thursday; Christmas (Friday 25th) is skipped
+        assert_eq!(nth_in_range(date(2026, 10, 1), date(2026, 12, 31), -2, business), Some(date(2026, 12, 30)));
+        assert_eq!(nth_from(date(2026, 12, 24), 1, false, business), Some(date(2026, 12, 28)));
+    }
+}
@@ -0,0 +1,155 @@
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+use crate::calendar::fiscal::FiscalCalendar;
+use crate::types::duration::add_months;
+
+/// Units a date can be anchored to with "start of" / "end of"
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PeriodUnit {
+    Day,
+    /// A seven-day week starting on the given weekday
+    Week(Weekday),
+    Month,
+    Quarter,
+    /// January-June or July-December
+    Half,
+    Year,
+    FiscalYear,
+}
+
+impl PeriodUnit {
+    /// Look up a unit; "week" starts on `week_start`, "iso_week" on Monday
+    /// and "us_week" on Sunday
+    pub fn from_name(name: &str, week_start: Weekday) -> Result<Self> {
+        match name.trim().to_lowercase().replace([' ', '-'], "_").as_str() {
+            "day" => Ok(Self::Day),
+            "week" => Ok(Self::Week(week_start)),
+            "iso_week" => Ok(Self::Week(Weekday::Mon)),
+            "us_week" => Ok(Self::Week(Weekday::Sun)),
+            "month" => Ok(Self::Month),
+            "quarter" => Ok(Self::Quarter),
+            "half" | "half_year" => Ok(Self::Half),
+            "year" => Ok(Self::Year),
+            "fiscal_year" | "fy" => Ok(Self::FiscalYear),
+            _ => Err(anyhow!(
+                "Unknown period unit '{}'. Valid units: day, week, iso_week, us_week, month, quarter, half, year, fiscal_year",
+                name
+            )),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Week(Weekday::Mon) => "iso_week",
+            Self::Week(Weekday::Sun) => "us_week",
+            Self::Week(_) => "week",
+            Self::Month => "month",
+            Self::Quarter => "quarter",
+            Self::Half => "half",
+            Self::Year => "year",
+            Self::FiscalYear => "fiscal_year",
+        }
+    }
+
+    /// First and last day of the period containing `date`
+    pub fn range(self, date: NaiveDate, fiscal: &FiscalCalendar) -> Result<(NaiveDate, NaiveDate)> {
+        let months = |start_month: u32, count: i32| {
+            let start = NaiveDate::from_ymd_opt(date.year(), start_month, 1)?;
+            Some((start, add_months(start, count)?.pred_opt()?))
+        };
+        let range = match self {
+            Self::Day => Some((date, date)),
+            Self::Week(first) => {
+                let offset = (date.weekday().num_days_from_monday() + 7 - first.num_days_from_monday()) % 7;
+                date.checked_sub_signed(Duration::days(offset as i64))
+                    .and_then(|start| Some((start, start.checked_add_signed(Duration::days(6))?)))
+            }
+            Self::Month => months(date.month(), 1),
+            Self::Quarter => months((date.month() - 1) / 3 * 3 + 1, 3),
+            Self::Half => months((date.month() - 1) / 6 * 6 + 1, 6),
+            Self::Year => months(1, 12),
+            Self::FiscalYear => return fiscal.year_range(fiscal.fiscal_year(date)),
+        };
+        range.ok_or_else(|| anyhow!("{} is out of range", date))
+    }
+
+    /// The period `offset` units after (or before, when negative) the one
+    /// containing `date`
+    pub fn relative_range(
+        self,
+        date: NaiveDate,
+        offset: i32,
+        fiscal: &FiscalCalendar,
+    ) -> Result<(NaiveDate, NaiveDate)> {
+        let (start, _) = self.range(date, fiscal)?;
+        let shifted = match self {
+            Self::Day => start.checked_add_signed(Duration::days(offset as i64)),
+            Self::Week(_) => start.checked_add_signed(Duration::weeks(offset as i64)),
+            Self::Month => add_months(start, offset),
+            Self::Quarter => add_months(start, offset * 3),
+            Self::Half => add_months(start, offset * 6),
+            Self::Year | Self::FiscalYear => add_months(start, offset * 12),
+        };
+        let shifted = shifted.ok_or_else(|| anyhow!("{} {}s from {} is out of range", offset, self.name(), date))?;
+        self.range(shifted, fiscal)
+    }
+
+    /// Short label for the period starting at `start`, e.g. "2026-Q4",
+    /// "2026-W43", "2026-H2" or "FY2027"
+    pub fn label(self, start: NaiveDate, fiscal: &FiscalCalendar) -> String {
+        match self {
+            Self::Day => start.format("%Y-%m-%d").to_string(),
+            Self::Week(Weekday::Mon) => {
+                let week = start.iso_week();
+                format!("{}-W{:02}", week.year(), week.week())
+            }
+            Self::Week(_) => format!("week of {}", start.format("%Y-%m-%d")),
+            Self::Month => start.format("%Y-%m").to_string(),
+            Self::Quarter => format!("{}-Q{}", start.year(), (start.month() - 1) / 3 + 1),
+            Self::Half => format!("{}-H{}", start.year(), (start.month() - 1) / 6 + 1),
+            Self::Year => start.year().to_string(),
+            Self::FiscalYear => format!("FY{}", fiscal.fiscal_year(start)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_period_ranges() {
+        let fiscal = FiscalCalendar::from_name("us_federal").unwrap();
+        let day = date(2026, 10, 18);
+        let range = |unit: PeriodUnit| unit.range(day, &fiscal).unwrap();
+        assert_eq!(range(PeriodUnit::Week(Weekday::Mon)), (date(2026, 10, 12), date(2026, 10, 18)));
+        assert_eq!(range(PeriodUnit::Week(Weekday::Sun)), (date(2026, 10, 18), date(2026, 10, 24)));
+        assert_eq!(range(PeriodUnit::Quarter), (date(2026, 10, 1), date(2026, 12, 31)));
+        assert_eq!(range(PeriodUnit::Half), (date(2026, 7, 1), date(2026, 12, 31)));
+        assert_eq!(range(PeriodUnit::FiscalYear), (date(2026, 10, 1), date(2027, 9, 30)));
+        assert_eq!(PeriodUnit::Half.label(date(2026, 7, 1), &fiscal), "2026-H2");
+    }
+
+    #[test]
+    fn test_relative_periods() {
+        let fiscal = FiscalCalendar::from_name("calendar").unwrap();
+        let day = date(2026, 1, 31);
+        assert_eq!(
+            PeriodUnit::Month.relative_range(day, 1, &fiscal).unwrap(),
+            (date(2026, 2, 1), date(2026, 2, 28))
+        );
+        assert_eq!(
+            PeriodUnit::Quarter.relative_range(day, -1, &fiscal).unwrap(),
+            (date(2025, 10, 1), date(2025, 12, 31))
+        );
+        assert!(PeriodUnit::from_name("fortnight", Weekday::Mon).is_err());
+        assert!(PeriodUnit::Week(Weekday::Mon).range(NaiveDate::MIN, &fiscal).is_err());
+        assert!(PeriodUnit::Week(Weekday::Mon).range(NaiveDate::MAX, &fiscal).is_err());
+    }
+}
@@ -0,0 +1,514 @@
+//! Recurrence rules: the RFC 5545 RRULE subset with FREQ (DAILY, WEEKLY,
+//! MONTHLY, YEARLY), INTERVAL, COUNT, UNTIL, BYDAY (with ordinals such as
+//! 2TU or -1FR), BYMONTHDAY, BYMONTH, and BYHOUR, BYMINUTE and BYSECOND for
+//! several times a day. Weeks start on Monday.
+
+use std::collections::VecDeque;
+
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
+
+use crate::calendar::nth::nth_in_range;
+use crate::types::duration::add_months;
+
+/// Most periods scanned for occurrences, so rules that never match end
+const MAX_PERIODS: u32 = 100_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl Frequency {
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Daily => "DAILY",
+            Self::Weekly => "WEEKLY",
+            Self::Monthly => "MONTHLY",
+            Self::Yearly => "YEARLY",
+        }
+    }
+
+    /// "day", "week", "month" or "year"
+    pub fn unit(self) -> &'static str {
+        match self {
+            Self::Daily => "day",
+            Self::Weekly => "week",
+            Self::Monthly => "month",
+            Self::Yearly => "year",
+        }
+    }
+}
+
+/// A BYDAY entry: every such weekday in the period, or the nth one
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ByDay {
+    pub nth: Option<i32>,
+    pub weekday: Weekday,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recurrence {
+    pub frequency: Frequency,
+    pub interval: u32,
+    pub by_day: Vec<ByDay>,
+    pub by_month_day: Vec<i32>,
+    pub by_month: Vec<u32>,
+    /// Times of day; each defaults to the start's hour, minute or second
+    pub by_hour: Vec<u32>,
+    pub by_minute: Vec<u32>,
+    pub by_second: Vec<u32>,
+    /// Total occurrences, including the first
+    pub count: Option<u32>,
+    /// Last possible start, compared with local start times
+    pub until: Option<NaiveDateTime>,
+}
+
+impl Recurrence {
+    pub fn new(frequency: Frequency) -> Self {
+        Self {
+            frequency,
+            interval: 1,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_month: Vec::new(),
+            by_hour: Vec::new(),
+            by_minute: Vec::new(),
+            by_second: Vec::new(),
+            count: None,
+            until: None,
+        }
+    }
+
+    /// An RRULE ("FREQ=WEEKLY;BYDAY=MO,WE", optionally prefixed "RRULE:"),
+    /// or "daily", "weekdays", "every 2 weeks" and similar
+    pub fn parse(text: &str) -> Result<Self> {
+        let text = text.trim();
+        let lower = text.to_lowercase();
+        if lower.starts_with("rrule:") || lower.starts_with("freq=") {
+            return Self::parse_rrule(text);
+        }
+
+        let words: Vec<&str> = lower.split_whitespace().collect();
+        let (interval, unit) = match words.as_slice() {
+            ["weekdays"] | ["every", "weekday"] => {
+                let mut rule = Self::new(Frequency::Weekly);
+                rule.by_day = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri]
+                    .into_iter()
+                    .map(|weekday| ByDay { nth: None, weekday })
+                    .collect();
+                return Ok(rule);
+            }
+            [unit] => (1, *unit),
+            ["every", unit] => (1, *unit),
+            ["every", n, unit] => (n.parse().map_err(|_| anyhow!("Invalid interval '{}'", n))?, *unit),
+            _ => return Err(unknown_rule(text)),
+        };
+        let frequency = match unit.trim_end_matches('s') {
+            "daily" | "day" => Frequency::Daily,
+            "weekly" | "week" => Frequency::Weekly,
+            "monthly" | "month" => Frequency::Monthly,
+            "yearly" | "annually" | "year" => Frequency::Yearly,
+            _ => return Err(unknown_rule(text)),
+        };
+        let mut rule = Self::new(frequency);
+        rule.interval = interval;
+        rule.validate()?;
+        Ok(rule)
+    }
+
+    pub fn parse_rrule(text: &str) -> Result<Self> {
+        let body = text.trim();
+        let body = body.get(..6).filter(|p| p.eq_ignore_ascii_case("rrule:")).map_or(body, |_| &body[6..]);
+        let mut frequency = None;
+        let mut rule = Self::new(Frequency::Daily);
+
+        for part in body.split(';').map(str::trim).filter(|p| !p.is_empty()) {
+            let (key, value) = part.split_once('=').ok_or_else(|| anyhow!("Expected KEY=VALUE, found '{}'", part))?;
+            let list = || value.split(',').map(str::trim);
+            match key.to_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(anyhow!("Unsupported FREQ '{}'. Supported: DAILY, WEEKLY, MONTHLY, YEARLY", value)),
+                    })
+                }
+                "INTERVAL" => rule.interval = number(key, value)?,
+                "COUNT" => rule.count = Some(number(key, value)?),
+                "UNTIL" => rule.until = Some(parse_until(value)?),
+                "BYDAY" => rule.by_day = list().map(parse_by_day).collect::<Result<_>>()?,
+                "BYMONTHDAY" => rule.by_month_day = list().map(|v| number(key, v)).collect::<Result<_>>()?,
+                "BYMONTH" => rule.by_month = list().map(|v| number(key, v)).collect::<Result<_>>()?,
+                "BYHOUR" => rule.by_hour = list().map(|v| number(key, v)).collect::<Result<_>>()?,
+                "BYMINUTE" => rule.by_minute = list().map(|v| number(key, v)).collect::<Result<_>>()?,
+                "BYSECOND" => rule.by_second = list().map(|v| number(key, v)).collect::<Result<_>>()?,
+                "WKST" if value.eq_ignore_ascii_case("MO") => {}
+                _ => return Err(anyhow!("Unsupported RRULE part '{}'", part)),
+            }
+        }
+
+        rule.frequency = frequency.ok_or_else(|| anyhow!("RRULE '{}' has no FREQ", text.trim()))?;
+        rule.validate()?;
+        Ok(rule)
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.interval == 0 || self.count == Some(0) {
+            return Err(anyhow!("INTERVAL and COUNT must be at least 1"));
+        }
+        if self.count.is_some() && self.until.is_some() {
+            return Err(anyhow!("COUNT and UNTIL cannot both be given"));
+        }
+        if let Some(month) = self.by_month.iter().find(|m| !(1..=12).contains(*m)) {
+            return Err(anyhow!("BYMONTH {} is not a month (1-12)", month));
+        }
+        if let Some(day) = self.by_month_day.iter().find(|d| **d == 0 || d.abs() > 31) {
+            return Err(anyhow!("BYMONTHDAY {} is not a day of the month (1-31 or -31 to -1)", day));
+        }
+        if let Some(hour) = self.by_hour.iter().find(|h| **h > 23) {
+            return Err(anyhow!("BYHOUR {} is not an hour (0-23)", hour));
+        }
+        if let Some(value) = self.by_minute.iter().chain(&self.by_second).find(|v| **v > 59) {
+            return Err(anyhow!("BYMINUTE and BYSECOND must be 0-59, found {}", value));
+        }
+        let ordinals = matches!(self.frequency, Frequency::Monthly | Frequency::Yearly);
+        if self.by_day.iter().any(|d| d.nth.is_some()) && !ordinals {
+            return Err(anyhow!("BYDAY ordinals such as 2TU need FREQ=MONTHLY or FREQ=YEARLY"));
+        }
+        Ok(())
+    }
+
+    /// The rule as an RRULE value, without the "RRULE:" prefix
+    pub fn to_rrule(&self) -> String {
+        let mut parts = vec![format!("FREQ={}", self.frequency.code())];
+        if self.interval != 1 {
+            parts.push(format!("INTERVAL={}", self.interval));
+        }
+        if !self.by_day.is_empty() {
+            let days: Vec<String> = self
+                .by_day
+                .iter()
+                .map(|d| format!("{}{}", d.nth.map(|n| n.to_string()).unwrap_or_default(), weekday_code(d.weekday)))
+                .collect();
+            parts.push(format!("BYDAY={}", days.join(",")));
+        }
+        if !self.by_month_day.is_empty() {
+            parts.push(format!("BYMONTHDAY={}", join(&self.by_month_day)));
+        }
+        if !self.by_month.is_empty() {
+            parts.push(format!("BYMONTH={}", join(&self.by_month)));
+        }
+        for (key, values) in [("BYHOUR", &self.by_hour), ("BYMINUTE", &self.by_minute), ("BYSECOND", &self.by_second)] {
+            if !values.is_empty() {
+                parts.push(format!("{}={}", key, join(values)));
+            }
+        }
+        if let Some(count) = self.count {
+            parts.push(format!("COUNT={}", count));
+        }
+        if let Some(until) = self.until {
+            parts.push(format!("UNTIL={}", until.format("%Y%m%dT%H%M%S")));
+        }
+        parts.join(";")
+    }
+
+    /// Start times of the occurrences, in order, beginning with `start`
+    pub fn occurrences(&self, start: NaiveDateTime) -> Occurrences {
+        let times = self.times(start.time());
+        let mut pending = VecDeque::from([start]);
+        if self.period_dates(start.date(), 0).is_some_and(|dates| dates.contains(&start.date())) {
+            pending.extend(times.iter().filter(|t| **t > start.time()).map(|t| start.date().and_time(*t)));
+        }
+        Occurrences { rule: self.clone(), start, times, period: 0, pending, emitted: 0 }
+    }
+
+    /// The first occurrence at or after `anchor` when the rule starts there;
+    /// the anchor itself is skipped unless it fits the rule
+    pub fn first_from(&self, anchor: NaiveDateTime) -> Option<NaiveDateTime> {
+        let fits = self.period_dates(anchor.date(), 0).is_some_and(|dates| dates.contains(&anchor.date()))
+            && self.times(anchor.time()).contains(&anchor.time());
+        self.occurrences(anchor).nth(if fits { 0 } else { 1 })
+    }
+
+    /// Times of day each occurrence date has, in order
+    pub fn times(&self, default: NaiveTime) -> Vec<NaiveTime> {
+        let or = |values: &[u32], default: u32| if values.is_empty() { vec![default] } else { values.to_vec() };
+        let mut times = Vec::new();
+        for hour in or(&self.by_hour, default.hour()) {
+            for minute in or(&self.by_minute, default.minute()) {
+                for second in or(&self.by_second, default.second()) {
+                    times.extend(NaiveTime::from_hms_opt(hour, minute, second));
+                }
+            }
+        }
+        times.sort();
+        times.dedup();
+        times
+    }
+
+    /// Candidate dates in the `period`th period after the one containing
+    /// `start`, or None once the period is past the supported dates
+    fn period_dates(&self, start: NaiveDate, period: u32) -> Option<Vec<NaiveDate>> {
+        let step = i64::from(period).checked_mul(i64::from(self.interval))?;
+        let mut dates = match self.frequency {
+            Frequency::Daily => {
+                let date = start.checked_add_signed(Duration::try_days(step)?)?;
+                let weekday_ok = self.by_day.is_empty() || self.by_day.iter().any(|d| d.weekday == date.weekday());
+                let day_ok = self.by_month_day.is_empty() || self.by_month_day.iter().any(|d| month_day(date, *d) == Some(date));
+                if weekday_ok && day_ok { vec![date] } else { Vec::new() }
+            }
+            Frequency::Weekly => {
+                let monday = start
+                    .checked_sub_signed(Duration::days(start.weekday().num_days_from_monday() as i64))?
+                    .checked_add_signed(Duration::try_weeks(step)?)?;
+                let mut weekdays: Vec<Weekday> = self.by_day.iter().map(|d| d.weekday).collect();
+                if weekdays.is_empty() {
+                    weekdays.push(start.weekday());
+                }
+                weekdays
+                    .iter()
+                    .map(|w| monday.checked_add_signed(Duration::days(w.num_days_from_monday() as i64)))
+                    .collect::<Option<_>>()?
+            }
+            Frequency::Monthly => {
+                let first = add_months(start.with_day(1).unwrap(), i32::try_from(step).ok()?)?;
+                self.days_in(first, month_end(first), start.day())
+            }
+            Frequency::Yearly => {
+                let year = start.year().checked_add(i32::try_from(step).ok()?)?;
+                let first = NaiveDate::from_ymd_opt(year, 1, 1)?;
+                if !self.by_month.is_empty() {
+                    self.by_month
+                        .iter()
+                        .filter_map(|m| first.with_month(*m))
+                        .flat_map(|m| self.days_in(m, month_end(m), start.day()))
+                        .collect()
+                } else if !self.by_day.is_empty() {
+                    self.days_in(first, first.with_month(12).map_or(first, month_end), start.day())
+                } else if !self.by_month_day.is_empty() {
+                    (1..=12)
+                        .filter_map(|m| first.with_month(m))
+                        .flat_map(|m| self.days_in(m, month_end(m), start.day()))
+                        .collect()
+                } else {
+                    first.with_month(start.month()).and_then(|m| m.with_day(start.day())).into_iter().collect()
+                }
+            }
+        };
+        if self.frequency != Frequency::Yearly && !self.by_month.is_empty() {
+            dates.retain(|date| self.by_month.contains(&date.month()));
+        }
+        dates.sort();
+        dates.dedup();
+        Some(dates)
+    }
+
+    /// Dates between `first` and `last` matching BYDAY and BYMONTHDAY, or
+    /// `default_day` of the month when neither is given
+    fn days_in(&self, first: NaiveDate, last: NaiveDate, default_day: u32) -> Vec<NaiveDate> {
+        let in_month_days = |date: NaiveDate| {
+            self.by_month_day.is_empty() || self.by_month_day.iter().any(|d| month_day(date, *d) == Some(date))
+        };
+        if !self.by_day.is_empty() {
+            return self
+                .by_day
+                .iter()
+                .flat_map(|by_day| match by_day.nth {
+                    Some(n) => nth_in_range(first, last, n, |d| d.weekday() == by_day.weekday).into_iter().collect(),
+                    None => first
+                        .iter_days()
+                        .take_while(|d| *d <= last)
+                        .filter(|d| d.weekday() == by_day.weekday)
+                        .collect::<Vec<_>>(),
+                })
+                .filter(|date| in_month_days(*date))
+                .collect();
+        }
+        if !self.by_month_day.is_empty() {
+            return self.by_month_day.iter().filter_map(|d| month_day(first, *d)).collect();
+        }
+        first.with_day(default_day).into_iter().collect()
+    }
+}
+
+pub struct Occurrences {
+    rule: Recurrence,
+    start: NaiveDateTime,
+    times: Vec<NaiveTime>,
+    period: u32,
+    pending: VecDeque<NaiveDateTime>,
+    emitted: u32,
+}
+
+impl Iterator for Occurrences {
+    type Item = NaiveDateTime;
+
+    fn next(&mut self) -> Option<NaiveDateTime> {
+        let occurrence = loop {
+            if let Some(occurrence) = self.pending.pop_front() {
+                break occurrence;
+            }
+            if self.period >= MAX_PERIODS {
+                return None;
+            }
+            let start = self.start.date();
+            let Some(dates) = self.rule.period_dates(start, self.period) else {
+                self.period = MAX_PERIODS;
+                return None;
+            };
+            let times = &self.times;
+            self.pending = dates
+                .into_iter()
+                .filter(|d| *d > start)
+                .flat_map(|d| times.iter().map(move |t| d.and_time(*t)))
+                .collect();
+            self.period += 1;
+        };
+
+        if self.rule.count.is_some_and(|count| self.emitted >= count) || self.rule.until.is_some_and(|u| occurrence > u) {
+            self.period = MAX_PERIODS;
+            self.pending.clear();
+            return None;
+        }
+        self.emitted += 1;
+        Some(occurrence)
+    }
+}
+
+/// Day `day` of the month containing `date`; negative counts from the end
+fn month_day(date: NaiveDate, day: i32) -> Option<NaiveDate> {
+    if day > 0 {
+        date.with_day(day as u32)
+    } else {
+        let last = month_end(date);
+        last.checked_sub_signed(Duration::days((-day - 1) as i64)).filter(|d| d.month() == last.month())
+    }
+}
+
+fn month_end(date: NaiveDate) -> NaiveDate {
+    add_months(date.with_day(1).unwrap(), 1).and_then(|d| d.pred_opt()).unwrap_or(date)
+}
+
+fn join<T: ToString>(values: &[T]) -> String {
+    values.iter().map(T::to_string).collect::<Vec<_>>().join(",")
+}
+
+fn number<T: std::str::FromStr>(key: &str, value: &str) -> Result<T> {
+    value.trim().parse().map_err(|_| anyhow!("Invalid {} '{}'", key, value))
+}
+
+/// UNTIL as a date (the whole day) or a date-time
+fn parse_until(value: &str) -> Result<NaiveDateTime> {
+    let value = value.trim().trim_end_matches('Z');
+    NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+        .or_else(|_| NaiveDate::parse_from_str(value, "%Y%m%d").map(|d| d.and_time(NaiveTime::from_hms_opt(23, 59, 59).unwrap())))
+        .map_err(|_| anyhow!("Invalid UNTIL '{}'; expected YYYYMMDD or YYYYMMDDTHHMMSS", value))
+}
+
+fn parse_by_day(value: &str) -> Result<ByDay> {
+    let invalid = || anyhow!("Invalid BYDAY '{}'; expected e.g. MO, 2TU or -1FR", value);
+    // Splitting by bytes needs ASCII
+    if !value.is_ascii() {
+        return Err(invalid());
+    }
+    let split = value.len().saturating_sub(2);
+    let (nth, code) = value.split_at(split);
+    let weekday = match code.to_uppercase().as_str() {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return Err(invalid()),
+    };
+    let nth = match nth.trim_start_matches('+') {
+        "" => None,
+        n => Some(number::<i32>("BYDAY", n).ok().filter(|n| *n != 0 && n.abs() <= 53).ok_or_else(|| {
+            anyhow!("Invalid BYDAY '{}'; ordinals are 1 to 53 or -53 to -1", value)
+        })?),
+    };
+    Ok(ByDay { nth, weekday })
+}
+
+pub fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn unknown_rule(text: &str) -> anyhow::Error {
+    anyhow!(
+        "Unknown recurrence '{}'. Use an RRULE such as 'FREQ=WEEKLY;BYDAY=MO,WE', or daily, weekly, monthly, yearly, weekdays, 'every 2 weeks'",
+        text
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(y: i32, m: u32, d: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(9, 0, 0).unwrap()
+    }
+
+    fn dates(rule: &str, start: NaiveDateTime, n: usize) -> Vec<String> {
+        let rule = Recurrence::parse(rule).unwrap();
+        rule.occurrences(start).take(n).map(|d| d.format("%Y-%m-%d").to_string()).collect()
+    }
+
+    #[test]
+    fn test_recurrence_occurrences() {
+        // Thursday 2026-10-15
+        assert_eq!(
+            dates("FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH", at(2026, 10, 15), 4),
+            ["2026-10-15", "2026-10-27", "2026-10-29", "2026-11-10"]
+        );
+        assert_eq!(dates("RRULE:FREQ=MONTHLY;BYDAY=-1FR;COUNT=3", at(2026, 10, 30), 5), ["2026-10-30", "2026-11-27", "2026-12-25"]);
+        assert_eq!(dates("FREQ=MONTHLY;BYMONTHDAY=31", at(2026, 1, 31), 3), ["2026-01-31", "2026-03-31", "2026-05-31"]);
+        assert_eq!(dates("FREQ=YEARLY;BYMONTH=11;BYDAY=4TH", at(2026, 11, 26), 2), ["2026-11-26", "2027-11-25"]);
+        assert_eq!(dates("weekdays", at(2026, 10, 16), 3), ["2026-10-16", "2026-10-19", "2026-10-20"]);
+        assert_eq!(dates("FREQ=DAILY;UNTIL=20261020", at(2026, 10, 18), 10).len(), 3);
+
+        let twice = Recurrence::parse("FREQ=DAILY;BYHOUR=9,17;BYMINUTE=30").unwrap();
+        let times: Vec<String> = twice.occurrences(at(2026, 10, 18)).take(4).map(|d| d.format("%d %H:%M").to_string()).collect();
+        assert_eq!(times, ["18 09:00", "18 09:30", "18 17:30", "19 09:30"]);
+        let second_tuesday = Recurrence::parse("FREQ=MONTHLY;BYDAY=2TU").unwrap();
+        assert_eq!(second_tuesday.first_from(at(2026, 10, 18)), Some(at(2026, 11, 10)));
+    }
+
+    #[test]
+    fn test_recurrence_rrule_round_trip_and_errors() {
+        let rule = Recurrence::parse("every 2 weeks").unwrap();
+        assert_eq!(rule.to_rrule(), "FREQ=WEEKLY;INTERVAL=2");
+        let rule = Recurrence::parse("FREQ=MONTHLY;BYDAY=2TU;COUNT=6").unwrap();
+        assert_eq!(Recurrence::parse(&rule.to_rrule()).unwrap(), rule);
+
+        assert!(Recurrence::parse("FREQ=WEEKLY;BYDAY=2TU").is_err());
+        assert!(Recurrence::parse("FREQ=HOURLY").is_err());
+        assert!(Recurrence::parse("fortnightly-ish").is_err());
+        assert!(Recurrence::parse("FREQ=MONTHLY;BYDAY=1Mé").is_err());
+        assert!(Recurrence::parse("FREQ=DAILY;INTERVAL=0").is_err());
+    }
+
+    #[test]
+    fn test_recurrence_huge_interval_ends() {
+        for frequency in ["DAILY", "WEEKLY", "MONTHLY", "YEARLY"] {
+            let rule = Recurrence::parse(&format!("FREQ={};INTERVAL=4000000000", frequency)).unwrap();
+            assert_eq!(rule.occurrences(at(2026, 10, 15)).count(), 1, "{}", frequency);
+        }
+    }
+}
@@ -0,0 +1,308 @@
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+use super::fiscal::{month_name, parse_month};
+
+/// How many weeks each period of a quarter has
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RetailPattern {
+    P445,
+    P454,
+    P544,
+}
+
+impl RetailPattern {
+    fn weeks(self) -> [u32; 3] {
+        match self {
+            Self::P445 => [4, 4, 5],
+            Self::P454 => [4, 5, 4],
+            Self::P544 => [5, 4, 4],
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::P445 => "4-4-5",
+            Self::P454 => "4-5-4",
+            Self::P544 => "5-4-4",
+        }
+    }
+}
+
+/// Which weekday ends the retail year
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum YearEndRule {
+    /// The last given weekday of the year-end month
+    LastWeekday,
+    /// The given weekday nearest the last day of the year-end month,
+    /// which may fall up to three days into the next month
+    NearestWeekday,
+}
+
+/// A 52/53-week retail calendar
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetailCalendar {
+    pub name: String,
+    pub pattern: RetailPattern,
+    pub end_month: u32,
+    pub end_weekday: Weekday,
+    pub rule: YearEndRule,
+}
+
+/// Where a date falls within its retail year
+#[derive(Debug, Clone)]
+pub struct RetailDate {
+    pub retail_year: i32,
+    pub quarter: u32,
+    pub period: u32,
+    pub week: u32,
+    pub week_of_period: u32,
+    pub year_start: NaiveDate,
+    pub year_end: NaiveDate,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub weeks_in_year: u32,
+}
+
+impl RetailCalendar {
+    /// Parse a calendar spec such as "nrf" or "4-5-4 last saturday january".
+    ///
+    /// "nrf" is the National Retail Federation 4-5-4 calendar ending on the
+    /// Saturday nearest January 31. Custom specs take a pattern followed by
+    /// optional rule ("last" or "nearest", default "last"), weekday
+    /// (default Saturday) and year-end month (default January).
+    pub fn from_name(spec: &str) -> Result<Self> {
+        let lower = spec.trim().to_lowercase();
+        if lower == "nrf" {
+            return Ok(Self {
+                name: "nrf".to_string(),
+                pattern: RetailPattern::P454,
+                end_month: 1,
+                end_weekday: Weekday::Sat,
+                rule: YearEndRule::NearestWeekday,
+            });
+        }
+
+        let invalid = || {
+            anyhow!(
+                "Unknown retail calendar '{}'. Use 'nrf' or a spec like '4-5-4 last saturday january'",
+                spec
+            )
+        };
+
+        let mut tokens = lower.split_whitespace();
+        let pattern = match tokens.next() {
+            Some("4-4-5" | "445") => RetailPattern::P445,
+            Some("4-5-4" | "454") => RetailPattern::P454,
+            Some("5-4-4" | "544") => RetailPattern::P544,
+            _ => return Err(invalid()),
+        };
+
+        let mut rule = YearEndRule::LastWeekday;
+        let mut end_weekday = Weekday::Sat;
+        let mut end_month = 1;
+
+        for token in tokens {
+            match token {
+                "last" => rule = YearEndRule::LastWeekday,
+                "nearest" => rule = YearEndRule::NearestWeekday,
+                "of" | "in" | "to" => {}
+                t => {
+                    if let Some(month) = parse_month(t) {
+                        end_month = month;
+                    } else {
+                        end_weekday = t.parse().map_err(|_| invalid())?;
+                    }
+                }
+            }
+        }
+
+        let rule_name = match rule {
+            YearEndRule::LastWeekday => "last",
+            YearEndRule::NearestWeekday => "nearest",
+        };
+
+        Ok(Self {
+            name: format!(
+                "{} {} {} {}",
+                pattern.label(),
+                rule_name,
+                weekday_name(end_weekday),
+                month_name(end_month)
+            ),
+            pattern,
+            end_month,
+            end_weekday,
+            rule,
+        })
+    }
+
+    /// Last day of the retail year that ends in the given calendar year
+    fn year_end_in(&self, year: i32) -> Option<NaiveDate> {
+        let month_end = if self.end_month == 12 {
+            NaiveDate::from_ymd_opt(year + 1, 1, 1)
+        } else {
+            NaiveDate::from_ymd_opt(year, self.end_month + 1, 1)
+        }?
+        .pred_opt()?;
+
+        let back = (month_end.weekday().num_days_from_monday() + 7
+            - self.end_weekday.num_days_from_monday())
+            % 7;
+        let last = month_end - Duration::days(back as i64);
+
+        Some(match self.rule {
+            YearEndRule::NearestWeekday if back > 3 => last + Duration::days(7),
+            _ => last,
+        })
+    }
+
+    /// First and last day of the retail year that ends in the given calendar year
+    fn bounds(&self, end_year: i32) -> Option<(NaiveDate, NaiveDate)> {
+        let start = self.year_end_in(end_year - 1)?.succ_opt()?;
+        Some((start, self.year_end_in(end_year)?))
+    }
+
+    /// Retail years are named after the calendar year holding most of their
+    /// days, so a year ending in January takes the name of the year it starts in
+    fn label(start: NaiveDate) -> i32 {
+        (start + Duration::days(182)).year()
+    }
+
+    /// First and last day of a retail year
+    pub fn year_range(&self, retail_year: i32) -> Result<(NaiveDate, NaiveDate)> {
+        (retail_year..=retail_year + 1)
+            .filter_map(|end_year| self.bounds(end_year))
+            .find(|(start, _)| Self::label(*start) == retail_year)
+            .ok_or_else(|| anyhow!("Retail year {} is out of range", retail_year))
+    }
+
+    /// First and last day of a retail period (1-12). In a 53-week year the
+    /// extra week is added to period 12.
+    pub fn period_range(&self, retail_year: i32, period: u32) -> Result<(NaiveDate, NaiveDate)> {
+        if !(1..=12).contains(&period) {
+            return Err(anyhow!("Retail period must be 1-12, got {}", period));
+        }
+
+        let (year_start, year_end) = self.year_range(retail_year)?;
+        let weeks_before: u32 = (1..period).map(|p| self.period_weeks(p)).sum();
+        let start = year_start + Duration::weeks(weeks_before as i64);
+        let end = if period == 12 {
+            year_end
+        } else {
+            start + Duration::weeks(self.period_weeks(period) as i64) - Duration::days(1)
+        };
+
+        Ok((start, end))
+    }
+
+    /// First and last day of a retail week (1-52, or 1-53 in a 53-week year)
+    pub fn week_range(&self, retail_year: i32, week: u32) -> Result<(NaiveDate, NaiveDate)> {
+        let (year_start, year_end) = self.year_range(retail_year)?;
+        let weeks_in_year = ((year_end - year_start).num_days() + 1) as u32 / 7;
+        if !(1..=weeks_in_year).contains(&week) {
+            return Err(anyhow!(
+                "Retail year {} has {} weeks, got week {}",
+                retail_year,
+                weeks_in_year,
+                week
+            ));
+        }
+
+        let start = year_start + Duration::weeks(week as i64 - 1);
+        Ok((start, start + Duration::days(6)))
+    }
+
+    fn period_weeks(&self, period: u32) -> u32 {
+        self.pattern.weeks()[((period - 1) % 3) as usize]
+    }
+
+    /// Locate a date within its retail year
+    pub fn locate(&self, date: NaiveDate) -> Result<RetailDate> {
+        let (year_start, year_end) = (date.year() - 1..=date.year() + 1)
+            .filter_map(|end_year| self.bounds(end_year))
+            .find(|(start, end)| *start <= date && date <= *end)
+            .ok_or_else(|| anyhow!("{} is out of range for retail calendars", date))?;
+
+        let retail_year = Self::label(year_start);
+        let weeks_in_year = ((year_end - year_start).num_days() + 1) as u32 / 7;
+        let week = ((date - year_start).num_days() / 7) as u32 + 1;
+
+        let mut period = 1;
+        let mut first_week = 1;
+        while period < 12 && week >= first_week + self.period_weeks(period) {
+            first_week += self.period_weeks(period);
+            period += 1;
+        }
+        let (period_start, period_end) = self.period_range(retail_year, period)?;
+
+        Ok(RetailDate {
+            retail_year,
+            quarter: (period - 1) / 3 + 1,
+            period,
+            week,
+            week_of_period: week - first_week + 1,
+            year_start,
+            year_end,
+            period_start,
+            period_end,
+            weeks_in_year,
+        })
+    }
+}
+
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "monday",
+        Weekday::Tue => "tuesday",
+        Weekday::Wed => "wednesday",
+        Weekday::Thu => "thursday",
+        Weekday::Fri => "friday",
+        Weekday::Sat => "saturday",
+        Weekday::Sun => "sunday",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_last_saturday_of_january() {
+        let calendar = RetailCalendar::from_name("4-5-4 last saturday january").unwrap();
+        let retail = calendar.locate(date(2026, 10, 18)).unwrap();
+        // Year runs 2026-02-01 (after Sat 2026-01-31) to Sat 2027-01-30
+        assert_eq!(retail.retail_year, 2026);
+        assert_eq!(retail.year_start, date(2026, 2, 1));
+        assert_eq!(retail.year_end, date(2027, 1, 30));
+        assert_eq!(retail.week, 38);
+        assert_eq!(retail.period, 9);
+        assert_eq!(retail.quarter, 3);
+        assert_eq!(retail.weeks_in_year, 52);
+    }
+
+    #[test]
+    fn test_nrf_53_week_year() {
+        let calendar = RetailCalendar::from_name("nrf").unwrap();
+        let (start, end) = calendar.year_range(2023).unwrap();
+        assert_eq!(start, date(2023, 1, 29));
+        assert_eq!(end, date(2024, 2, 3));
+        let retail = calendar.locate(date(2024, 2, 1)).unwrap();
+        assert_eq!(retail.weeks_in_year, 53);
+        assert_eq!(retail.period, 12);
+        assert_eq!(retail.week_of_period, 5);
+    }
+
+    #[test]
+    fn test_period_and_week_ranges() {
+        let calendar = RetailCalendar::from_name("4-4-5 last saturday december").unwrap();
+        let (start, end) = calendar.period_range(2026, 3).unwrap();
+        assert_eq!((end - start).num_days() + 1, 35);
+        assert!(calendar.week_range(2026, 53).is_err());
+        assert!(RetailCalendar::from_name("3-3-3").is_err());
+    }
+}
@@ -0,0 +1,651 @@
+//! Schedules in plain English ("every 2nd Tuesday at 09:00 Europe/London",
+//! "the last Friday of every month at 17:30") and as iCalendar DTSTART and
+//! RRULE lines, both read into a [`Recurrence`] with its first occurrence.
+//!
+//! English schedules without "at" run at midnight, and without "starting"
+//! begin at the first matching time from the anchor they are read with.
+
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
+use chrono_tz::Tz;
+
+use crate::calendar::cron::{capitalize, list};
+use crate::calendar::fiscal::{month_name, parse_month};
+use crate::calendar::ics::read_time;
+use crate::calendar::nth::ordinal_name;
+use crate::calendar::recurrence::{ByDay, Frequency, Recurrence};
+use crate::parser::natural::parse_time_of_day;
+use crate::parser::nth::ordinal;
+
+/// Words that start a clause after the days
+const CLAUSES: [&str; 7] = ["at", "starting", "from", "until", "in", "if", "for"];
+const WEEKDAYS: [Weekday; 5] = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri];
+const WEEKDAY_NAMES: [&str; 7] = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
+
+/// A recurrence rule with its first occurrence and zone
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schedule {
+    pub rule: Recurrence,
+    /// The first occurrence, in local time
+    pub start: NaiveDateTime,
+    pub timezone: Option<Tz>,
+}
+
+/// Hours, minutes and seconds named so far, each `None` until given
+type TimeFields = [Option<Vec<u32>>; 3];
+
+impl Schedule {
+    /// An RRULE, optionally after a DTSTART line; without one the schedule
+    /// starts at the first occurrence from midnight on `anchor`
+    pub fn parse_ical(text: &str, anchor: NaiveDate) -> Result<Self> {
+        let (mut rule, mut start, mut timezone) = (None, None, None);
+        for line in text.split_whitespace() {
+            let upper = line.to_uppercase();
+            if upper.starts_with("DTSTART") {
+                let (name_params, value) = line.split_once(':').ok_or_else(|| anyhow!("Invalid DTSTART '{}'", line))?;
+                let params = name_params.split_once(';').map_or("", |(_, params)| params);
+                let (time, _, zone) = read_time(params, value)?;
+                timezone = zone.map(|name| name.parse::<Tz>().map_err(|_| anyhow!("Unknown TZID '{}'", name))).transpose()?;
+                start = Some(time);
+            } else if upper.starts_with("RRULE:") || upper.starts_with("FREQ=") {
+                rule = Some(Recurrence::parse_rrule(line)?);
+            } else {
+                return Err(anyhow!("Expected a DTSTART or RRULE line, found '{}'", line));
+            }
+        }
+        let rule = rule.ok_or_else(|| anyhow!("No RRULE found; expected e.g. 'RRULE:FREQ=WEEKLY;BYDAY=TU'"))?;
+        let start = match start {
+            Some(start) => start,
+            None => rule.first_from(anchor.into()).ok_or_else(|| anyhow!("The rule has no occurrences"))?,
+        };
+        Ok(Self { rule, start, timezone })
+    }
+
+    /// DTSTART and RRULE lines, separated by a newline
+    pub fn to_ical(&self) -> String {
+        let start = self.start.format("%Y%m%dT%H%M%S");
+        let dtstart = match self.timezone {
+            Some(Tz::UTC) => format!("DTSTART:{}Z", start),
+            Some(tz) => format!("DTSTART;TZID={}:{}", tz.name(), start),
+            None => format!("DTSTART:{}", start),
+        };
+        format!("{}\nRRULE:{}", dtstart, self.rule.to_rrule())
+    }
+
+    /// English such as "every weekday at 9am", "every 2 weeks on Monday and
+    /// Friday" or "the 2nd and last Friday of every month at 17:30
+    /// Europe/Paris", with optional "in <months>", "if it is a <weekday>",
+    /// "starting <date>", "until <date>" and "<n> times"
+    pub fn parse_english(text: &str, anchor: NaiveDateTime, parse_date: impl Fn(&str) -> Result<NaiveDate>) -> Result<Self> {
+        let mut timezone = None;
+        let mut words = Vec::new();
+        for word in text.replace(',', " , ").split_whitespace() {
+            match word.parse::<Tz>() {
+                Ok(tz) if word.contains('/') || word.eq_ignore_ascii_case("UTC") => timezone = Some(tz),
+                _ => words.push(word.to_lowercase()),
+            }
+        }
+        let mut words: Vec<&str> = words.iter().map(String::as_str).collect();
+        let mut count = None;
+        if let [.., n, "times" | "occurrences"] = words.as_slice() {
+            count = Some(n.parse::<u32>().map_err(|_| anyhow!("Invalid count '{}'", n))?);
+            words.truncate(words.len() - 2);
+            while matches!(words.last(), Some(&"," | &"for")) {
+                words.pop();
+            }
+        }
+
+        let mut clauses: Vec<(&str, Vec<&str>)> = vec![("", Vec::new())];
+        for word in words {
+            match CLAUSES.contains(&word) {
+                true => clauses.push((word, Vec::new())),
+                false => clauses.last_mut().unwrap().1.push(word),
+            }
+        }
+        for (_, words) in clauses.iter_mut() {
+            while words.last() == Some(&",") {
+                words.pop();
+            }
+        }
+
+        let (mut rule, mut fields, stepped) = parse_main(&clauses[0].1)?;
+        let mut starting = None;
+        for (clause, words) in &clauses[1..] {
+            match *clause {
+                "at" => {
+                    for (field, value) in fields.iter_mut().zip(parse_times(words)?) {
+                        if let Some(value) = value {
+                            if field.is_some() {
+                                return Err(anyhow!("The times in '{}' conflict with the repeat", words.join(" ")));
+                            }
+                            *field = Some(value);
+                        }
+                    }
+                }
+                "starting" | "from" => starting = Some(parse_date(&words.join(" "))?),
+                "until" => rule.until = Some(parse_date(&words.join(" "))?.and_hms_opt(23, 59, 59).unwrap()),
+                "in" if words.is_empty() => {}
+                "in" => {
+                    if !rule.by_month.is_empty() {
+                        return Err(anyhow!("The months are given twice"));
+                    }
+                    rule.by_month = months(words)?;
+                }
+                "if" => {
+                    let days = words.iter().skip_while(|w| matches!(**w, "it" | "is" | "falls" | "on" | "a" | "an"));
+                    let days: Vec<&str> = days.copied().collect();
+                    rule.by_day.extend(weekdays(&days)?.into_iter().map(|weekday| ByDay { nth: None, weekday }));
+                }
+                _ => match words.as_slice() {
+                    [n, "times" | "occurrences"] => count = Some(n.parse().map_err(|_| anyhow!("Invalid count '{}'", n))?),
+                    _ => return Err(anyhow!("Expected 'for <n> times', found 'for {}'", words.join(" "))),
+                },
+            }
+        }
+        rule.count = count;
+
+        let [hours, minutes, seconds] = fields;
+        let hours = hours.unwrap_or_else(|| if stepped { (0..24).collect() } else { vec![0] });
+        let (mut minutes, mut seconds) = (minutes.unwrap_or(vec![0]), seconds.unwrap_or(vec![0]));
+        let mut hours = hours;
+        for values in [&mut hours, &mut minutes, &mut seconds] {
+            values.sort();
+            values.dedup();
+        }
+        let several = |values: &[u32]| if values.len() > 1 { values.to_vec() } else { Vec::new() };
+        rule.by_hour = several(&hours);
+        rule.by_minute = several(&minutes);
+        rule.by_second = several(&seconds);
+        // Checks the values and puts them in order
+        let rule = Recurrence::parse_rrule(&rule.to_rrule())?;
+
+        let time = NaiveTime::from_hms_opt(hours[0], minutes[0], seconds[0])
+            .ok_or_else(|| anyhow!("Invalid time {:02}:{:02}:{:02}", hours[0], minutes[0], seconds[0]))?;
+        let none = || anyhow!("'{}' never occurs", text.trim());
+        let start = match starting {
+            Some(date) => rule.first_from(date.and_time(time)).ok_or_else(none)?,
+            None => {
+                let first = rule.first_from(anchor.date().and_time(time)).ok_or_else(none)?;
+                match first < anchor {
+                    true => rule.occurrences(first).find(|t| *t >= anchor).ok_or_else(none)?,
+                    false => first,
+                }
+            }
+        };
+        Ok(Self { rule, start, timezone })
+    }
+
+    /// The schedule in the English [`Schedule::parse_english`] reads
+    pub fn describe(&self) -> String {
+        let (repeat, at) = self.describe_time();
+        let (days, conditions) = self.describe_days();
+        let simple = self.rule.interval == 1 && matches!(self.rule.frequency, Frequency::Daily | Frequency::Weekly);
+        let mut text = match repeat {
+            None => days,
+            Some(repeat) if days == "every day" => repeat,
+            Some(repeat) => match days.strip_prefix("every ") {
+                Some("weekday") => format!("{} on weekdays", repeat),
+                Some(weekdays) if simple => format!("{} on {}", repeat, weekdays),
+                _ => format!("{} on {}", repeat, days),
+            },
+        };
+        if let Some(at) = at {
+            text.push_str(&format!(" at {}", at));
+        }
+        if let Some(tz) = self.timezone {
+            text.push_str(&format!(" {}", tz.name()));
+        }
+        text.push_str(&conditions);
+        if self.rule.interval > 1 || self.rule.count.is_some() {
+            text.push_str(&format!(" starting {}", self.start.format("%Y-%m-%d")));
+        }
+        if let Some(until) = self.rule.until {
+            text.push_str(&format!(" until {}", until.format("%Y-%m-%d")));
+        }
+        if let Some(count) = self.rule.count {
+            text.push_str(&format!(", {} times", count));
+        }
+        text
+    }
+
+    /// A repeat within the day such as "every 15 minutes", and what follows "at"
+    fn describe_time(&self) -> (Option<String>, Option<String>) {
+        let rule = &self.rule;
+        let or = |values: &[u32], default: u32| if values.is_empty() { vec![default] } else { values.to_vec() };
+        let hours = or(&rule.by_hour, self.start.hour());
+        let minutes = or(&rule.by_minute, self.start.minute());
+        let seconds = or(&rule.by_second, self.start.second());
+        let times = rule.times(self.start.time());
+        if times.len() <= 6 {
+            let with_seconds = seconds.iter().any(|s| *s != 0);
+            let times: Vec<String> = times
+                .iter()
+                .map(|time| time.format(if with_seconds { "%H:%M:%S" } else { "%H:%M" }).to_string())
+                .collect();
+            return (None, Some(list(&times)));
+        }
+
+        let steps = |values: &[u32], limit: u32| {
+            let step = values.get(1).map_or(limit, |second| second - values[0]);
+            (values[0] == 0 && values.iter().copied().eq((0..limit).step_by(step as usize))).then_some(step)
+        };
+        let numbers = |values: &[u32]| list(&values.iter().map(u32::to_string).collect::<Vec<_>>());
+        if seconds == [0] {
+            match (steps(&hours, 24), steps(&minutes, 60), minutes.as_slice()) {
+                (Some(step), _, [minute]) => {
+                    let repeat = if step == 1 { "every hour".to_string() } else { format!("every {} hours", step) };
+                    return (Some(repeat), (*minute != 0).then(|| format!("minute {}", minute)));
+                }
+                (Some(1), Some(1), _) => return (Some("every minute".to_string()), None),
+                (Some(1), Some(step), _) => return (Some(format!("every {} minutes", step)), None),
+                _ => {}
+            }
+        }
+        let mut at = String::new();
+        if seconds != [0] {
+            at.push_str(&format!("seconds {} of ", numbers(&seconds)));
+        }
+        at.push_str(&format!("minutes {} past hours {}", numbers(&minutes), numbers(&hours)));
+        (None, Some(at))
+    }
+
+    /// The days, and any "in <months>" and "if it is a <weekday>" that follow the time
+    fn describe_days(&self) -> (String, String) {
+        let rule = &self.rule;
+        let n = rule.interval;
+        let name = |weekday: Weekday| WEEKDAY_NAMES[weekday.num_days_from_monday() as usize].to_string();
+        let mut plain: Vec<Weekday> = rule.by_day.iter().filter(|day| day.nth.is_none()).map(|day| day.weekday).collect();
+        plain.sort_by_key(|weekday| weekday.num_days_from_monday());
+        let plain_names: Vec<String> = plain.iter().map(|weekday| name(*weekday)).collect();
+        let month_names = |months: &[u32]| list(&months.iter().map(|m| capitalize(month_name(*m))).collect::<Vec<_>>());
+        let mut specs: Vec<String> = rule
+            .by_month_day
+            .iter()
+            .map(|day| if *day > 0 { ordinal_name(*day) } else { format!("{} day", ordinal_name(*day)) })
+            .collect();
+        specs.extend(rule.by_day.iter().filter_map(|day| day.nth.map(|nth| format!("{} {}", ordinal_name(nth), name(day.weekday)))));
+
+        let mut filter = !plain.is_empty() && !rule.by_month_day.is_empty();
+        let text = match rule.frequency {
+            Frequency::Daily if plain.is_empty() || n > 1 => {
+                filter = !plain.is_empty();
+                if n == 1 { "every day".to_string() } else { format!("every {} days", n) }
+            }
+            Frequency::Daily | Frequency::Weekly => {
+                let days = if plain.is_empty() { vec![name(self.start.weekday())] } else { plain_names.clone() };
+                match n {
+                    1 if plain == WEEKDAYS => "every weekday".to_string(),
+                    1 => format!("every {}", list(&days)),
+                    _ if days.len() == 1 => format!("every {} {}", ordinal_name(n as i32), days[0]),
+                    _ => format!("every {} weeks on {}", n, list(&days)),
+                }
+            }
+            Frequency::Monthly if specs.is_empty() && !plain.is_empty() => match n {
+                1 => format!("every {}", list(&plain_names)),
+                _ => format!("every {} of every {} months", list(&plain_names), n),
+            },
+            Frequency::Monthly => {
+                if specs.is_empty() {
+                    specs.push(ordinal_name(self.start.day() as i32));
+                }
+                match n {
+                    1 => format!("the {} of every month", list(&specs)),
+                    _ => format!("the {} of every {} months", list(&specs), n),
+                }
+            }
+            Frequency::Yearly => {
+                let months = if rule.by_month.is_empty() { vec![self.start.month()] } else { rule.by_month.clone() };
+                let every = if n == 1 { "every year".to_string() } else { format!("every {} years", n) };
+                match (rule.by_month_day.as_slice(), rule.by_day.is_empty(), months.as_slice()) {
+                    ([], true, [month]) => format!("{} on {} {}", every, capitalize(month_name(*month)), self.start.day()),
+                    ([day], true, [month]) if *day > 0 => format!("{} on {} {}", every, capitalize(month_name(*month)), day),
+                    _ => {
+                        if specs.is_empty() {
+                            specs.push(ordinal_name(self.start.day() as i32));
+                        }
+                        format!("the {} of {} {}", list(&specs), month_names(&months), every)
+                    }
+                }
+            }
+        };
+        let mut conditions = String::new();
+        if !rule.by_month.is_empty() && rule.frequency != Frequency::Yearly {
+            conditions.push_str(&format!(" in {}", month_names(&rule.by_month)));
+        }
+        if filter {
+            conditions.push_str(&format!(" if it is a {}", plain_names.join(" or ")));
+        }
+        (text, conditions)
+    }
+}
+
+/// The days of the schedule, any repeat within the day, and whether that
+/// repeat is in minutes (so it runs every hour unless told otherwise)
+fn parse_main(words: &[&str]) -> Result<(Recurrence, TimeFields, bool)> {
+    let step = |n: &str, limit: u32, unit: &str| -> Result<Vec<u32>> {
+        let step: u32 = n.parse().map_err(|_| anyhow!("Invalid number of {}s '{}'", unit, n))?;
+        match step > 0 && limit.is_multiple_of(step) {
+            true => Ok((0..limit).step_by(step as usize).collect()),
+            false => Err(anyhow!("Every {} {}s does not divide the {}", step, unit, if unit == "hour" { "day" } else { "hour" })),
+        }
+    };
+    let (fields, stepped, rest): (TimeFields, bool, &[&str]) = match words {
+        ["every" | "each", "minute", rest @ ..] => ([None, Some((0..60).collect()), None], true, rest),
+        ["every" | "each", n, "minutes", rest @ ..] => ([None, Some(step(n, 60, "minute")?), None], true, rest),
+        ["hourly", rest @ ..] | ["every" | "each", "hour", rest @ ..] => ([Some((0..24).collect()), None, None], false, rest),
+        ["every" | "each", n, "hours", rest @ ..] => ([Some(step(n, 24, "hour")?), None, None], false, rest),
+        _ => ([None, None, None], false, words),
+    };
+    let rule = match rest {
+        [] if stepped || fields[0].is_some() => Recurrence::new(Frequency::Daily),
+        ["on", "every" | "each" | "the", ..] if words.len() != rest.len() => parse_days(&rest[1..])?,
+        ["on", days @ ..] if words.len() != rest.len() => parse_every(days)?,
+        days => parse_days(days)?,
+    };
+    Ok((rule, fields, stepped))
+}
+
+fn parse_days(words: &[&str]) -> Result<Recurrence> {
+    match words {
+        ["daily"] => Ok(Recurrence::new(Frequency::Daily)),
+        ["weekly"] => Ok(Recurrence::new(Frequency::Weekly)),
+        ["monthly"] => Ok(Recurrence::new(Frequency::Monthly)),
+        ["yearly" | "annually"] => Ok(Recurrence::new(Frequency::Yearly)),
+        ["weekdays"] | ["on", "weekdays"] => parse_every(&["weekday"]),
+        ["the", rest @ ..] | ["on", "the", rest @ ..] => parse_nths(rest),
+        ["every" | "each", rest @ ..] | ["on", rest @ ..] => parse_every(rest),
+        _ => Err(unknown_schedule(words)),
+    }
+}
+
+/// What follows "every": "day", "3 weeks on Monday", "other Tuesday", ...
+fn parse_every(words: &[&str]) -> Result<Recurrence> {
+    let (interval, rest) = match words {
+        ["other", rest @ ..] => (2, rest),
+        [n, rest @ ..] if n.parse::<u32>().is_ok() => (n.parse()?, rest),
+        [n, rest @ ..] if ordinal(n).is_some() && !rest.is_empty() => (ordinal(n).unwrap() as u32, rest),
+        _ => (1, words),
+    };
+    let mut rule = match rest {
+        ["day" | "days"] => Recurrence::new(Frequency::Daily),
+        ["week" | "weeks", tail @ ..] => {
+            let mut rule = Recurrence::new(Frequency::Weekly);
+            match tail {
+                [] => {}
+                ["on", days @ ..] => rule.by_day = plain_days(&weekdays(days)?),
+                _ => return Err(unknown_schedule(words)),
+            }
+            rule
+        }
+        ["month" | "months", tail @ ..] => match tail {
+            [] => Recurrence::new(Frequency::Monthly),
+            ["on", "the", specs @ ..] => {
+                let mut rule = Recurrence::new(Frequency::Monthly);
+                add_nths(&mut rule, specs)?;
+                rule
+            }
+            _ => return Err(unknown_schedule(words)),
+        },
+        ["year" | "years", tail @ ..] => {
+            let mut rule = Recurrence::new(Frequency::Yearly);
+            match tail {
+                [] => {}
+                // "on March 15" or "on 15th March"
+                ["on", a, b] => {
+                    let month = |w: &str| parse_month(w).filter(|_| w.parse::<u32>().is_err());
+                    let day = |w: &str| w.trim_end_matches(|c: char| c.is_alphabetic()).parse::<i32>().ok();
+                    match (month(a), day(b), month(b), day(a)) {
+                        (Some(month), Some(day), ..) | (_, _, Some(month), Some(day)) => {
+                            rule.by_month = vec![month];
+                            rule.by_month_day = vec![day];
+                        }
+                        _ => return Err(unknown_schedule(words)),
+                    }
+                }
+                _ => return Err(unknown_schedule(words)),
+            }
+            rule
+        }
+        days => {
+            let mut rule = Recurrence::new(Frequency::Weekly);
+            rule.by_day = plain_days(&weekdays(days)?);
+            rule
+        }
+    };
+    rule.interval = interval;
+    Ok(rule)
+}
+
+/// What follows "the": "2nd Tuesday of every month", "1st and 15th of
+/// March every year", ...
+fn parse_nths(words: &[&str]) -> Result<Recurrence> {
+    let of = words.iter().position(|w| *w == "of").ok_or_else(|| unknown_schedule(words))?;
+    let (specs, period) = (&words[..of], &words[of + 1..]);
+    let mut rule = match period {
+        ["every" | "each", "month"] | ["the", "month"] => Recurrence::new(Frequency::Monthly),
+        ["every" | "each", "other", "month"] => {
+            let mut rule = Recurrence::new(Frequency::Monthly);
+            rule.interval = 2;
+            rule
+        }
+        ["every" | "each", n, "months"] => {
+            let mut rule = Recurrence::new(Frequency::Monthly);
+            rule.interval = n.parse().map_err(|_| anyhow!("Invalid number of months '{}'", n))?;
+            rule
+        }
+        _ => {
+            let every = period.iter().position(|w| *w == "every" || *w == "each").unwrap_or(period.len());
+            let mut rule = Recurrence::new(Frequency::Yearly);
+            rule.by_month = months(&period[..every])?;
+            rule.interval = match &period[every..] {
+                [] | [_, "year"] => 1,
+                [_, "other", "year"] => 2,
+                [_, n, "years"] => n.parse().map_err(|_| anyhow!("Invalid number of years '{}'", n))?,
+                _ => return Err(unknown_schedule(words)),
+            };
+            rule
+        }
+    };
+    add_nths(&mut rule, specs)?;
+    Ok(rule)
+}
+
+/// "1st", "last day", "2nd to last day", "2nd Tuesday" or "last Friday",
+/// joined by "and" or commas
+fn add_nths(rule: &mut Recurrence, words: &[&str]) -> Result<()> {
+    for item in words.split(|w| matches!(*w, "and" | "," | "or")).filter(|item| !item.is_empty()) {
+        let item = item.strip_prefix(&["the"]).unwrap_or(item);
+        let (nth, rest) = match item {
+            ["last", rest @ ..] => (-1, rest),
+            [n, "to", "last", rest @ ..] => (-ordinal(n).ok_or_else(|| unknown_day(item))?, rest),
+            [n, rest @ ..] => (ordinal(n).ok_or_else(|| unknown_day(item))?, rest),
+            [] => continue,
+        };
+        match rest {
+            [] | ["day"] if nth > 0 => rule.by_month_day.push(nth),
+            ["day"] => rule.by_month_day.push(nth),
+            [day] => rule.by_day.push(ByDay { nth: Some(nth), weekday: weekday(day).ok_or_else(|| unknown_day(item))? }),
+            _ => return Err(unknown_day(item)),
+        }
+    }
+    match rule.by_month_day.is_empty() && rule.by_day.is_empty() {
+        true => Err(anyhow!("No days given before 'of'")),
+        false => Ok(()),
+    }
+}
+
+/// The hours, minutes and seconds after "at": times such as "9am and
+/// 17:30", or "minute 30", or "minutes 0 and 30 past hours 9 and 10"
+fn parse_times(words: &[&str]) -> Result<TimeFields> {
+    let mut fields: TimeFields = [None, None, None];
+    if words.first().is_some_and(|w| ["second", "minute", "hour"].contains(&w.trim_end_matches('s'))) {
+        let mut unit = 0;
+        for word in words {
+            match word.trim_end_matches('s') {
+                "hour" => unit = 0,
+                "minute" => unit = 1,
+                "second" => unit = 2,
+                "of" | "past" | "and" | "," => {}
+                n => {
+                    let value = n.parse().map_err(|_| anyhow!("Invalid {} '{}'", ["hour", "minute", "second"][unit], n))?;
+                    fields[unit].get_or_insert_with(Vec::new).push(value);
+                }
+            }
+        }
+        return Ok(fields);
+    }
+
+    let mut times = Vec::new();
+    for item in words.split(|w| matches!(*w, "and" | ",")).filter(|item| !item.is_empty()) {
+        let text = item.concat();
+        times.push(parse_time_of_day(&text).ok_or_else(|| anyhow!("Invalid time '{}'", item.join(" ")))?);
+    }
+    if times.is_empty() {
+        return Err(anyhow!("Expected a time after 'at'"));
+    }
+    let distinct = |part: fn(&NaiveTime) -> u32| {
+        let mut values: Vec<u32> = times.iter().map(part).collect();
+        values.sort();
+        values.dedup();
+        values
+    };
+    let (hours, minutes, seconds) = (distinct(NaiveTime::hour), distinct(NaiveTime::minute), distinct(NaiveTime::second));
+    times.sort();
+    times.dedup();
+    if hours.len() * minutes.len() * seconds.len() != times.len() {
+        return Err(anyhow!(
+            "The times {} are not every combination of their hours and minutes, which a single rule needs",
+            words.join(" ").replace(" ,", ",")
+        ));
+    }
+    Ok([Some(hours), Some(minutes), Some(seconds)])
+}
+
+fn weekday(word: &str) -> Option<Weekday> {
+    word.trim_end_matches('s').parse().ok()
+}
+
+/// "Monday and Friday", "mon, wed", "Monday to Friday", "weekdays", "weekends"
+fn weekdays(words: &[&str]) -> Result<Vec<Weekday>> {
+    // "mon-fri" is a range too
+    let mut tokens = Vec::new();
+    for word in words {
+        match word.split_once('-') {
+            Some((from, to)) => tokens.extend([from, "to", to]),
+            None => tokens.push(*word),
+        }
+    }
+    let mut days: Vec<Weekday> = Vec::new();
+    let mut range = false;
+    for word in tokens {
+        match word {
+            "and" | "," | "or" | "" => {}
+            "to" | "through" | "thru" => range = true,
+            "weekday" | "weekdays" => days.extend(WEEKDAYS),
+            "weekend" | "weekends" => days.extend([Weekday::Sat, Weekday::Sun]),
+            _ => {
+                let day = weekday(word).ok_or_else(|| unknown_day(words))?;
+                if std::mem::take(&mut range) {
+                    let mut from = days.last().copied().ok_or_else(|| unknown_day(words))?;
+                    while from != day {
+                        from = from.succ();
+                        days.push(from);
+                    }
+                } else {
+                    days.push(day);
+                }
+            }
+        }
+    }
+    match days.is_empty() {
+        true => Err(unknown_day(words)),
+        false => Ok(days),
+    }
+}
+
+fn plain_days(days: &[Weekday]) -> Vec<ByDay> {
+    days.iter().map(|weekday| ByDay { nth: None, weekday: *weekday }).collect()
+}
+
+fn months(words: &[&str]) -> Result<Vec<u32>> {
+    let months: Vec<u32> = words
+        .iter()
+        .filter(|w| !matches!(**w, "and" | "," | "or"))
+        .map(|w| parse_month(w).ok_or_else(|| anyhow!("Unknown month '{}'", w)))
+        .collect::<Result<_>>()?;
+    match months.is_empty() {
+        true => Err(anyhow!("Expected month names")),
+        false => Ok(months),
+    }
+}
+
+fn unknown_day(words: &[&str]) -> anyhow::Error {
+    anyhow!("Unknown day '{}'; expected e.g. 'Monday', '15th', '2nd Tuesday' or 'last day'", words.join(" "))
+}
+
+fn unknown_schedule(words: &[&str]) -> anyhow::Error {
+    anyhow!(
+        "Unknown schedule '{}'; expected e.g. 'every weekday', 'every 2 weeks on Monday', 'the last Friday of every month' or 'every year on March 15'",
+        words.join(" ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(text: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn english(text: &str) -> Schedule {
+        let iso = |d: &str| NaiveDate::parse_from_str(d, "%Y-%m-%d").map_err(|e| anyhow!("{}", e));
+        Schedule::parse_english(text, at("2026-10-19 12:00"), iso).unwrap()
+    }
+
+    #[test]
+    fn test_parse_english() {
+        let cases = [
+            ("every weekday at 9am", "FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR", "2026-10-20 09:00"),
+            ("every 2nd Tuesday at 09:00 Europe/London", "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU", "2026-10-20 09:00"),
+            ("the last Friday of every month at 17:30", "FREQ=MONTHLY;BYDAY=-1FR", "2026-10-30 17:30"),
+            ("the 1st and 15th of every month", "FREQ=MONTHLY;BYMONTHDAY=1,15", "2026-11-01 00:00"),
+            ("the 4th Thursday of November every year at noon", "FREQ=YEARLY;BYDAY=4TH;BYMONTH=11", "2026-11-26 12:00"),
+            ("every 15 minutes on weekdays", "FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR;BYHOUR=0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,21,22,23;BYMINUTE=0,15,30,45", "2026-10-19 12:00"),
+            ("every day at 9:00 and 17:00 until 2026-12-31", "FREQ=DAILY;BYHOUR=9,17;UNTIL=20261231T235959", "2026-10-19 17:00"),
+        ];
+        for (text, rrule, start) in cases {
+            let schedule = english(text);
+            assert_eq!((schedule.rule.to_rrule().as_str(), schedule.start), (rrule, at(start)), "{}", text);
+        }
+        assert_eq!(english("every 2nd Tuesday at 09:00 Europe/London").timezone, Some(chrono_tz::Europe::London));
+
+        let iso = |_: &str| -> Result<NaiveDate> { Err(anyhow!("no dates")) };
+        assert!(Schedule::parse_english("every day at 9:00 and 17:30", at("2026-10-19 12:00"), iso).is_err());
+        assert!(Schedule::parse_english("whenever", at("2026-10-19 12:00"), iso).is_err());
+    }
+
+    #[test]
+    fn test_describe_round_trip() {
+        let texts = [
+            "every weekday at 09:00",
+            "every 2nd Tuesday at 09:00 Europe/London starting 2026-10-20",
+            "the 2nd to last day of every month at 17:30",
+            "the 13th of every month at 00:00 if it is a Friday",
+            "every year on March 15 at 08:00",
+            "every 15 minutes on Monday and Friday",
+            "every 2 hours at minute 30",
+            "every day at minutes 0 and 30 past hours 9, 10, 11 and 12",
+            "the 1st Monday of every month at 10:00 in January, April, July and October starting 2027-01-04, 4 times",
+        ];
+        for text in texts {
+            let schedule = english(text);
+            assert_eq!(schedule.describe(), text);
+        }
+
+        let ical = Schedule::parse_ical("DTSTART;TZID=Europe/Paris:20261020T090000\nRRULE:FREQ=WEEKLY;BYDAY=TU", at("2026-10-19 00:00").date());
+        let ical = ical.unwrap();
+        assert_eq!(ical.to_ical(), "DTSTART;TZID=Europe/Paris:20261020T090000\nRRULE:FREQ=WEEKLY;BYDAY=TU");
+        assert_eq!(ical.describe(), "every Tuesday at 09:00 Europe/Paris");
+    }
+}
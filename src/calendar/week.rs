@@ -0,0 +1,173 @@
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+/// Week numbering systems
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WeekSystem {
+    /// ISO 8601: Monday-start weeks, week 1 contains January 4
+    Iso,
+    /// US: Sunday-start weeks, week 1 contains January 1
+    Us,
+    /// Broadcast: Monday-start weeks, week 1 contains January 1
+    Broadcast,
+}
+
+/// Where a date falls in a week numbering system
+#[derive(Debug, Clone)]
+pub struct WeekDate {
+    pub week_year: i32,
+    pub week: u32,
+    /// Day within the week, 1 = first day of the week
+    pub day: u32,
+    pub week_start: NaiveDate,
+    pub week_end: NaiveDate,
+    pub weeks_in_year: u32,
+}
+
+impl WeekSystem {
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "iso" | "iso8601" | "iso_8601" => Ok(Self::Iso),
+            "us" | "sunday" | "us_sunday" => Ok(Self::Us),
+            "broadcast" => Ok(Self::Broadcast),
+            _ => Err(anyhow!(
+                "Unknown week system '{}'. Valid systems: iso, us, broadcast",
+                name
+            )),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Iso => "iso",
+            Self::Us => "us",
+            Self::Broadcast => "broadcast",
+        }
+    }
+
+    pub fn first_weekday(self) -> Weekday {
+        match self {
+            Self::Us => Weekday::Sun,
+            Self::Iso | Self::Broadcast => Weekday::Mon,
+        }
+    }
+
+    /// First day of the week containing a date
+    pub fn week_start(self, date: NaiveDate) -> NaiveDate {
+        let offset = (date.weekday().num_days_from_monday() + 7
+            - self.first_weekday().num_days_from_monday())
+            % 7;
+        date - Duration::days(offset as i64)
+    }
+
+    /// First day of week 1 of a week-numbering year
+    fn year_start(self, year: i32) -> Option<NaiveDate> {
+        let anchor = match self {
+            Self::Iso => NaiveDate::from_ymd_opt(year, 1, 4)?,
+            Self::Us | Self::Broadcast => NaiveDate::from_ymd_opt(year, 1, 1)?,
+        };
+        Some(self.week_start(anchor))
+    }
+
+    /// Locate a date within its week-numbering year
+    pub fn locate(self, date: NaiveDate) -> Result<WeekDate> {
+        let out_of_range = || anyhow!("{} is out of range for week numbering", date);
+
+        let mut week_year = date.year();
+        if date >= self.year_start(week_year + 1).ok_or_else(out_of_range)? {
+            week_year += 1;
+        } else if date < self.year_start(week_year).ok_or_else(out_of_range)? {
+            week_year -= 1;
+        }
+
+        let year_start = self.year_start(week_year).ok_or_else(out_of_range)?;
+        let next_year_start = self.year_start(week_year + 1).ok_or_else(out_of_range)?;
+        let week_start = self.week_start(date);
+
+        Ok(WeekDate {
+            week_year,
+            week: ((week_start - year_start).num_days() / 7) as u32 + 1,
+            day: ((date - week_start).num_days() + 1) as u32,
+            week_start,
+            week_end: week_start + Duration::days(6),
+            weeks_in_year: ((next_year_start - year_start).num_days() / 7) as u32,
+        })
+    }
+
+    /// First and last day of a numbered week
+    pub fn week_range(self, year: i32, week: u32) -> Result<(NaiveDate, NaiveDate)> {
+        let year_start = self
+            .year_start(year)
+            .ok_or_else(|| anyhow!("Year {} is out of range", year))?;
+        let weeks_in_year = self.locate(year_start)?.weeks_in_year;
+
+        if !(1..=weeks_in_year).contains(&week) {
+            return Err(anyhow!(
+                "{} week-year {} has {} weeks, got week {}",
+                self.name(),
+                year,
+                weeks_in_year,
+                week
+            ));
+        }
+
+        let start = year_start + Duration::weeks(week as i64 - 1);
+        Ok((start, start + Duration::days(6)))
+    }
+
+    /// Date within the same week that falls on the given weekday
+    pub fn align(self, date: NaiveDate, weekday: Weekday) -> NaiveDate {
+        let offset = (weekday.num_days_from_monday() + 7
+            - self.first_weekday().num_days_from_monday())
+            % 7;
+        self.week_start(date) + Duration::days(offset as i64)
+    }
+}
+
+/// Format a date as an ISO 8601 week date, e.g. "2026-W43-4"
+pub fn iso_week_date(date: NaiveDate) -> String {
+    let week = date.iso_week();
+    format!(
+        "{:04}-W{:02}-{}",
+        week.year(),
+        week.week(),
+        date.weekday().number_from_monday()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_iso_week_year_differs_from_calendar_year() {
+        let week = WeekSystem::Iso.locate(date(2027, 1, 1)).unwrap();
+        assert_eq!(week.week_year, 2026);
+        assert_eq!(week.week, 53);
+        assert_eq!(iso_week_date(date(2026, 10, 22)), "2026-W43-4");
+    }
+
+    #[test]
+    fn test_us_and_broadcast_weeks() {
+        let us = WeekSystem::Us.locate(date(2026, 10, 18)).unwrap();
+        assert_eq!(us.week, 43);
+        assert_eq!(us.day, 1);
+        assert_eq!(us.week_start, date(2026, 10, 18));
+
+        let broadcast = WeekSystem::Broadcast.locate(date(2025, 12, 29)).unwrap();
+        assert_eq!(broadcast.week_year, 2026);
+        assert_eq!(broadcast.week, 1);
+    }
+
+    #[test]
+    fn test_week_range_and_align() {
+        let (start, end) = WeekSystem::Iso.week_range(2026, 43).unwrap();
+        assert_eq!((start, end), (date(2026, 10, 19), date(2026, 10, 25)));
+        assert!(WeekSystem::Iso.week_range(2025, 53).is_err());
+        assert_eq!(WeekSystem::Us.align(date(2026, 10, 22), Weekday::Mon), date(2026, 10, 19));
+    }
+}
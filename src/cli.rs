@@ -0,0 +1,641 @@
+use std::io::Read;
+use std::process::ExitCode;
+
+use clap::{Args, Subcommand, ValueEnum};
+use serde::Serialize;
+use serde_json::Value;
+
+use ucm::Ucm;
+use ucm::tools::batch::{parse_operations, BatchOperation};
+use ucm::tools::events::EventFields;
+use ucm::tools::free_slots::SlotQuery;
+use ucm::tools::{
+    ucm_add, ucm_age, ucm_astro_events, ucm_batch, ucm_convert, ucm_cron, ucm_diff, ucm_era, ucm_eval, ucm_event_create,
+    ucm_event_delete, ucm_event_export, ucm_event_list, ucm_event_update, ucm_free_slots, ucm_info, ucm_instructions,
+    ucm_mark_delete, ucm_mark_list, ucm_mark_set, ucm_now, ucm_nth_weekday, ucm_parse, ucm_period, ucm_remind,
+    ucm_reminders, ucm_retail, ucm_schedule_translate, ucm_status, ucm_sun, ucm_timestamp, ucm_week,
+};
+use ucm::types::UcmError;
+
+/// How command results are printed
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    /// Aligned key/value lines, with lists shown as tables
+    Table,
+    /// The same pretty-printed JSON the MCP tools return
+    Json,
+}
+
+/// Run a single tool from the command line instead of serving MCP
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Current date and time
+    Now {
+        /// Fiscal calendar profile or starting month
+        #[arg(long)]
+        fiscal_calendar: Option<String>,
+    },
+    /// Parse a natural language date expression
+    Parse {
+        /// e.g. "next friday", "R8.10.18", "FY27 Q2"
+        expression: String,
+    },
+    /// Difference between two dates
+    Diff { from: String, to: String },
+    /// Add a duration to a date
+    Add {
+        date: String,
+        /// e.g. "3 weeks" or "-5 days"
+        #[arg(allow_hyphen_values = true)]
+        duration: String,
+    },
+    /// Convert a duration between units
+    Convert {
+        #[arg(allow_negative_numbers = true)]
+        value: f64,
+        /// days, weeks, months, years, hours, minutes or seconds
+        unit: String,
+    },
+    /// Detailed information about a date
+    Info {
+        date: String,
+        #[arg(long)]
+        fiscal_calendar: Option<String>,
+        #[arg(long)]
+        retail_calendar: Option<String>,
+    },
+    /// Convert between Gregorian dates and Japanese eras
+    Era { date: String },
+    /// Map a date to a retail 4-4-5/4-5-4/5-4-4 calendar, or back
+    Retail {
+        /// "nrf" or a spec such as "4-4-5 last saturday august"
+        calendar: String,
+        #[arg(long)]
+        date: Option<String>,
+        #[arg(long)]
+        year: Option<i32>,
+        #[arg(long)]
+        period: Option<u32>,
+        #[arg(long)]
+        week: Option<u32>,
+    },
+    /// ISO, US and broadcast week numbers
+    Week {
+        #[arg(long)]
+        date: Option<String>,
+        #[arg(long)]
+        year: Option<i32>,
+        #[arg(long)]
+        week: Option<u32>,
+        /// iso, us or broadcast
+        #[arg(long)]
+        system: Option<String>,
+        #[arg(long, allow_negative_numbers = true)]
+        add_weeks: Option<i64>,
+        /// Weekday to align the result to, e.g. "monday"
+        #[arg(long)]
+        align: Option<String>,
+    },
+    /// Sunrise, sunset and twilight times
+    Sun {
+        date: String,
+        #[arg(long, allow_negative_numbers = true)]
+        latitude: Option<f64>,
+        #[arg(long, allow_negative_numbers = true)]
+        longitude: Option<f64>,
+        #[arg(long)]
+        city: Option<String>,
+        #[arg(long)]
+        timezone: Option<String>,
+    },
+    /// Moon phases, equinoxes, solstices and eclipses in a date range
+    AstroEvents {
+        from: String,
+        #[arg(long)]
+        to: Option<String>,
+        /// Comma-separated, e.g. "full_moon,eclipses"
+        #[arg(long, value_delimiter = ',')]
+        kinds: Option<Vec<String>>,
+        #[arg(long)]
+        timezone: Option<String>,
+    },
+    /// Run a JSON array of operations, e.g.
+    /// '[{"tool":"add","params":{"date":"today","add":"3 weeks"}}]'
+    Batch {
+        /// The operations as JSON, or "-" to read them from stdin
+        operations: String,
+    },
+    /// Evaluate a date expression, e.g. "end_of_month(today) - 1 week"
+    Eval {
+        expression: String,
+    },
+    /// Start and end of the period containing a date, e.g. "next quarter"
+    Period {
+        /// A date inside the period, or a period phrase when --unit is omitted
+        date: Option<String>,
+        /// day, week, iso_week, us_week, month, quarter, half, year or fiscal_year
+        #[arg(long)]
+        unit: Option<String>,
+        /// 1 for the next period, -1 for the previous one
+        #[arg(long, allow_negative_numbers = true)]
+        offset: Option<i32>,
+        #[arg(long)]
+        week_start: Option<String>,
+        #[arg(long)]
+        fiscal_calendar: Option<String>,
+    },
+    /// The nth weekday of a period, e.g. "last Friday of each month in 2027"
+    NthWeekday {
+        expression: String,
+        /// Skip this country's public holidays when counting business days
+        #[arg(long)]
+        country: Option<String>,
+    },
+    /// Age or tenure from a birth or start date
+    Age {
+        date: String,
+        #[arg(long)]
+        as_of: Option<String>,
+        /// Also show when this age is reached, e.g. 65
+        #[arg(long)]
+        turns: Option<u32>,
+        /// february_28 or march_1
+        #[arg(long)]
+        leap_day: Option<String>,
+        /// Number of upcoming anniversaries to list
+        #[arg(long)]
+        anniversaries: Option<u32>,
+    },
+    /// Save a named date, usable as @name in any command
+    MarkSet {
+        name: String,
+        date: String,
+        #[arg(long)]
+        note: Option<String>,
+    },
+    /// Saved named dates with countdowns
+    MarkList,
+    /// Delete a saved named date
+    MarkDelete { name: String },
+    /// Create an event
+    EventCreate {
+        title: String,
+        /// e.g. "2026-10-20T09:30" or "next tuesday at 2pm"
+        start: String,
+        #[command(flatten)]
+        options: EventOptions,
+    },
+    /// Change fields of an event
+    EventUpdate {
+        id: String,
+        #[arg(long)]
+        title: Option<String>,
+        #[arg(long)]
+        start: Option<String>,
+        #[command(flatten)]
+        options: EventOptions,
+    },
+    /// Delete an event
+    EventDelete { id: String },
+    /// Events on a day or in a period, e.g. "next tuesday" or "this week"
+    EventList {
+        when: Option<String>,
+        #[arg(long)]
+        from: Option<String>,
+        #[arg(long)]
+        to: Option<String>,
+        #[arg(long)]
+        tag: Option<String>,
+    },
+    /// Events as an iCalendar file; the .ics text is printed as is unless
+    /// --format json is given
+    EventExport {
+        #[arg(long)]
+        tag: Option<String>,
+    },
+    /// Free slots of at least a duration in working hours
+    FreeSlots {
+        /// e.g. "90 minutes"
+        duration: String,
+        /// Day or period, e.g. "this week"
+        when: Option<String>,
+        #[arg(long)]
+        from: Option<String>,
+        #[arg(long)]
+        to: Option<String>,
+        /// Busy interval "start/end"; repeatable
+        #[arg(long)]
+        busy: Vec<String>,
+        /// .ics file of busy events, in the data directory
+        #[arg(long)]
+        ics_file: Option<String>,
+        /// Ignore stored events
+        #[arg(long)]
+        no_events: bool,
+        /// e.g. "09:00-17:00"
+        #[arg(long)]
+        working_hours: Option<String>,
+        /// e.g. "mon-fri"
+        #[arg(long)]
+        working_days: Option<String>,
+        #[arg(long)]
+        timezone: Option<String>,
+        /// e.g. "12:00-13:00"
+        #[arg(long)]
+        lunch: Option<String>,
+        #[arg(long)]
+        buffer_before: Option<String>,
+        #[arg(long)]
+        buffer_after: Option<String>,
+        /// US, GB, CA or AU
+        #[arg(long)]
+        country: Option<String>,
+        #[arg(long)]
+        limit: Option<usize>,
+    },
+    /// Save a reminder, delivered by a running MCP server when due
+    Remind {
+        /// e.g. "tomorrow at 9am" or "in 20 minutes"
+        when: String,
+        message: String,
+    },
+    /// Pending reminders, and any missed with no client connected
+    Reminders {
+        /// Id of a reminder to cancel
+        #[arg(long)]
+        cancel: Option<String>,
+    },
+    /// Explain a cron expression and list its next runs
+    Cron {
+        /// e.g. "*/15 9-17 * * MON-FRI" or "0 0 12 ? * 6#3"
+        expression: String,
+        #[arg(long)]
+        count: Option<usize>,
+        #[arg(long)]
+        timezone: Option<String>,
+        /// List runs after this time (default now)
+        #[arg(long)]
+        from: Option<String>,
+        /// List the most recent runs instead
+        #[arg(long)]
+        previous: bool,
+    },
+    /// Translate a schedule between cron, RRULE and English
+    ScheduleTranslate {
+        /// e.g. "0 9 * * 1-5", "RRULE:FREQ=MONTHLY;BYDAY=-1FR" or "every 2nd Tuesday at 09:00"
+        schedule: String,
+        /// cron, rrule or english (default: detected)
+        #[arg(long)]
+        source: Option<String>,
+        /// cron, rrule or english (default: both others)
+        #[arg(long)]
+        target: Option<String>,
+        #[arg(long)]
+        timezone: Option<String>,
+        /// When a schedule without a start begins (default now)
+        #[arg(long)]
+        start: Option<String>,
+        #[arg(long)]
+        samples: Option<usize>,
+    },
+    /// Convert a raw timestamp, or a date and time, to every timestamp unit
+    Timestamp {
+        /// e.g. "1792402200", "134368758002500000", "46314.5" or "2026-10-19T09:30:00Z"
+        value: String,
+        /// unix_s, unix_ms, unix_us, unix_ns, filetime, dotnet_ticks, excel, mac_hfs, ntp or gps
+        #[arg(long)]
+        unit: Option<String>,
+        #[arg(long)]
+        timezone: Option<String>,
+    },
+    /// Version and build information
+    Status,
+    /// How to use each tool
+    Instructions,
+}
+
+/// Event fields shared by event-create and event-update
+#[derive(Debug, Args)]
+pub struct EventOptions {
+    #[arg(long)]
+    end: Option<String>,
+    /// e.g. "90 minutes"
+    #[arg(long)]
+    duration: Option<String>,
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    all_day: Option<bool>,
+    /// IANA time zone
+    #[arg(long)]
+    timezone: Option<String>,
+    /// RRULE or daily, weekly, monthly, yearly, weekdays, "every 2 weeks"
+    #[arg(long)]
+    recurrence: Option<String>,
+    /// Comma-separated
+    #[arg(long, value_delimiter = ',')]
+    tags: Option<Vec<String>>,
+    #[arg(long)]
+    note: Option<String>,
+}
+
+impl EventOptions {
+    fn into_fields(self, title: Option<String>, start: Option<String>) -> EventFields {
+        EventFields {
+            title,
+            start,
+            end: self.end,
+            duration: self.duration,
+            all_day: self.all_day,
+            timezone: self.timezone,
+            recurrence: self.recurrence,
+            tags: self.tags,
+            note: self.note,
+        }
+    }
+}
+
+/// Run a command, print its result and return the process exit code
+pub fn run(command: Command, format: OutputFormat, ucm: &Ucm) -> ExitCode {
+    let output = match command {
+        Command::Now { fiscal_calendar } => render(ucm_now(ucm, fiscal_calendar.as_deref()), format),
+        Command::Parse { expression } => render(ucm_parse(ucm, &expression), format),
+        Command::Diff { from, to } => render(ucm_diff(ucm, &from, &to), format),
+        Command::Add { date, duration } => render(ucm_add(ucm, &date, &duration), format),
+        Command::Convert { value, unit } => render(ucm_convert(value, &unit), format),
+        Command::Info { date, fiscal_calendar, retail_calendar } => render(
+            ucm_info(ucm, &date, fiscal_calendar.as_deref(), retail_calendar.as_deref()),
+            format,
+        ),
+        Command::Era { date } => render(ucm_era(ucm, &date), format),
+        Command::Retail { calendar, date, year, period, week } => render(
+            ucm_retail(ucm, &calendar, date.as_deref(), year, period, week),
+            format,
+        ),
+        Command::Week { date, year, week, system, add_weeks, align } => render(
+            ucm_week(ucm, date.as_deref(), year, week, system.as_deref(), add_weeks, align.as_deref()),
+            format,
+        ),
+        Command::Sun { date, latitude, longitude, city, timezone } => render(
+            ucm_sun(ucm, &date, latitude, longitude, city.as_deref(), timezone.as_deref()),
+            format,
+        ),
+        Command::AstroEvents { from, to, kinds, timezone } => render(
+            ucm_astro_events(ucm, &from, to.as_deref(), kinds.as_deref(), timezone.as_deref()),
+            format,
+        ),
+        Command::Batch { operations } => render(
+            read_operations(&operations).and_then(|operations| ucm_batch(ucm, &operations)),
+            format,
+        ),
+        Command::Eval { expression } => render(ucm_eval(ucm, &expression), format),
+        Command::Period { date, unit, offset, week_start, fiscal_calendar } => render(
+            ucm_period(ucm, date.as_deref(), unit.as_deref(), offset, week_start.as_deref(), fiscal_calendar.as_deref()),
+            format,
+        ),
+        Command::NthWeekday { expression, country } => {
+            render(ucm_nth_weekday(ucm, &expression, country.as_deref()), format)
+        }
+        Command::Age { date, as_of, turns, leap_day, anniversaries } => render(
+            ucm_age(ucm, &date, as_of.as_deref(), turns, leap_day.as_deref(), anniversaries),
+            format,
+        ),
+        Command::MarkSet { name, date, note } => render(ucm_mark_set(ucm, &name, &date, note.as_deref()), format),
+        Command::MarkList => render(ucm_mark_list(ucm), format),
+        Command::MarkDelete { name } => render(ucm_mark_delete(ucm, &name), format),
+        Command::EventCreate { title, start, options } => {
+            render(ucm_event_create(ucm, options.into_fields(Some(title), Some(start))), format)
+        }
+        Command::EventUpdate { id, title, start, options } => {
+            render(ucm_event_update(ucm, &id, options.into_fields(title, start)), format)
+        }
+        Command::EventDelete { id } => render(ucm_event_delete(ucm, &id), format),
+        Command::EventList { when, from, to, tag } => render(
+            ucm_event_list(ucm, when.as_deref(), from.as_deref(), to.as_deref(), tag.as_deref()),
+            format,
+        ),
+        Command::EventExport { tag } => match format {
+            OutputFormat::Json => render(ucm_event_export(ucm, tag.as_deref()), format),
+            OutputFormat::Table => ucm_event_export(ucm, tag.as_deref()).map(|export| export.ics),
+        },
+        Command::FreeSlots {
+            duration,
+            when,
+            from,
+            to,
+            busy,
+            ics_file,
+            no_events,
+            working_hours,
+            working_days,
+            timezone,
+            lunch,
+            buffer_before,
+            buffer_after,
+            country,
+            limit,
+        } => {
+            let query = SlotQuery {
+                duration,
+                when,
+                from,
+                to,
+                busy,
+                ics_file,
+                include_events: no_events.then_some(false),
+                working_hours,
+                working_days,
+                timezone,
+                lunch,
+                buffer_before,
+                buffer_after,
+                country,
+                limit,
+            };
+            render(ucm_free_slots(ucm, query), format)
+        }
+        Command::Remind { when, message } => render(ucm_remind(ucm, &when, &message), format),
+        Command::Reminders { cancel } => render(ucm_reminders(ucm, cancel.as_deref()), format),
+        Command::Cron { expression, count, timezone, from, previous } => render(
+            ucm_cron(ucm, &expression, count, timezone.as_deref(), from.as_deref(), previous),
+            format,
+        ),
+        Command::ScheduleTranslate { schedule, source, target, timezone, start, samples } => render(
+            ucm_schedule_translate(
+                ucm,
+                &schedule,
+                source.as_deref(),
+                target.as_deref(),
+                timezone.as_deref(),
+                start.as_deref(),
+                samples,
+            ),
+            format,
+        ),
+        Command::Timestamp { value, unit, timezone } => {
+            render(ucm_timestamp(ucm, &value, unit.as_deref(), timezone.as_deref()), format)
+        }
+        Command::Status => render(Ok(ucm_status()), format),
+        Command::Instructions => render(Ok(ucm_instructions()), format),
+    };
+
+    match output {
+        Ok(text) => {
+            print!("{}", text);
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            match format {
+                OutputFormat::Json => eprintln!("{}", serde_json::to_string_pretty(&error).unwrap()),
+                OutputFormat::Table => eprintln!("error ({}): {}", error.error, error.message),
+            }
+            ExitCode::from(error.exit_code())
+        }
+    }
+}
+
+fn read_operations(argument: &str) -> Result<Vec<BatchOperation>, UcmError> {
+    if argument != "-" {
+        return parse_operations(argument);
+    }
+    let mut json = String::new();
+    std::io::stdin()
+        .read_to_string(&mut json)
+        .map_err(|e| UcmError::parse_error("-", &format!("Could not read operations from stdin: {}", e)))?;
+    parse_operations(&json)
+}
+
+fn render<T: Serialize>(result: Result<T, UcmError>, format: OutputFormat) -> Result<String, UcmError> {
+    result.map(|response| match format {
+        OutputFormat::Json => format!("{}\n", serde_json::to_string_pretty(&response).unwrap()),
+        OutputFormat::Table => render_table(&serde_json::to_value(&response).unwrap()),
+    })
+}
+
+fn scalar(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Null => String::new(),
+        Value::Array(items) => items.iter().map(scalar).collect::<Vec<_>>().join(", "),
+        Value::Object(_) => value.to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Flatten nested objects into dotted keys; arrays of objects are set aside
+/// to be printed as tables
+fn flatten<'a>(
+    prefix: &str,
+    value: &'a Value,
+    rows: &mut Vec<(String, String)>,
+    tables: &mut Vec<(String, &'a Vec<Value>)>,
+) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let key = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+                flatten(&key, child, rows, tables);
+            }
+        }
+        Value::Array(items) if items.iter().any(Value::is_object) => {
+            tables.push((prefix.to_string(), items));
+        }
+        _ => rows.push((prefix.to_string(), scalar(value))),
+    }
+}
+
+/// Render a JSON response as aligned key/value lines followed by a table
+/// for each list of objects
+fn render_table(value: &Value) -> String {
+    let mut rows = Vec::new();
+    let mut tables = Vec::new();
+    flatten("", value, &mut rows, &mut tables);
+
+    let mut out = String::new();
+    let width = rows.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+    for (key, text) in &rows {
+        out.push_str(&format!("{:<width$}  {}\n", key, text, width = width));
+    }
+
+    for (name, items) in tables {
+        let mut columns: Vec<String> = Vec::new();
+        let mut cells: Vec<Vec<(String, String)>> = Vec::new();
+        for item in items {
+            let mut item_rows = Vec::new();
+            flatten("", item, &mut item_rows, &mut Vec::new());
+            for (key, _) in &item_rows {
+                if !columns.contains(key) {
+                    columns.push(key.clone());
+                }
+            }
+            cells.push(item_rows);
+        }
+
+        let grid: Vec<Vec<String>> = cells
+            .iter()
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|column| {
+                        row.iter()
+                            .find(|(key, _)| key == column)
+                            .map(|(_, text)| text.clone())
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            })
+            .collect();
+        let widths: Vec<usize> = columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| grid.iter().map(|row| row[i].len()).chain([column.len()]).max().unwrap())
+            .collect();
+
+        let line = |values: &[String]| -> String {
+            let padded: Vec<String> = values
+                .iter()
+                .zip(&widths)
+                .map(|(text, width)| format!("{:<width$}", text, width = width))
+                .collect();
+            format!("{}\n", padded.join("  ").trim_end())
+        };
+
+        out.push_str(&format!("\n{}\n", name));
+        out.push_str(&line(&columns));
+        out.push_str(&line(&widths.iter().map(|w| "-".repeat(*w)).collect::<Vec<_>>()));
+        for row in &grid {
+            out.push_str(&line(row));
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_render_table() {
+        let value = json!({
+            "date": "2026-10-18",
+            "fiscal": { "fiscal_year": 2027 },
+            "events": [
+                { "name": "Full Moon", "days_from_now": 8 },
+                { "name": "December Solstice", "days_from_now": 64 }
+            ]
+        });
+        let text = render_table(&value);
+        assert!(text.contains("date                2026-10-18\n"));
+        assert!(text.contains("fiscal.fiscal_year  2027\n"));
+        assert!(text.contains("\nevents\ndays_from_now  name\n"));
+        assert!(text.contains("64             December Solstice\n"));
+    }
+
+    #[test]
+    fn test_error_exit_codes() {
+        let code = |error: UcmError| error.exit_code();
+        assert_eq!(code(UcmError::parse_error("x", "bad")), 3);
+        assert_eq!(code(UcmError::invalid_timezone("Mars/Base")), 8);
+        assert_eq!(code(UcmError::unknown_resource("ucm://x")), 1);
+    }
+}
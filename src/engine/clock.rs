@@ -0,0 +1,79 @@
+use std::fmt::Debug;
+
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, TimeZone};
+
+use crate::types::UcmError;
+
+/// Where the engine reads the current instant from
+pub trait Clock: Debug + Send + Sync {
+    fn now(&self) -> DateTime<FixedOffset>;
+}
+
+/// The system clock in the system time zone
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<FixedOffset> {
+        Local::now().fixed_offset()
+    }
+}
+
+/// A clock pinned to one instant, for reproducible answers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedClock(pub DateTime<FixedOffset>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<FixedOffset> {
+        self.0
+    }
+}
+
+impl FixedClock {
+    /// Parse an RFC 3339 datetime, or a local datetime or date (midnight)
+    /// in the system time zone
+    pub fn parse(reference: &str) -> Result<Self, UcmError> {
+        let reference = reference.trim();
+        if let Ok(instant) = DateTime::parse_from_rfc3339(reference) {
+            return Ok(Self(instant));
+        }
+
+        let local = ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"]
+            .iter()
+            .find_map(|format| NaiveDateTime::parse_from_str(reference, format).ok())
+            .or_else(|| {
+                NaiveDate::parse_from_str(reference, "%Y-%m-%d")
+                    .ok()
+                    .and_then(|date| date.and_hms_opt(0, 0, 0))
+            })
+            .ok_or_else(|| {
+                UcmError::parse_error(
+                    reference,
+                    "Expected a reference time such as '2026-03-01', '2026-03-01T09:00:00' or '2026-03-01T09:00:00+09:00'",
+                )
+            })?;
+
+        Local
+            .from_local_datetime(&local)
+            .earliest()
+            .map(|instant| Self(instant.fixed_offset()))
+            .ok_or_else(|| UcmError::out_of_range(reference, "That local time does not exist in the system time zone"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ErrorKind;
+
+    #[test]
+    fn test_fixed_clock_parse() {
+        let clock = FixedClock::parse("2026-03-01T09:30:00+09:00").unwrap();
+        assert_eq!(clock.now().to_rfc3339(), "2026-03-01T09:30:00+09:00");
+
+        let midnight = FixedClock::parse("2026-03-01").unwrap().now();
+        assert_eq!(midnight.naive_local(), NaiveDate::from_ymd_opt(2026, 3, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+
+        assert_eq!(FixedClock::parse("last tuesday").unwrap_err().error, ErrorKind::ParseError);
+    }
+}
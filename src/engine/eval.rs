@@ -0,0 +1,511 @@
+use std::cmp::Ordering;
+
+use chrono::{Datelike, Duration, Months, NaiveDate, NaiveDateTime, Weekday};
+
+use super::Ucm;
+use crate::calendar::period::PeriodUnit;
+use crate::parser::expr::{parse_expression, BinaryOp, Expr, ExprKind, Unit};
+use crate::types::UcmError;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+const DAYS_PER_MONTH: f64 = 30.44;
+
+/// The result of evaluating a date expression
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Date(NaiveDate),
+    DateTime(NaiveDateTime),
+    Duration(Interval),
+    Number(f64),
+}
+
+impl Value {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Date(_) => "date",
+            Self::DateTime(_) => "datetime",
+            Self::Duration(_) => "duration",
+            Self::Number(_) => "number",
+        }
+    }
+}
+
+/// A calendar-aware length of time. Months keep their calendar meaning
+/// (2026-01-31 + 1 month is 2026-02-28) and business days skip weekends.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Interval {
+    pub months: i64,
+    pub business_days: i64,
+    pub days: i64,
+    pub seconds: i64,
+}
+
+impl Interval {
+    fn negate(self) -> Option<Self> {
+        Some(Self {
+            months: self.months.checked_neg()?,
+            business_days: self.business_days.checked_neg()?,
+            days: self.days.checked_neg()?,
+            seconds: self.seconds.checked_neg()?,
+        })
+    }
+
+    fn plus(self, other: Self) -> Option<Self> {
+        Some(Self {
+            months: self.months.checked_add(other.months)?,
+            business_days: self.business_days.checked_add(other.business_days)?,
+            days: self.days.checked_add(other.days)?,
+            seconds: self.seconds.checked_add(other.seconds)?,
+        })
+    }
+
+    /// Length in days, counting a month as 30.44 days and a business day as 1.4
+    pub fn approximate_days(&self) -> f64 {
+        self.months as f64 * DAYS_PER_MONTH
+            + self.business_days as f64 * 7.0 / 5.0
+            + self.days as f64
+            + self.seconds as f64 / SECONDS_PER_DAY as f64
+    }
+
+    fn from_seconds(total: i64) -> Self {
+        Self { days: total / SECONDS_PER_DAY, seconds: total % SECONDS_PER_DAY, ..Self::default() }
+    }
+
+    /// Human readable form, e.g. "1 month 3 days" or "-2 business days"
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        let mut push = |value: i64, unit: &str| {
+            if value != 0 {
+                let plural = if value.abs() == 1 { "" } else { "s" };
+                parts.push(format!("{} {}{}", value, unit, plural));
+            }
+        };
+        push(self.months, "month");
+        push(self.business_days, "business day");
+        push(self.days, "day");
+        let (hours, rest) = (self.seconds / 3600, self.seconds % 3600);
+        push(hours, "hour");
+        push(rest / 60, "minute");
+        push(rest % 60, "second");
+        if parts.is_empty() {
+            "0 days".to_string()
+        } else {
+            parts.join(" ")
+        }
+    }
+}
+
+impl Ucm {
+    /// Evaluate a date expression such as `next friday + 2 business days`
+    /// or `(2026-12-25 - today) in weeks`; see [`crate::parser::expr`] for
+    /// the grammar. Errors carry the span of the offending part.
+    pub fn eval(&self, expression: &str) -> Result<Value, UcmError> {
+        let expr = parse_expression(expression)?;
+        Evaluator { ucm: self, expression }.eval(&expr)
+    }
+}
+
+struct Evaluator<'a> {
+    ucm: &'a Ucm,
+    expression: &'a str,
+}
+
+impl Evaluator<'_> {
+    fn error(&self, expr: &Expr, message: &str) -> UcmError {
+        UcmError::parse_error(self.expression, message).at(expr.start, expr.end)
+    }
+
+    fn range_error(&self, expr: &Expr) -> UcmError {
+        UcmError::out_of_range(self.expression, "Result is outside the supported date range").at(expr.start, expr.end)
+    }
+
+    fn eval(&self, expr: &Expr) -> Result<Value, UcmError> {
+        match &expr.kind {
+            ExprKind::Number(n) => Ok(Value::Number(*n)),
+            ExprKind::Quantity { value, unit, ago } => {
+                let interval = self.quantity(expr, *value, *unit)?;
+                if *ago {
+                    let now = Value::DateTime(self.ucm.now().naive_local());
+                    let interval = interval.negate().ok_or_else(|| self.range_error(expr))?;
+                    return self.shift(expr, now, interval);
+                }
+                Ok(Value::Duration(interval))
+            }
+            ExprKind::IsoDate(text) => self.iso_date(expr, text),
+            ExprKind::Phrase(phrase) => self.phrase(expr, phrase),
+            ExprKind::Negate(operand) => match self.eval(operand)? {
+                Value::Number(n) => Ok(Value::Number(-n)),
+                Value::Duration(d) => d.negate().map(Value::Duration).ok_or_else(|| self.range_error(expr)),
+                other => Err(self.error(operand, &format!("Cannot negate a {}", other.kind()))),
+            },
+            ExprKind::Binary(left, op, right) => {
+                let (a, b) = (self.eval(left)?, self.eval(right)?);
+                self.binary(expr, a, *op, b)
+            }
+            ExprKind::Call(name, args) => self.call(expr, name, args),
+            ExprKind::Convert(inner, unit) => match self.eval(inner)? {
+                Value::Duration(interval) => self.convert(expr, interval, *unit).map(Value::Number),
+                other => Err(self.error(
+                    inner,
+                    &format!("Only durations can be converted with 'in', not a {}", other.kind()),
+                )),
+            },
+        }
+    }
+
+    fn quantity(&self, expr: &Expr, value: f64, unit: Unit) -> Result<Interval, UcmError> {
+        let whole = |count: f64| {
+            (count.fract() == 0.0).then_some(count as i64).ok_or_else(|| {
+                self.error(expr, &format!("{} must be a whole number; use days for fractions", unit.name()))
+            })
+        };
+        let seconds = |per: f64| Ok(Interval::from_seconds((value * per).round() as i64));
+
+        match unit {
+            Unit::Seconds => seconds(1.0),
+            Unit::Minutes => seconds(60.0),
+            Unit::Hours => seconds(3600.0),
+            Unit::Days => seconds(SECONDS_PER_DAY as f64),
+            Unit::Weeks => seconds(7.0 * SECONDS_PER_DAY as f64),
+            Unit::Months => Ok(Interval { months: whole(value)?, ..Interval::default() }),
+            Unit::Quarters => Ok(Interval { months: whole(value * 3.0)?, ..Interval::default() }),
+            Unit::Years => Ok(Interval { months: whole(value * 12.0)?, ..Interval::default() }),
+            Unit::BusinessDays => Ok(Interval { business_days: whole(value)?, ..Interval::default() }),
+        }
+    }
+
+    fn iso_date(&self, expr: &Expr, text: &str) -> Result<Value, UcmError> {
+        if text.contains('T') {
+            return ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M"]
+                .iter()
+                .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+                .map(Value::DateTime)
+                .ok_or_else(|| self.error(expr, &format!("'{}' is not a valid date and time", text)));
+        }
+        self.phrase(expr, text)
+    }
+
+    fn phrase(&self, expr: &Expr, phrase: &str) -> Result<Value, UcmError> {
+        if phrase.eq_ignore_ascii_case("now") {
+            return Ok(Value::DateTime(self.ucm.now().naive_local()));
+        }
+        self.ucm.parse_date(phrase).map(Value::Date).map_err(|e| {
+            UcmError::parse_error(self.expression, &format!("Could not read '{}' as a date: {}", phrase, e.message))
+                .at(expr.start, expr.end)
+        })
+    }
+
+    fn binary(&self, expr: &Expr, a: Value, op: BinaryOp, b: Value) -> Result<Value, UcmError> {
+        use Value::*;
+        match (a, op, b) {
+            (Number(x), BinaryOp::Add, Number(y)) => Ok(Number(x + y)),
+            (Number(x), BinaryOp::Subtract, Number(y)) => Ok(Number(x - y)),
+            (Number(x), BinaryOp::Multiply, Number(y)) => Ok(Number(x * y)),
+            (Number(x), BinaryOp::Divide, Number(y)) => {
+                if y == 0.0 {
+                    return Err(self.error(expr, "Division by zero"));
+                }
+                Ok(Number(x / y))
+            }
+            (Date(_) | DateTime(_), BinaryOp::Add, Duration(d)) => self.shift(expr, a, d),
+            (Duration(d), BinaryOp::Add, Date(_) | DateTime(_)) => self.shift(expr, b, d),
+            (Date(_) | DateTime(_), BinaryOp::Subtract, Duration(d)) => {
+                self.shift(expr, a, d.negate().ok_or_else(|| self.range_error(expr))?)
+            }
+            (Date(x), BinaryOp::Subtract, Date(y)) => {
+                Ok(Duration(Interval { days: (x - y).num_days(), ..Interval::default() }))
+            }
+            (Date(_) | DateTime(_), BinaryOp::Subtract, Date(_) | DateTime(_)) => {
+                let seconds = (as_datetime(a) - as_datetime(b)).num_seconds();
+                Ok(Duration(Interval::from_seconds(seconds)))
+            }
+            (Duration(x), BinaryOp::Add, Duration(y)) => x.plus(y).map(Duration).ok_or_else(|| self.range_error(expr)),
+            (Duration(x), BinaryOp::Subtract, Duration(y)) => {
+                y.negate().and_then(|y| x.plus(y)).map(Duration).ok_or_else(|| self.range_error(expr))
+            }
+            (Duration(d), BinaryOp::Multiply, Number(n)) | (Number(n), BinaryOp::Multiply, Duration(d)) => {
+                self.scale(expr, d, n).map(Duration)
+            }
+            (Duration(d), BinaryOp::Divide, Number(n)) => {
+                if n == 0.0 {
+                    return Err(self.error(expr, "Division by zero"));
+                }
+                self.scale(expr, d, 1.0 / n).map(Duration)
+            }
+            (Duration(x), BinaryOp::Divide, Duration(y)) => {
+                if y.approximate_days() == 0.0 {
+                    return Err(self.error(expr, "Division by a zero duration"));
+                }
+                Ok(Number(x.approximate_days() / y.approximate_days()))
+            }
+            _ => {
+                let verb = match op {
+                    BinaryOp::Add => "add",
+                    BinaryOp::Subtract => "subtract",
+                    BinaryOp::Multiply => "multiply",
+                    BinaryOp::Divide => "divide",
+                };
+                Err(self.error(expr, &format!("Cannot {} a {} and a {}", verb, a.kind(), b.kind())))
+            }
+        }
+    }
+
+    fn scale(&self, expr: &Expr, interval: Interval, factor: f64) -> Result<Interval, UcmError> {
+        // `as` saturates, so anything at or past i64::MAX is out of range
+        let fits = |value: f64| value.abs() < i64::MAX as f64;
+        let whole = |count: i64, unit: &str| {
+            let scaled = count as f64 * factor;
+            if scaled.fract() != 0.0 {
+                return Err(self.error(expr, &format!("Scaling would give a fractional number of {}", unit)));
+            }
+            fits(scaled).then_some(scaled as i64).ok_or_else(|| self.range_error(expr))
+        };
+        let seconds = interval
+            .days
+            .checked_mul(SECONDS_PER_DAY)
+            .and_then(|seconds| seconds.checked_add(interval.seconds))
+            .map(|seconds| (seconds as f64 * factor).round())
+            .filter(|seconds| fits(*seconds))
+            .ok_or_else(|| self.range_error(expr))? as i64;
+        Ok(Interval {
+            months: whole(interval.months, "months")?,
+            business_days: whole(interval.business_days, "business days")?,
+            ..Interval::from_seconds(seconds)
+        })
+    }
+
+    /// Move a date or datetime by an interval: months first, then business
+    /// days, then days and seconds. A date stays a date unless the interval
+    /// has a time part.
+    fn shift(&self, expr: &Expr, start: Value, interval: Interval) -> Result<Value, UcmError> {
+        let datetime = as_datetime(start);
+        let mut date = datetime.date();
+
+        let months = u32::try_from(interval.months.unsigned_abs()).map(Months::new).map_err(|_| self.range_error(expr))?;
+        date = if interval.months >= 0 { date.checked_add_months(months) } else { date.checked_sub_months(months) }
+            .ok_or_else(|| self.range_error(expr))?;
+        date = add_business_days(date, interval.business_days).ok_or_else(|| self.range_error(expr))?;
+
+        let shifted = Duration::try_days(interval.days)
+            .zip(Duration::try_seconds(interval.seconds))
+            .and_then(|(days, seconds)| days.checked_add(&seconds))
+            .and_then(|delta| date.and_time(datetime.time()).checked_add_signed(delta))
+            .ok_or_else(|| self.range_error(expr))?;
+
+        match start {
+            Value::Date(_) if interval.seconds == 0 => Ok(Value::Date(shifted.date())),
+            _ => Ok(Value::DateTime(shifted)),
+        }
+    }
+
+    fn convert(&self, expr: &Expr, interval: Interval, unit: Unit) -> Result<f64, UcmError> {
+        if interval == (Interval { business_days: interval.business_days, ..Interval::default() }) {
+            if unit == Unit::BusinessDays {
+                return Ok(interval.business_days as f64);
+            }
+        } else if unit == Unit::BusinessDays {
+            return Err(self.error(expr, "Only business days can be expressed in business days; use business_days(from, to)"));
+        }
+
+        let month_units = match unit {
+            Unit::Months => Some(1.0),
+            Unit::Quarters => Some(3.0),
+            Unit::Years => Some(12.0),
+            _ => None,
+        };
+        if let Some(per) = month_units {
+            if interval == (Interval { months: interval.months, ..Interval::default() }) {
+                return Ok(interval.months as f64 / per);
+            }
+        }
+
+        let days = interval.approximate_days();
+        Ok(match unit {
+            Unit::Seconds => days * SECONDS_PER_DAY as f64,
+            Unit::Minutes => days * 1440.0,
+            Unit::Hours => days * 24.0,
+            Unit::Days => days,
+            Unit::Weeks => days / 7.0,
+            Unit::Months => days / DAYS_PER_MONTH,
+            Unit::Quarters => days / (DAYS_PER_MONTH * 3.0),
+            Unit::Years => days / 365.25,
+            Unit::BusinessDays => days * 5.0 / 7.0,
+        })
+    }
+
+    fn period(&self, expr: &Expr, unit: PeriodUnit, date: NaiveDate) -> Result<(NaiveDate, NaiveDate), UcmError> {
+        self.ucm.period(unit, date, 0).map_err(|e| e.at(expr.start, expr.end))
+    }
+
+    fn call(&self, expr: &Expr, name: &str, args: &[Expr]) -> Result<Value, UcmError> {
+        let values = args.iter().map(|arg| self.eval(arg)).collect::<Result<Vec<_>, _>>()?;
+        let arity = |count: usize| {
+            if values.len() == count {
+                Ok(())
+            } else {
+                Err(self.error(expr, &format!("{}() takes {} argument{}", name, count, if count == 1 { "" } else { "s" })))
+            }
+        };
+        let date_arg = |index: usize| match values[index] {
+            Value::Date(date) => Ok(date),
+            Value::DateTime(datetime) => Ok(datetime.date()),
+            other => Err(self.error(&args[index], &format!("{}() expects a date, not a {}", name, other.kind()))),
+        };
+
+        match name {
+            "days_in_month" => {
+                arity(1)?;
+                let (start, end) = self.period(expr, PeriodUnit::Month, date_arg(0)?)?;
+                Ok(Value::Number(((end - start).num_days() + 1) as f64))
+            }
+            "business_days" => {
+                arity(2)?;
+                Ok(Value::Number(business_days_between(date_arg(0)?, date_arg(1)?) as f64))
+            }
+            "min" | "max" => {
+                let Some(first) = values.first() else {
+                    return Err(self.error(expr, &format!("{}() needs at least one argument", name)));
+                };
+                let mut best = *first;
+                for (value, arg) in values.iter().zip(args).skip(1) {
+                    let ordering = compare(best, *value)
+                        .ok_or_else(|| self.error(arg, &format!("Cannot compare a {} with a {}", best.kind(), value.kind())))?;
+                    if (name == "max") == (ordering == Ordering::Less) {
+                        best = *value;
+                    }
+                }
+                Ok(best)
+            }
+            _ => {
+                let anchored = name
+                    .strip_prefix("start_of_")
+                    .map(|unit| (false, unit))
+                    .or_else(|| name.strip_prefix("end_of_").map(|unit| (true, unit)))
+                    .and_then(|(at_end, unit)| Some((at_end, PeriodUnit::from_name(unit, Weekday::Mon).ok()?)));
+                let Some((at_end, unit)) = anchored else {
+                    return Err(self.error(
+                        expr,
+                        &format!(
+                            "Unknown function '{}'. Functions: start_of_<unit> and end_of_<unit> (day, week, iso_week, us_week, month, quarter, half, year, fiscal_year), min, max, days_in_month, business_days",
+                            name
+                        ),
+                    ));
+                };
+                arity(1)?;
+                let (start, end) = self.period(expr, unit, date_arg(0)?)?;
+                Ok(Value::Date(if at_end { end } else { start }))
+            }
+        }
+    }
+}
+
+fn as_datetime(value: Value) -> NaiveDateTime {
+    match value {
+        Value::Date(date) => date.and_hms_opt(0, 0, 0).unwrap(),
+        Value::DateTime(datetime) => datetime,
+        _ => unreachable!("only dates are shifted"),
+    }
+}
+
+fn compare(a: Value, b: Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => x.partial_cmp(&y),
+        (Value::Duration(x), Value::Duration(y)) => x.approximate_days().partial_cmp(&y.approximate_days()),
+        (Value::Date(_) | Value::DateTime(_), Value::Date(_) | Value::DateTime(_)) => {
+            Some(as_datetime(a).cmp(&as_datetime(b)))
+        }
+        _ => None,
+    }
+}
+
+fn is_business_day(date: NaiveDate) -> bool {
+    !matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// Step over `count` Monday-Friday days; a weekend start moves to the
+/// adjacent business day first
+fn add_business_days(mut date: NaiveDate, count: i64) -> Option<NaiveDate> {
+    let step = if count < 0 { -1 } else { 1 };
+    for _ in 0..count.abs() {
+        date = date.checked_add_signed(Duration::days(step))?;
+        while !is_business_day(date) {
+            date = date.checked_add_signed(Duration::days(step))?;
+        }
+    }
+    Some(date)
+}
+
+/// Monday-Friday days from `from` (inclusive) to `to` (exclusive), negative
+/// when `to` is earlier
+fn business_days_between(from: NaiveDate, to: NaiveDate) -> i64 {
+    let (start, end, sign) = if from <= to { (from, to, 1) } else { (to, from, -1) };
+    let days = (end - start).num_days();
+    let weeks = days / 7;
+    let count = weeks * 5
+        + (0..days % 7)
+            .filter(|i| is_business_day(start + Duration::days(weeks * 7 + i)))
+            .count() as i64;
+    count * sign
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ErrorKind;
+
+    fn ucm() -> Ucm {
+        // Sunday 2026-10-18
+        Ucm::new().at_reference(Some("2026-10-18T09:00:00+00:00")).unwrap().with_timezone(chrono_tz::UTC)
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> Value {
+        Value::Date(NaiveDate::from_ymd_opt(y, m, d).unwrap())
+    }
+
+    #[test]
+    fn test_eval_dates() {
+        let ucm = ucm();
+        assert_eq!(ucm.eval("2026-10-23 + 2 business days").unwrap(), date(2026, 10, 27));
+        assert_eq!(ucm.eval("end_of_month(today) - 1 week").unwrap(), date(2026, 10, 24));
+        assert_eq!(ucm.eval("max(2026-10-01, today + 30d)").unwrap(), date(2026, 11, 17));
+        assert_eq!(ucm.eval("2026-01-31 + 1 month").unwrap(), date(2026, 2, 28));
+        assert_eq!(ucm.eval("start_of_quarter(2026-W43-4)").unwrap(), date(2026, 10, 1));
+        assert_eq!(ucm.eval("tomorrow - 3 * 2d").unwrap(), date(2026, 10, 13));
+
+        let later = ucm.eval("now + 90 min").unwrap();
+        assert_eq!(later.kind(), "datetime");
+    }
+
+    #[test]
+    fn test_eval_durations_and_numbers() {
+        let ucm = ucm();
+        let Value::Number(weeks) = ucm.eval("(2026-12-25 - today) in weeks").unwrap() else { panic!() };
+        assert!((weeks - 9.714).abs() < 0.001);
+        assert_eq!(ucm.eval("(1 year + 6 months) in years").unwrap(), Value::Number(1.5));
+        assert_eq!(ucm.eval("business_days(2026-10-19, 2026-10-31)").unwrap(), Value::Number(10.0));
+        assert_eq!(ucm.eval("days_in_month(2028-02-10) / 2").unwrap(), Value::Number(14.5));
+
+        let Value::Duration(interval) = ucm.eval("2026-12-25 - 2026-10-18").unwrap() else { panic!() };
+        assert_eq!(interval.describe(), "68 days");
+    }
+
+    #[test]
+    fn test_eval_error_spans() {
+        let ucm = ucm();
+        let span = |expression: &str| ucm.eval(expression).unwrap_err().span.map(|s| (s.start, s.end));
+        assert_eq!(span("today + flurbsday"), Some((8, 17)));
+        assert_eq!(span("today + today"), Some((0, 13)));
+        assert_eq!(span("end_of_month(3d)"), Some((13, 15)));
+        assert_eq!(span("1.5 months + today"), Some((0, 10)));
+    }
+
+    #[test]
+    fn test_eval_huge_intervals() {
+        let ucm = ucm();
+        let kind = |expression: &str| ucm.eval(expression).unwrap_err().error;
+        assert_eq!(kind("today + 200000000000 days"), ErrorKind::OutOfRange);
+        assert_eq!(kind("today + 5000000000 months"), ErrorKind::OutOfRange);
+        assert_eq!(kind("100000000000 days * 100000000000"), ErrorKind::OutOfRange);
+        assert_eq!(kind("9223372036854775807 months + 9223372036854775807 months"), ErrorKind::OutOfRange);
+    }
+}
@@ -0,0 +1,342 @@
+pub mod clock;
+pub mod eval;
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone,
+    Weekday,
+};
+use chrono_tz::Tz;
+
+pub use clock::{Clock, FixedClock, SystemClock};
+pub use eval::Value;
+
+use crate::astro::moon::illumination;
+use crate::calendar::fiscal::{FiscalCalendar, FiscalDate};
+use crate::calendar::japanese::{to_era_date, EraDate};
+use crate::calendar::period::PeriodUnit;
+use crate::parser::natural::split_time_of_day;
+use crate::parser::{parse_duration, parse_to_date_in, parse_to_datetime_in};
+use crate::store::{EventStore, MarkStore, ReminderStore};
+use crate::types::{DurationBreakdown, UcmError};
+
+/// The date engine behind the MCP tools, for embedding in Rust programs.
+///
+/// A `Ucm` holds the clock "now" is read from and the conventions dates are
+/// interpreted with: the time zone "today" is taken in and the fiscal
+/// calendar used for "FY27 Q2" style expressions, and optionally the data
+/// directory saved `@name` dates and events are kept in. Methods return
+/// chrono types and [`UcmError`]s.
+#[derive(Debug, Clone)]
+pub struct Ucm {
+    clock: Arc<dyn Clock>,
+    timezone: Option<Tz>,
+    fiscal: FiscalCalendar,
+    data_dir: Option<PathBuf>,
+}
+
+/// The difference between two dates
+#[derive(Debug, Clone)]
+pub struct DateDiff {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    /// Signed number of days from `from` to `to`
+    pub days: i64,
+    pub breakdown: DurationBreakdown,
+}
+
+/// Calendar facts about a date
+#[derive(Debug, Clone)]
+pub struct DateInfo {
+    pub date: NaiveDate,
+    pub quarter: u32,
+    pub days_in_month: u32,
+    pub is_weekend: bool,
+    /// Signed number of days from today
+    pub days_from_today: i64,
+    pub fiscal: FiscalDate,
+    /// Japanese era, for dates from Meiji (1868-10-23) on
+    pub era: Option<EraDate<'static>>,
+    /// Illuminated fraction of the Moon at noon UTC, 0-1
+    pub moon_illumination: f64,
+    pub moon_waxing: bool,
+}
+
+impl Default for Ucm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Ucm {
+    /// The system clock and time zone, and the `UCM_FISCAL_CALENDAR` fiscal profile
+    pub fn new() -> Self {
+        Self {
+            clock: Arc::new(SystemClock),
+            timezone: None,
+            fiscal: FiscalCalendar::default_profile(),
+            data_dir: None,
+        }
+    }
+
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Pin the clock to a reference time (see [`FixedClock::parse`]), or
+    /// keep the current clock when none is given
+    pub fn at_reference(self, reference: Option<&str>) -> Result<Self, UcmError> {
+        match reference.map(str::trim).filter(|r| !r.is_empty()) {
+            Some(reference) => Ok(self.with_clock(FixedClock::parse(reference)?)),
+            None => Ok(self),
+        }
+    }
+
+    pub fn with_timezone(mut self, timezone: Tz) -> Self {
+        self.timezone = Some(timezone);
+        self
+    }
+
+    pub fn with_fiscal_calendar(mut self, fiscal: FiscalCalendar) -> Self {
+        self.fiscal = fiscal;
+        self
+    }
+
+    /// Keep saved dates and events in `dir`
+    pub fn with_data_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.data_dir = Some(dir.into());
+        self
+    }
+
+    pub fn data_dir(&self) -> Result<&Path, UcmError> {
+        self.data_dir.as_deref().ok_or_else(|| {
+            UcmError::storage_error("", "No data directory is configured; pass --data-dir or set UCM_DATA_DIR")
+        })
+    }
+
+    /// A file a client names, which must be inside the data directory;
+    /// relative paths are taken from there
+    pub fn data_file(&self, path: &str) -> Result<PathBuf, UcmError> {
+        let outside = || UcmError::storage_error(path, "Not a readable file in the data directory");
+        let dir = self.data_dir()?.canonicalize().map_err(|_| outside())?;
+        let file = dir.join(path).canonicalize().map_err(|_| outside())?;
+        file.starts_with(&dir).then_some(file).ok_or_else(outside)
+    }
+
+    /// Saved `@name` dates
+    pub fn marks(&self) -> Result<MarkStore, UcmError> {
+        Ok(MarkStore::in_dir(self.data_dir()?))
+    }
+
+    pub fn events(&self) -> Result<EventStore, UcmError> {
+        Ok(EventStore::in_dir(self.data_dir()?))
+    }
+
+    pub fn reminders(&self) -> Result<ReminderStore, UcmError> {
+        Ok(ReminderStore::in_dir(self.data_dir()?))
+    }
+
+    /// The configured zone; `None` means the system zone
+    pub fn timezone(&self) -> Option<Tz> {
+        self.timezone
+    }
+
+    /// Time zone name, or "local" for the system zone
+    pub fn timezone_name(&self) -> String {
+        self.timezone.map(|tz| tz.name().to_string()).unwrap_or_else(|| "local".to_string())
+    }
+
+    pub fn fiscal_calendar(&self) -> &FiscalCalendar {
+        &self.fiscal
+    }
+
+    /// The clock's current instant in the engine's time zone
+    pub fn now(&self) -> DateTime<FixedOffset> {
+        let now = self.clock.now();
+        match self.timezone {
+            Some(tz) => now.with_timezone(&tz).fixed_offset(),
+            None => now,
+        }
+    }
+
+    /// An instant in the engine's time zone
+    pub fn in_zone<Z: TimeZone>(&self, instant: DateTime<Z>) -> DateTime<FixedOffset> {
+        match self.timezone {
+            Some(tz) => instant.with_timezone(&tz).fixed_offset(),
+            None => instant.with_timezone(&Local).fixed_offset(),
+        }
+    }
+
+    pub fn today(&self) -> NaiveDate {
+        self.now().date_naive()
+    }
+
+    /// A local date and time in the engine's time zone; the earlier instant
+    /// is used when clocks go back, and the gap is skipped when they go forward
+    pub fn localize(&self, datetime: NaiveDateTime) -> DateTime<FixedOffset> {
+        self.localize_in(datetime, self.timezone)
+    }
+
+    /// Like [`Ucm::localize`], in `timezone` or else the engine's zone
+    pub fn localize_in(&self, datetime: NaiveDateTime, timezone: Option<Tz>) -> DateTime<FixedOffset> {
+        fn resolve<Z: TimeZone>(zone: &Z, datetime: NaiveDateTime) -> DateTime<FixedOffset> {
+            zone.from_local_datetime(&datetime)
+                .earliest()
+                .or_else(|| zone.from_local_datetime(&(datetime + Duration::hours(1))).earliest())
+                .map(|dt| dt.fixed_offset())
+                .unwrap_or_else(|| {
+                    let offset = zone.offset_from_utc_datetime(&datetime).fix();
+                    offset.from_utc_datetime(&datetime)
+                })
+        }
+        match timezone.or(self.timezone) {
+            Some(tz) => resolve(&tz, datetime),
+            None => resolve(&Local, datetime),
+        }
+    }
+
+    /// Start of a date in the engine's time zone
+    pub fn start_of_day(&self, date: NaiveDate) -> DateTime<FixedOffset> {
+        self.localize(date.and_hms_opt(0, 0, 0).unwrap())
+    }
+
+    /// Parse an ISO, ISO week, Japanese era, fiscal or natural language date,
+    /// or an `@name` saved date
+    pub fn parse_date(&self, expression: &str) -> Result<NaiveDate, UcmError> {
+        if expression.trim().starts_with('@') {
+            return Ok(self.marks()?.get(expression)?.date);
+        }
+        parse_to_date_in(expression, &self.fiscal, self.now().naive_local()).map_err(|e| parse_failure(expression, e))
+    }
+
+    /// Parse a date and time such as "2026-10-20T09:30" or "next tuesday at
+    /// 2pm", in the engine's time zone; a date alone means midnight
+    pub fn parse_datetime(&self, expression: &str) -> Result<NaiveDateTime, UcmError> {
+        let trimmed = expression.trim();
+        if trimmed.starts_with('@') {
+            return match split_time_of_day(trimmed) {
+                Some((mark, time)) if !mark.is_empty() => Ok(self.parse_date(mark)?.and_time(time)),
+                _ => Ok(self.parse_date(trimmed)?.and_time(NaiveTime::MIN)),
+            };
+        }
+        parse_to_datetime_in(trimmed, &self.fiscal, self.now().naive_local()).map_err(|e| parse_failure(expression, e))
+    }
+
+    /// Parse a duration such as "3 weeks" or "-5 days"
+    pub fn parse_duration(&self, expression: &str) -> Result<Duration, UcmError> {
+        parse_duration(expression).map_err(|e| UcmError::parse_error(expression, &e.to_string()))
+    }
+
+    pub fn add(&self, date: NaiveDate, duration: Duration) -> Result<NaiveDate, UcmError> {
+        date.checked_add_signed(duration).ok_or_else(|| {
+            UcmError::out_of_range(&date.to_string(), "Result is outside the supported date range")
+        })
+    }
+
+    /// First and last day of the period `offset` units from the one
+    /// containing `date` (0 for the containing period)
+    pub fn period(&self, unit: PeriodUnit, date: NaiveDate, offset: i32) -> Result<(NaiveDate, NaiveDate), UcmError> {
+        unit.relative_range(date, offset, &self.fiscal)
+            .map_err(|e| UcmError::out_of_range(&date.to_string(), &e.to_string()))
+    }
+
+    pub fn diff(&self, from: NaiveDate, to: NaiveDate) -> DateDiff {
+        DateDiff {
+            from,
+            to,
+            days: (to - from).num_days(),
+            breakdown: DurationBreakdown::between_dates(from, to),
+        }
+    }
+
+    pub fn info(&self, date: NaiveDate) -> Result<DateInfo, UcmError> {
+        let (moon_illumination, moon_waxing) = illumination(date.and_hms_opt(12, 0, 0).unwrap());
+
+        let fiscal = self.fiscal.locate(date).map_err(|e| {
+            UcmError::out_of_range(&date.format("%Y-%m-%d").to_string(), &e.to_string())
+        })?;
+
+        Ok(DateInfo {
+            date,
+            quarter: (date.month() - 1) / 3 + 1,
+            days_in_month: days_in_month(date),
+            is_weekend: matches!(date.weekday(), Weekday::Sat | Weekday::Sun),
+            days_from_today: (date - self.today()).num_days(),
+            fiscal,
+            era: to_era_date(date),
+            moon_illumination,
+            moon_waxing,
+        })
+    }
+}
+
+/// The parser's own error when it raised one, such as out_of_range for a
+/// date past the supported years, or else a parse error
+fn parse_failure(expression: &str, error: anyhow::Error) -> UcmError {
+    error.downcast().unwrap_or_else(|e| UcmError::parse_error(expression, &e.to_string()))
+}
+
+fn days_in_month(date: NaiveDate) -> u32 {
+    let first = date.with_day(1).unwrap();
+    first
+        .checked_add_months(chrono::Months::new(1))
+        .map(|next| (next - first).num_days() as u32)
+        .unwrap_or(31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ErrorKind;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_engine_typed_results() {
+        let ucm = Ucm::new().with_fiscal_calendar(FiscalCalendar::from_name("us_federal").unwrap());
+        let start = ucm.parse_date("FY27 Q2").unwrap();
+        assert_eq!(start, date(2027, 1, 1));
+
+        let due = ucm.add(start, ucm.parse_duration("3 weeks").unwrap()).unwrap();
+        assert_eq!(due, date(2027, 1, 22));
+        assert_eq!(ucm.diff(start, due).days, 21);
+
+        let info = ucm.info(due).unwrap();
+        assert!(!info.is_weekend);
+        assert_eq!(info.fiscal.fiscal_year, 2027);
+        assert_eq!(info.days_in_month, 31);
+
+        let error = ucm.parse_date("not a date").unwrap_err();
+        assert_eq!(error.error, ErrorKind::ParseError);
+        assert_eq!(ucm.parse_date("R2147483647").unwrap_err().error, ErrorKind::OutOfRange);
+
+        // The fiscal year of the last supported date ends past it
+        assert_eq!(ucm.info(NaiveDate::MAX).unwrap_err().error, ErrorKind::OutOfRange);
+    }
+
+    #[test]
+    fn test_engine_fixed_clock() {
+        let ucm = Ucm::new().at_reference(Some("2026-03-01T10:00:00+00:00")).unwrap();
+        assert_eq!(ucm.today(), date(2026, 3, 1));
+        assert_eq!(ucm.parse_date("tomorrow").unwrap(), date(2026, 3, 2));
+        assert_eq!(ucm.info(date(2026, 3, 31)).unwrap().days_from_today, 30);
+
+        let tokyo = ucm.with_timezone(chrono_tz::Asia::Tokyo);
+        assert_eq!(tokyo.now().to_rfc3339(), "2026-03-01T19:00:00+09:00");
+    }
+
+    #[test]
+    fn test_engine_timezone() {
+        let ucm = Ucm::new().with_timezone(chrono_tz::America::New_York);
+        assert_eq!(ucm.timezone_name(), "America/New_York");
+        // Clocks go forward at 02:00 on 2026-03-08
+        let gap = ucm.localize(date(2026, 3, 8).and_hms_opt(2, 30, 0).unwrap());
+        assert_eq!(gap.offset().local_minus_utc(), -4 * 3600);
+        assert_eq!(ucm.start_of_day(date(2026, 7, 4)).to_rfc3339(), "2026-07-04T00:00:00-04:00");
+    }
+}
@@ -0,0 +1,188 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::{header, HeaderValue, Method, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
+use rmcp::transport::{StreamableHttpServerConfig, StreamableHttpService};
+use tokio_util::sync::CancellationToken;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+use ucm::Ucm;
+use crate::UcmServer;
+
+/// Settings for the streamable HTTP transport
+#[derive(Debug, Clone)]
+pub struct HttpOptions {
+    pub bind: SocketAddr,
+    /// Path the MCP endpoint is mounted at, e.g. "/mcp"
+    pub path: String,
+    /// Required `Authorization: Bearer` token; no auth when unset
+    pub auth_token: Option<String>,
+    /// Allowed CORS origins; "*" allows any origin, empty disables CORS
+    pub cors_origins: Vec<String>,
+}
+
+/// Serve UCM over streamable HTTP (with SSE streaming) until Ctrl-C or SIGTERM
+pub async fn serve(options: HttpOptions, engine: Ucm) -> anyhow::Result<()> {
+    let shutdown = CancellationToken::new();
+
+    // Sessions are clones of one server, so they share reminder delivery
+    let server = UcmServer::new(engine);
+    let mcp = StreamableHttpService::new(
+        move || Ok(server.clone()),
+        Arc::new(LocalSessionManager::default()),
+        StreamableHttpServerConfig {
+            cancellation_token: shutdown.child_token(),
+            ..Default::default()
+        },
+    );
+
+    let mut mcp_routes = Router::new().nest_service(&options.path, mcp);
+    if let Some(token) = options.auth_token.clone() {
+        mcp_routes = mcp_routes.layer(middleware::from_fn_with_state(Arc::new(token), require_bearer));
+    }
+
+    let mut app = Router::new().route("/health", get(|| async { "ok" })).merge(mcp_routes);
+    if let Some(cors) = cors_layer(&options.cors_origins)? {
+        app = app.layer(cors);
+    }
+
+    let listener = tokio::net::TcpListener::bind(options.bind).await?;
+    eprintln!(
+        "UCM listening on http://{}{}{}",
+        listener.local_addr()?,
+        options.path,
+        if options.auth_token.is_some() { " (bearer auth)" } else { "" }
+    );
+
+    let signal = shutdown.clone();
+    tokio::spawn(async move {
+        shutdown_signal().await;
+        signal.cancel();
+    });
+
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown.cancelled_owned())
+        .await?;
+    Ok(())
+}
+
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        let _ = tokio::signal::ctrl_c().await;
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(_) => std::future::pending::<()>().await,
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
+
+/// Whether an Authorization header carries the expected bearer token
+fn is_authorized(header: Option<&str>, token: &str) -> bool {
+    let Some(presented) = header.and_then(|value| value.strip_prefix("Bearer ")) else {
+        return false;
+    };
+    // Compare without short-circuiting so timing does not leak the token
+    presented.len() == token.len()
+        && presented
+            .bytes()
+            .zip(token.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+async fn require_bearer(State(token): State<Arc<String>>, request: Request, next: Next) -> Response {
+    let header = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+
+    // CORS preflight requests never carry credentials
+    if request.method() == Method::OPTIONS || is_authorized(header, &token) {
+        return next.run(request).await;
+    }
+
+    (
+        StatusCode::UNAUTHORIZED,
+        [(header::WWW_AUTHENTICATE, "Bearer")],
+        "missing or invalid bearer token",
+    )
+        .into_response()
+}
+
+fn cors_layer(origins: &[String]) -> anyhow::Result<Option<CorsLayer>> {
+    if origins.is_empty() {
+        return Ok(None);
+    }
+
+    let allow_origin = if origins.iter().any(|origin| origin == "*") {
+        AllowOrigin::any()
+    } else {
+        let origins = origins
+            .iter()
+            .map(|origin| {
+                HeaderValue::from_str(origin)
+                    .map_err(|_| anyhow::anyhow!("Invalid CORS origin '{}'", origin))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        AllowOrigin::list(origins)
+    };
+
+    let mcp_headers = [
+        header::CONTENT_TYPE,
+        header::AUTHORIZATION,
+        header::ACCEPT,
+        header::HeaderName::from_static("last-event-id"),
+        header::HeaderName::from_static("mcp-session-id"),
+        header::HeaderName::from_static("mcp-protocol-version"),
+    ];
+
+    Ok(Some(
+        CorsLayer::new()
+            .allow_origin(allow_origin)
+            .allow_methods([Method::GET, Method::POST, Method::DELETE, Method::OPTIONS])
+            .allow_headers(mcp_headers)
+            .expose_headers([header::HeaderName::from_static("mcp-session-id")]),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_authorized() {
+        assert!(is_authorized(Some("Bearer s3cret"), "s3cret"));
+        assert!(!is_authorized(Some("Bearer s3cre"), "s3cret"));
+        assert!(!is_authorized(Some("Basic s3cret"), "s3cret"));
+        assert!(!is_authorized(None, "s3cret"));
+    }
+
+    #[test]
+    fn test_cors_layer() {
+        assert!(cors_layer(&[]).unwrap().is_none());
+        assert!(cors_layer(&["*".to_string()]).unwrap().is_some());
+        assert!(cors_layer(&["https://app.example.com".to_string()]).unwrap().is_some());
+        assert!(cors_layer(&["bad\norigin".to_string()]).is_err());
+    }
+}
@@ -1,6 +1,28 @@
+//! Universal Calendar Manager: date parsing and calendar arithmetic.
+//!
+//! The [`Ucm`] engine is the typed API; the `ucm_*` tool functions wrap it
+//! with the JSON responses the MCP server returns.
+//!
+//! ```no_run
+//! use ucm::Ucm;
+//!
+//! let ucm = Ucm::new().with_timezone(chrono_tz::Asia::Tokyo);
+//! let start = ucm.parse_date("FY27 Q2")?;
+//! let due = ucm.add(start, ucm.parse_duration("3 weeks")?)?;
+//! println!("{} days to go", ucm.diff(ucm.today(), due).days);
+//! # Ok::<(), ucm::UcmError>(())
+//! ```
+
+pub mod astro;
+pub mod calendar;
+pub mod engine;
 pub mod parser;
+pub mod prompts;
+pub mod resources;
+pub mod store;
 pub mod tools;
 pub mod types;
 
+pub use engine::{DateDiff, DateInfo, Ucm, Value};
 pub use tools::*;
 pub use types::*;
@@ -1,24 +1,66 @@
+use std::process::ExitCode;
+use std::sync::Arc;
+
 use rmcp::{
     handler::server::router::tool::ToolRouter,
+    handler::server::tool::schema_for_output,
     handler::server::wrapper::Parameters,
-    model::{ServerCapabilities, ServerInfo},
+    model::{
+        AnnotateAble, CallToolResult, Content, ErrorData as McpError, JsonObject, GetPromptRequestParams, GetPromptResult,
+        ListPromptsResult, ListResourceTemplatesResult, ListResourcesResult,
+        PaginatedRequestParams, Prompt, PromptArgument, PromptMessage, PromptMessageRole,
+        RawResource, RawResourceTemplate, ReadResourceRequestParams, ReadResourceResult,
+        ResourceContents, ServerCapabilities, ServerInfo, SetLevelRequestParams,
+    },
+    service::{NotificationContext, RequestContext},
     tool, tool_handler, tool_router,
     transport::stdio,
-    ServiceExt,
+    RoleServer, ServiceExt,
 };
-use serde::Deserialize;
+use clap::{Parser, ValueEnum};
+use serde::{Deserialize, Serialize};
+
+mod cli;
+mod http;
+mod notify;
 
-mod parser;
-mod tools;
-mod types;
+use ucm::tools::batch::BatchOperation;
+use ucm::tools::events::EventFields;
+use ucm::tools::free_slots::SlotQuery;
+use ucm::tools::{
+    ucm_add, ucm_age, ucm_astro_events, ucm_batch, ucm_convert, ucm_cron, ucm_diff, ucm_era, ucm_eval, ucm_event_create,
+    ucm_event_delete, ucm_event_export, ucm_event_list, ucm_event_update, ucm_free_slots, ucm_info, ucm_instructions,
+    ucm_mark_delete, ucm_mark_list, ucm_mark_set, ucm_now, ucm_nth_weekday, ucm_parse, ucm_period, ucm_remind,
+    ucm_reminders, ucm_retail, ucm_schedule_translate, ucm_status, ucm_sun, ucm_timestamp, ucm_week,
+};
+use ucm::types::{
+    AddResponse, AgeResponse, AstroEventsResponse, BatchResponse, ConvertResponse, CronResponse, DiffResponse,
+    EraResponse, ErrorKind, EvalResponse, EventDeleteResponse, EventExportResponse, EventListResponse, EventResponse,
+    FreeSlotsResponse, InfoResponse, InstructionsResponse, MarkDeleteResponse, MarkListResponse, MarkSetResponse,
+    NowResponse, NthWeekdayResponse, ParseResponse, PeriodResponse, RemindResponse, RemindersResponse, RetailResponse,
+    ScheduleTranslateResponse, StatusResponse, SunResponse, TimestampResponse, UcmError, WeekResponse,
+};
+use ucm::{prompts, resources, store, Ucm};
 
-use tools::{ucm_add, ucm_convert, ucm_diff, ucm_info, ucm_instructions, ucm_now, ucm_parse, ucm_status};
+/// Description of the `now` parameter that pins the reference time
+const NOW_DESCRIPTION: &str = "Reference 'now' for relative expressions like 'today' (e.g., '2026-03-01' or \
+    '2026-03-01T09:00:00+09:00'); defaults to the server clock";
 
 // Parameter types for tools
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct NowParams {
+    #[schemars(description = "Optional fiscal calendar: us_federal, uk, japan, australia, india, calendar, or a starting month (e.g., 'october')")]
+    pub fiscal_calendar: Option<String>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct ParseParams {
     #[schemars(description = "Natural language date expression")]
     pub expression: String,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -27,6 +69,8 @@ pub struct DiffParams {
     pub from: String,
     #[schemars(description = "End date (natural language or ISO format)")]
     pub to: String,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -35,6 +79,8 @@ pub struct AddParams {
     pub date: String,
     #[schemars(description = "Duration to add (e.g., '3 weeks', '2 months', '-5 days')")]
     pub add: String,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -47,79 +93,826 @@ pub struct ConvertParams {
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct InfoParams {
-    #[schemars(description = "Date to analyze (natural language or ISO format)")]
+    #[schemars(description = "Date to analyze (natural language, ISO format, or fiscal like 'FY27 Q2')")]
+    pub date: String,
+    #[schemars(description = "Optional fiscal calendar: us_federal, uk, japan, australia, india, calendar, or a starting month (e.g., 'october')")]
+    pub fiscal_calendar: Option<String>,
+    #[schemars(description = "Optional retail 52/53-week calendar: 'nrf' or a spec like '4-5-4 last saturday january'")]
+    pub retail_calendar: Option<String>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct EraParams {
+    #[schemars(description = "Gregorian or Japanese era date (e.g., '2026-10-18', '令和8年10月18日', 'R8.10.18')")]
+    pub date: String,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct RetailParams {
+    #[schemars(description = "Retail calendar: 'nrf' or '<4-4-5|4-5-4|5-4-4> [last|nearest] <weekday> <year-end month>' (e.g., '4-5-4 last saturday january')")]
+    pub calendar: String,
+    #[schemars(description = "Date to map to the retail calendar (natural language or ISO format)")]
+    pub date: Option<String>,
+    #[schemars(description = "Retail year to map back to dates (used when date is omitted)")]
+    pub year: Option<i32>,
+    #[schemars(description = "Retail period 1-12 within the year")]
+    pub period: Option<u32>,
+    #[schemars(description = "Week of the period if period is given, otherwise week of the year (1-53)")]
+    pub week: Option<u32>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct WeekParams {
+    #[schemars(description = "Date (natural language, ISO, or ISO week date like '2026-W43-4')")]
+    pub date: Option<String>,
+    #[schemars(description = "Week-numbering year, used with week when date is omitted")]
+    pub year: Option<i32>,
+    #[schemars(description = "Week number, used with year when date is omitted")]
+    pub week: Option<u32>,
+    #[schemars(description = "Week system for year/week input and alignment: iso (default), us, broadcast")]
+    pub system: Option<String>,
+    #[schemars(description = "Number of weeks to add (negative to subtract)")]
+    pub add_weeks: Option<i64>,
+    #[schemars(description = "Land on a day of the resulting week: weekday name, 'start' or 'end'")]
+    pub align: Option<String>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SunParams {
+    #[schemars(description = "Date (natural language or ISO format)")]
+    pub date: String,
+    #[schemars(description = "Latitude in degrees, north positive; give with longitude instead of a city")]
+    pub latitude: Option<f64>,
+    #[schemars(description = "Longitude in degrees, east positive")]
+    pub longitude: Option<f64>,
+    #[schemars(description = "Built-in city name (e.g., 'London', 'Tokyo', 'New York')")]
+    pub city: Option<String>,
+    #[schemars(description = "IANA time zone for the results (e.g., 'America/Chicago'); defaults to the city's zone or local time")]
+    pub timezone: Option<String>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct AstroEventsParams {
+    #[schemars(description = "Start date (natural language or ISO format)")]
+    pub from: String,
+    #[schemars(description = "End date; defaults to one year after the start")]
+    pub to: Option<String>,
+    #[schemars(description = "Event kinds: moon_phases, seasons, eclipses, all, or single events like full_moon, december_solstice, solar_eclipse. Defaults to moon_phases and seasons")]
+    pub kinds: Option<Vec<String>>,
+    #[schemars(description = "IANA time zone for event times (e.g., 'UTC', 'America/Chicago'); defaults to local time")]
+    pub timezone: Option<String>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct BatchParams {
+    #[schemars(description = "Operations to run in order, at most 100. Reference an earlier result in a string parameter with $N.field, e.g. {\"tool\": \"add\", \"params\": {\"date\": \"$1.result_date\", \"add\": \"3 weeks\"}}")]
+    pub operations: Vec<BatchOperation>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct PeriodParams {
+    #[schemars(description = "Date inside the period (natural language or ISO, default today), or without a unit a period phrase such as 'next quarter', 'end of this month' or 'Q3 2027'")]
+    pub date: Option<String>,
+    #[schemars(description = "Period unit: day, week, iso_week, us_week, month, quarter, half, year or fiscal_year")]
+    pub unit: Option<String>,
+    #[schemars(description = "Periods to move from the containing one: 1 for next, -1 for previous (default 0)")]
+    pub offset: Option<i32>,
+    #[schemars(description = "First day of a 'week' unit (default monday)")]
+    pub week_start: Option<String>,
+    #[schemars(description = "Fiscal calendar for fiscal_year: us_federal, uk, japan, australia, india, calendar, or a starting month")]
+    pub fiscal_calendar: Option<String>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct AgeParams {
+    #[schemars(description = "Birth date, or start date for tenure (natural language or ISO format)")]
     pub date: String,
+    #[schemars(description = "Date to measure the age on (default today)")]
+    pub as_of: Option<String>,
+    #[schemars(description = "Also return the date of this age or anniversary, e.g. 65")]
+    pub turns: Option<u32>,
+    #[schemars(description = "Where a February 29 birthday falls in common years: february_28 (default) or march_1")]
+    pub leap_day: Option<String>,
+    #[schemars(description = "Number of upcoming anniversaries to list (default 3, at most 100)")]
+    pub anniversaries: Option<u32>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct NthWeekdayParams {
+    #[schemars(description = "e.g. '3rd Thursday of November 2026', 'last Friday of each month in 2027', 'second-to-last business day of the quarter', 'first Monday after the 15th'")]
+    pub expression: String,
+    #[schemars(description = "Country whose public holidays are not business days: US, GB, CA or AU (default: weekends only)")]
+    pub country: Option<String>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct MarkSetParams {
+    #[schemars(description = "Name to save the date under, referenced later as '@name' (letters, digits, '_' or '-')")]
+    pub name: String,
+    #[schemars(description = "Date to save (natural language or ISO format)")]
+    pub date: String,
+    #[schemars(description = "Optional description, e.g. 'v2 public launch'")]
+    pub note: Option<String>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct MarkListParams {
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct MarkDeleteParams {
+    #[schemars(description = "Name of the saved date, with or without the '@'")]
+    pub name: String,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct EventCreateParams {
+    #[schemars(description = "Event title")]
+    pub title: String,
+    #[schemars(description = "Start, e.g. '2026-10-20T09:30', 'next tuesday at 2pm', or a date for all-day events")]
+    pub start: String,
+    #[schemars(description = "End time, or the last day of an all-day event")]
+    pub end: Option<String>,
+    #[schemars(description = "Length instead of end, e.g. '90 minutes' (default 1 hour, or 1 day for all-day events)")]
+    pub duration: Option<String>,
+    #[schemars(description = "Whether the event lasts whole days")]
+    pub all_day: Option<bool>,
+    #[schemars(description = "IANA time zone the start and end are in (default: the server's zone)")]
+    pub timezone: Option<String>,
+    #[schemars(description = "RRULE such as 'FREQ=WEEKLY;BYDAY=TU,TH' or 'FREQ=MONTHLY;BYDAY=-1FR', or daily, weekly, monthly, yearly, weekdays, 'every 2 weeks'")]
+    pub recurrence: Option<String>,
+    #[schemars(description = "Tags, e.g. ['work']")]
+    pub tags: Option<Vec<String>>,
+    #[schemars(description = "Optional description")]
+    pub note: Option<String>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct EventUpdateParams {
+    #[schemars(description = "Event id, e.g. 'evt-3'")]
+    pub id: String,
+    #[schemars(description = "Event title")]
+    pub title: Option<String>,
+    #[schemars(description = "Start, e.g. '2026-10-20T09:30', 'next tuesday at 2pm', or a date for all-day events")]
+    pub start: Option<String>,
+    #[schemars(description = "End time, or the last day of an all-day event")]
+    pub end: Option<String>,
+    #[schemars(description = "Length instead of end, e.g. '90 minutes' (default 1 hour, or 1 day for all-day events)")]
+    pub duration: Option<String>,
+    #[schemars(description = "Whether the event lasts whole days")]
+    pub all_day: Option<bool>,
+    #[schemars(description = "IANA time zone the start and end are in (default: the server's zone)")]
+    pub timezone: Option<String>,
+    #[schemars(description = "RRULE such as 'FREQ=WEEKLY;BYDAY=TU,TH' or 'FREQ=MONTHLY;BYDAY=-1FR', or daily, weekly, monthly, yearly, weekdays, 'every 2 weeks'; 'none' removes it")]
+    pub recurrence: Option<String>,
+    #[schemars(description = "Tags, e.g. ['work']")]
+    pub tags: Option<Vec<String>>,
+    #[schemars(description = "Optional description")]
+    pub note: Option<String>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct EventDeleteParams {
+    #[schemars(description = "Event id, e.g. 'evt-3'")]
+    pub id: String,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct EventListParams {
+    #[schemars(description = "Day or period to list, e.g. 'next tuesday', 'this week', 'November 2026'")]
+    pub when: Option<String>,
+    #[schemars(description = "First day, when 'when' is not given (default today)")]
+    pub from: Option<String>,
+    #[schemars(description = "Last day (default six days after 'from')")]
+    pub to: Option<String>,
+    #[schemars(description = "Only events with this tag")]
+    pub tag: Option<String>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct EventExportParams {
+    #[schemars(description = "Only events with this tag")]
+    pub tag: Option<String>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct FreeSlotsParams {
+    #[schemars(description = "Shortest slot wanted, e.g. '90 minutes'")]
+    pub duration: String,
+    #[schemars(description = "Day or period to search, e.g. 'this week', 'next tuesday'")]
+    pub when: Option<String>,
+    #[schemars(description = "First day, when 'when' is not given (default today)")]
+    pub from: Option<String>,
+    #[schemars(description = "Last day (default six days after 'from')")]
+    pub to: Option<String>,
+    #[schemars(description = "Busy intervals as 'start/end', e.g. ['2026-10-20 09:00/10:30']")]
+    pub busy: Option<Vec<String>>,
+    #[schemars(description = ".ics file in the data directory whose events are busy time; relative paths are taken from there")]
+    pub ics_file: Option<String>,
+    #[schemars(description = "Treat stored events as busy (default: yes, when a data directory is configured)")]
+    pub include_events: Option<bool>,
+    #[schemars(description = "Working hours, e.g. '09:00-17:00' (default)")]
+    pub working_hours: Option<String>,
+    #[schemars(description = "Working days, e.g. 'mon-fri' (default) or 'sun-thu'")]
+    pub working_days: Option<String>,
+    #[schemars(description = "IANA time zone of the working hours and busy times (default: the server's zone)")]
+    pub timezone: Option<String>,
+    #[schemars(description = "Lunch break to keep free, e.g. '12:00-13:00'")]
+    pub lunch: Option<String>,
+    #[schemars(description = "Gap to leave before each busy interval, e.g. '10 minutes'")]
+    pub buffer_before: Option<String>,
+    #[schemars(description = "Gap to leave after each busy interval, e.g. '15 minutes'")]
+    pub buffer_after: Option<String>,
+    #[schemars(description = "Country whose public holidays are days off: US, GB, CA or AU")]
+    pub country: Option<String>,
+    #[schemars(description = "Most slots to return (default 20)")]
+    pub limit: Option<usize>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct RemindParams {
+    #[schemars(description = "When to remind: a date and time ('tomorrow at 9am', '2026-10-20T09:00:00+02:00') or 'in 20 minutes'")]
+    pub when: String,
+    #[schemars(description = "What to remind about")]
+    pub message: String,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct RemindersParams {
+    #[schemars(description = "Id of a pending reminder to cancel, e.g. 'rem-3'")]
+    pub cancel: Option<String>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct CronParams {
+    #[schemars(description = "Cron expression: 5 fields (minute hour day-of-month month day-of-week), 6 with seconds first, Quartz with '?' and an optional year, or a macro like '@daily'; L, W and # are supported")]
+    pub expression: String,
+    #[schemars(description = "Number of runs to list (default 5, at most 100)")]
+    pub count: Option<usize>,
+    #[schemars(description = "IANA time zone the schedule runs in (default: the server's zone)")]
+    pub timezone: Option<String>,
+    #[schemars(description = "List runs after this time, or before it with 'previous' (default now)")]
+    pub from: Option<String>,
+    #[schemars(description = "List the most recent runs before 'from' instead of the next ones")]
+    pub previous: Option<bool>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ScheduleTranslateParams {
+    #[schemars(description = "Schedule to translate: a cron expression ('0 9 * * MON-FRI'), an RRULE with optional DTSTART line ('DTSTART;TZID=Europe/London:20261020T090000 RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TU'), or English ('every 2nd Tuesday at 09:00 Europe/London')")]
+    pub schedule: String,
+    #[schemars(description = "Notation of the schedule: 'cron', 'rrule' or 'english' (default: detected)")]
+    pub source: Option<String>,
+    #[schemars(description = "Notation to translate to: 'cron', 'rrule' or 'english' (default: both others)")]
+    pub target: Option<String>,
+    #[schemars(description = "IANA time zone of the schedule when it does not name one (default: the server's zone)")]
+    pub timezone: Option<String>,
+    #[schemars(description = "When a schedule without a start begins (default now)")]
+    pub start: Option<String>,
+    #[schemars(description = "Number of occurrences to list for each notation (default 5, at most 50)")]
+    pub samples: Option<usize>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct TimestampParams {
+    #[schemars(description = "A raw timestamp such as '1792402200', '1792402200250', '134368758002500000' or '46314.5' (decimals, '_' separators and 0x hex allowed), or a date and time to convert to every unit")]
+    pub value: String,
+    #[schemars(description = "Unit of a numeric value: unix_s, unix_ms, unix_us, unix_ns, filetime, dotnet_ticks, excel, mac_hfs, ntp or gps (default: detected from its size)")]
+    pub unit: Option<String>,
+    #[schemars(description = "IANA time zone to show the time in, and of Excel serials (default: the server's zone)")]
+    pub timezone: Option<String>,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct EvalParams {
+    #[schemars(description = "Date expression, e.g. 'next friday + 2 business days', 'end_of_month(today) - 1 week', 'max(2026-10-01, today + 30d)' or '(2026-12-25 - today) in weeks'")]
+    pub expression: String,
+    #[schemars(description = NOW_DESCRIPTION)]
+    pub now: Option<String>,
+}
+
+/// JSON Schema for a tool's structured output, which MCP requires to be an object
+fn try_output_schema<T: schemars::JsonSchema + 'static>() -> Result<Arc<JsonObject>, String> {
+    schema_for_output::<T>().map_err(|e| format!("{}: {}", std::any::type_name::<T>(), e))
+}
+
+/// Output schema for a `#[tool]` attribute; a type that is not an object
+/// gets a bare object schema and a warning rather than stopping the server
+fn output_schema<T: schemars::JsonSchema + 'static>() -> Arc<JsonObject> {
+    try_output_schema::<T>().unwrap_or_else(|e| {
+        eprintln!("warning: no output schema for {}", e);
+        let mut schema = JsonObject::new();
+        schema.insert("type".to_string(), serde_json::Value::String("object".to_string()));
+        Arc::new(schema)
+    })
+}
+
+/// Successful tool result: structured content, plus the same JSON as text
+/// for clients that do not read structuredContent
+fn structured<T: Serialize>(response: &T) -> CallToolResult {
+    let mut result = CallToolResult::structured(serde_json::to_value(response).unwrap());
+    result.content = vec![Content::text(serde_json::to_string_pretty(response).unwrap())];
+    result
+}
+
+/// Tool result for a fallible tool; a `UcmError` is returned as text with
+/// isError set, since it does not match the output schema
+fn tool_result<T: Serialize>(result: Result<T, UcmError>) -> CallToolResult {
+    match result {
+        Ok(response) => structured(&response),
+        Err(error) => CallToolResult::error(vec![Content::text(
+            serde_json::to_string_pretty(&error).unwrap(),
+        )]),
+    }
 }
 
 #[derive(Clone)]
 pub struct UcmServer {
     tool_router: ToolRouter<Self>,
+    /// Engine for every request; its clock may be pinned server-wide
+    engine: Ucm,
+    /// Shared by clones, so HTTP sessions have one delivery task
+    reminders: notify::ReminderDelivery,
 }
 
 #[tool_router]
 impl UcmServer {
-    pub fn new() -> Self {
+    pub fn new(engine: Ucm) -> Self {
         Self {
             tool_router: Self::tool_router(),
+            reminders: notify::ReminderDelivery::new(engine.clone()),
+            engine,
         }
     }
 
-    #[tool(description = "Get current date and time from system")]
-    fn ucm_now(&self) -> String {
-        let response = ucm_now();
-        serde_json::to_string_pretty(&response).unwrap()
+    /// The server engine, pinned to a per-call reference time if one is given
+    fn at(&self, now: Option<&str>) -> Result<Ucm, UcmError> {
+        self.engine.clone().at_reference(now)
     }
 
-    #[tool(description = "Parse natural language date expression (e.g., 'next wednesday', 'yesterday', 'october 22')")]
-    fn ucm_parse(&self, Parameters(params): Parameters<ParseParams>) -> String {
-        match ucm_parse(&params.expression) {
-            Ok(response) => serde_json::to_string_pretty(&response).unwrap(),
-            Err(error) => serde_json::to_string_pretty(&error).unwrap(),
-        }
+    #[tool(
+        description = "Get current date and time from system, optionally with fiscal year/quarter/period/week",
+        output_schema = output_schema::<NowResponse>()
+    )]
+    fn ucm_now(&self, Parameters(params): Parameters<NowParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_now(&ucm, params.fiscal_calendar.as_deref())
+        })))
     }
 
-    #[tool(description = "Calculate difference between two dates in multiple units")]
-    fn ucm_diff(&self, Parameters(params): Parameters<DiffParams>) -> String {
-        match ucm_diff(&params.from, &params.to) {
-            Ok(response) => serde_json::to_string_pretty(&response).unwrap(),
-            Err(error) => serde_json::to_string_pretty(&error).unwrap(),
-        }
+    #[tool(
+        description = "Parse natural language date expression (e.g., 'next wednesday', 'yesterday', 'october 22')",
+        output_schema = output_schema::<ParseResponse>()
+    )]
+    fn ucm_parse(&self, Parameters(params): Parameters<ParseParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_parse(&ucm, &params.expression)
+        })))
     }
 
-    #[tool(description = "Add duration to a date (e.g., '3 weeks', '-5 days', '2 months')")]
-    fn ucm_add(&self, Parameters(params): Parameters<AddParams>) -> String {
-        match ucm_add(&params.date, &params.add) {
-            Ok(response) => serde_json::to_string_pretty(&response).unwrap(),
-            Err(error) => serde_json::to_string_pretty(&error).unwrap(),
-        }
+    #[tool(
+        description = "Calculate difference between two dates in multiple units",
+        output_schema = output_schema::<DiffResponse>()
+    )]
+    fn ucm_diff(&self, Parameters(params): Parameters<DiffParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_diff(&ucm, &params.from, &params.to)
+        })))
     }
 
-    #[tool(description = "Convert duration between units (e.g., 3823 days to years/months/weeks)")]
-    fn ucm_convert(&self, Parameters(params): Parameters<ConvertParams>) -> String {
-        match ucm_convert(params.value, &params.from_unit) {
-            Ok(response) => serde_json::to_string_pretty(&response).unwrap(),
-            Err(error) => serde_json::to_string_pretty(&error).unwrap(),
-        }
+    #[tool(
+        description = "Add duration to a date (e.g., '3 weeks', '-5 days', '2 months')",
+        output_schema = output_schema::<AddResponse>()
+    )]
+    fn ucm_add(&self, Parameters(params): Parameters<AddParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_add(&ucm, &params.date, &params.add)
+        })))
     }
 
-    #[tool(description = "Get detailed information about a date")]
-    fn ucm_info(&self, Parameters(params): Parameters<InfoParams>) -> String {
-        match ucm_info(&params.date) {
-            Ok(response) => serde_json::to_string_pretty(&response).unwrap(),
-            Err(error) => serde_json::to_string_pretty(&error).unwrap(),
-        }
+    #[tool(
+        description = "Convert duration between units (e.g., 3823 days to years/months/weeks)",
+        output_schema = output_schema::<ConvertResponse>()
+    )]
+    fn ucm_convert(&self, Parameters(params): Parameters<ConvertParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(ucm_convert(params.value, &params.from_unit)))
+    }
+
+    #[tool(
+        description = "Get detailed information about a date, optionally including its fiscal year/quarter/period/week",
+        output_schema = output_schema::<InfoResponse>()
+    )]
+    fn ucm_info(&self, Parameters(params): Parameters<InfoParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_info(
+                &ucm,
+                &params.date,
+                params.fiscal_calendar.as_deref(),
+                params.retail_calendar.as_deref(),
+            )
+        })))
+    }
+
+    #[tool(
+        description = "Convert between Gregorian dates and Japanese imperial eras (e.g., '令和8年10月18日', 'R8.10.18', '2026-10-18')",
+        output_schema = output_schema::<EraResponse>()
+    )]
+    fn ucm_era(&self, Parameters(params): Parameters<EraParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_era(&ucm, &params.date)
+        })))
+    }
+
+    #[tool(
+        description = "Map a date to a retail 4-4-5/4-5-4/5-4-4 calendar (year/period/week, 53-week years), or a retail year/period/week back to dates",
+        output_schema = output_schema::<RetailResponse>()
+    )]
+    fn ucm_retail(&self, Parameters(params): Parameters<RetailParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_retail(
+                &ucm,
+                &params.calendar,
+                params.date.as_deref(),
+                params.year,
+                params.period,
+                params.week,
+            )
+        })))
+    }
+
+    #[tool(
+        description = "Convert dates to/from ISO (2026-W43-4), US Sunday-start and broadcast week numbers, list a week's date range, and add weeks aligned to a weekday",
+        output_schema = output_schema::<WeekResponse>()
+    )]
+    fn ucm_week(&self, Parameters(params): Parameters<WeekParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_week(
+                &ucm,
+                params.date.as_deref(),
+                params.year,
+                params.week,
+                params.system.as_deref(),
+                params.add_weeks,
+                params.align.as_deref(),
+            )
+        })))
+    }
+
+    #[tool(
+        description = "Get sunrise, sunset, solar noon, day length and civil/nautical/astronomical twilight for a location and date",
+        output_schema = output_schema::<SunResponse>()
+    )]
+    fn ucm_sun(&self, Parameters(params): Parameters<SunParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_sun(
+                &ucm,
+                &params.date,
+                params.latitude,
+                params.longitude,
+                params.city.as_deref(),
+                params.timezone.as_deref(),
+            )
+        })))
+    }
+
+    #[tool(
+        description = "List moon phases, equinoxes, solstices and eclipses between two dates, computed offline",
+        output_schema = output_schema::<AstroEventsResponse>()
+    )]
+    fn ucm_astro_events(&self, Parameters(params): Parameters<AstroEventsParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_astro_events(
+                &ucm,
+                &params.from,
+                params.to.as_deref(),
+                params.kinds.as_deref(),
+                params.timezone.as_deref(),
+            )
+        })))
+    }
+
+    #[tool(
+        description = "Run many parse/diff/add/info/convert operations in one call; later operations can use earlier results via $N.field references",
+        output_schema = output_schema::<BatchResponse>()
+    )]
+    fn ucm_batch(&self, Parameters(params): Parameters<BatchParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_batch(&ucm, &params.operations)
+        })))
+    }
+
+    #[tool(
+        description = "Start and end dates of the day, week, month, quarter, half, year or fiscal year containing a date, or the next/previous one; also resolves phrases like 'end of this month', 'start of next quarter' and 'EOW'",
+        output_schema = output_schema::<PeriodResponse>()
+    )]
+    fn ucm_period(&self, Parameters(params): Parameters<PeriodParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_period(
+                &ucm,
+                params.date.as_deref(),
+                params.unit.as_deref(),
+                params.offset,
+                params.week_start.as_deref(),
+                params.fiscal_calendar.as_deref(),
+            )
+        })))
+    }
+
+    #[tool(
+        description = "Exact age or tenure in years, months and days, with an explicit February 29 policy, the next birthday or anniversary, the date someone turns a given age, and upcoming anniversaries",
+        output_schema = output_schema::<AgeResponse>()
+    )]
+    fn ucm_age(&self, Parameters(params): Parameters<AgeParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_age(
+                &ucm,
+                &params.date,
+                params.as_of.as_deref(),
+                params.turns,
+                params.leap_day.as_deref(),
+                params.anniversaries,
+            )
+        })))
+    }
+
+    #[tool(
+        description = "Find the nth or last weekday, business day or day of a period ('3rd Thursday of November 2026', 'second-to-last business day of the quarter'), of every period in a range ('last Friday of each month in 2027'), or counted from a date ('first Monday after the 15th')",
+        output_schema = output_schema::<NthWeekdayResponse>()
+    )]
+    fn ucm_nth_weekday(&self, Parameters(params): Parameters<NthWeekdayParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_nth_weekday(&ucm, &params.expression, params.country.as_deref())
+        })))
+    }
+
+    #[tool(
+        description = "Save a named date such as a launch or deadline; every tool then accepts '@name' in place of a date, across conversations",
+        output_schema = output_schema::<MarkSetResponse>()
+    )]
+    fn ucm_mark_set(&self, Parameters(params): Parameters<MarkSetParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_mark_set(&ucm, &params.name, &params.date, params.note.as_deref())
+        })))
+    }
+
+    #[tool(
+        description = "List saved named dates with a countdown to each",
+        output_schema = output_schema::<MarkListResponse>()
+    )]
+    fn ucm_mark_list(&self, Parameters(params): Parameters<MarkListParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| ucm_mark_list(&ucm))))
+    }
+
+    #[tool(
+        description = "Delete a saved named date",
+        output_schema = output_schema::<MarkDeleteResponse>()
+    )]
+    fn ucm_mark_delete(&self, Parameters(params): Parameters<MarkDeleteParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_mark_delete(&ucm, &params.name)
+        })))
+    }
+
+    #[tool(
+        description = "Create a calendar event with start and end or duration, time zone, optional recurrence (RRULE), tags and note; stored locally and reported with any overlapping events",
+        output_schema = output_schema::<EventResponse>()
+    )]
+    fn ucm_event_create(&self, Parameters(params): Parameters<EventCreateParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            let fields = EventFields {
+                title: Some(params.title),
+                start: Some(params.start),
+                end: params.end,
+                duration: params.duration,
+                all_day: params.all_day,
+                timezone: params.timezone,
+                recurrence: params.recurrence,
+                tags: params.tags,
+                note: params.note,
+            };
+            ucm_event_create(&ucm, fields)
+        })))
+    }
+
+    #[tool(
+        description = "Change fields of a stored event; moving the start keeps its length unless end or duration is given",
+        output_schema = output_schema::<EventResponse>()
+    )]
+    fn ucm_event_update(&self, Parameters(params): Parameters<EventUpdateParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            let fields = EventFields {
+                title: params.title,
+                start: params.start,
+                end: params.end,
+                duration: params.duration,
+                all_day: params.all_day,
+                timezone: params.timezone,
+                recurrence: params.recurrence,
+                tags: params.tags,
+                note: params.note,
+            };
+            ucm_event_update(&ucm, &params.id, fields)
+        })))
+    }
+
+    #[tool(
+        description = "Delete a stored event and all its occurrences",
+        output_schema = output_schema::<EventDeleteResponse>()
+    )]
+    fn ucm_event_delete(&self, Parameters(params): Parameters<EventDeleteParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| ucm_event_delete(&ucm, &params.id))))
+    }
+
+    #[tool(
+        description = "List stored event occurrences for a day or period ('what do I have next Tuesday', 'this week') or a date range, with overlapping events flagged",
+        output_schema = output_schema::<EventListResponse>()
+    )]
+    fn ucm_event_list(&self, Parameters(params): Parameters<EventListParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_event_list(
+                &ucm,
+                params.when.as_deref(),
+                params.from.as_deref(),
+                params.to.as_deref(),
+                params.tag.as_deref(),
+            )
+        })))
+    }
+
+    #[tool(
+        description = "Export stored events as an iCalendar (.ics) file for other calendar apps",
+        output_schema = output_schema::<EventExportResponse>()
+    )]
+    fn ucm_event_export(&self, Parameters(params): Parameters<EventExportParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| ucm_event_export(&ucm, params.tag.as_deref()))))
+    }
+
+    #[tool(
+        description = "Find free slots of at least a duration within working hours over a day or range, around busy time from inline intervals, a local .ics file and stored events, with optional buffers, lunch break and public holidays",
+        output_schema = output_schema::<FreeSlotsResponse>()
+    )]
+    fn ucm_free_slots(&self, Parameters(params): Parameters<FreeSlotsParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            let query = SlotQuery {
+                duration: params.duration,
+                when: params.when,
+                from: params.from,
+                to: params.to,
+                busy: params.busy.unwrap_or_default(),
+                ics_file: params.ics_file,
+                include_events: params.include_events,
+                working_hours: params.working_hours,
+                working_days: params.working_days,
+                timezone: params.timezone,
+                lunch: params.lunch,
+                buffer_before: params.buffer_before,
+                buffer_after: params.buffer_after,
+                country: params.country,
+                limit: params.limit,
+            };
+            ucm_free_slots(&ucm, query)
+        })))
+    }
+
+    #[tool(
+        description = "Set a reminder for a date and time or 'in 20 minutes'; it is saved, and a connected server sends it to the client as a log message (logger 'ucm.reminders') when it falls due",
+        output_schema = output_schema::<RemindResponse>()
+    )]
+    fn ucm_remind(&self, Parameters(params): Parameters<RemindParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| ucm_remind(&ucm, &params.when, &params.message))))
+    }
+
+    #[tool(
+        description = "List pending reminders, optionally cancelling one by id; reminders that fell due with no client connected are listed as missed until one is sent them",
+        output_schema = output_schema::<RemindersResponse>()
+    )]
+    fn ucm_reminders(&self, Parameters(params): Parameters<RemindersParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| ucm_reminders(&ucm, params.cancel.as_deref()))))
+    }
+
+    #[tool(
+        description = "Explain a cron expression (standard, with seconds, or Quartz with L, W, # and ?) in English and list its next or previous run times in an IANA time zone, noting runs moved or repeated by DST changes",
+        output_schema = output_schema::<CronResponse>()
+    )]
+    fn ucm_cron(&self, Parameters(params): Parameters<CronParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_cron(
+                &ucm,
+                &params.expression,
+                params.count,
+                params.timezone.as_deref(),
+                params.from.as_deref(),
+                params.previous.unwrap_or(false),
+            )
+        })))
+    }
+
+    #[tool(
+        description = "Translate a schedule between cron, RRULE and English (e.g. 'every 2nd Tuesday at 09:00 Europe/London'), noting what the target cannot express and listing the first occurrences of each to show whether they agree",
+        output_schema = output_schema::<ScheduleTranslateResponse>()
+    )]
+    fn ucm_schedule_translate(&self, Parameters(params): Parameters<ScheduleTranslateParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_schedule_translate(
+                &ucm,
+                &params.schedule,
+                params.source.as_deref(),
+                params.target.as_deref(),
+                params.timezone.as_deref(),
+                params.start.as_deref(),
+                params.samples,
+            )
+        })))
+    }
+
+    #[tool(
+        description = "Convert raw timestamps: Unix seconds, milliseconds, microseconds or nanoseconds, Windows FILETIME, .NET ticks, Excel/Lotus serial dates (with the 1900 leap-year bug), Mac HFS+, NTP and GPS time. Reads a number in a given unit or one detected from its size, or a date and time, and gives the instant in every unit",
+        output_schema = output_schema::<TimestampResponse>()
+    )]
+    fn ucm_timestamp(&self, Parameters(params): Parameters<TimestampParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_timestamp(&ucm, &params.value, params.unit.as_deref(), params.timezone.as_deref())
+        })))
     }
 
-    #[tool(description = "Get UCM server status, version, and build information")]
-    fn ucm_status(&self) -> String {
-        let response = ucm_status();
-        serde_json::to_string_pretty(&response).unwrap()
+    #[tool(
+        description = "Evaluate a date expression: dates, durations (3 weeks, 30d, 2 business days) and numbers combined with + - * /, functions like end_of_month(date), min, max, business_days(from, to), and 'in <unit>' conversion; errors give the offending span",
+        output_schema = output_schema::<EvalResponse>()
+    )]
+    fn ucm_eval(&self, Parameters(params): Parameters<EvalParams>) -> Result<CallToolResult, McpError> {
+        Ok(tool_result(self.at(params.now.as_deref()).and_then(|ucm| {
+            ucm_eval(&ucm, &params.expression)
+        })))
     }
 
-    #[tool(description = "Get instructions on how to use UCM tools and what to expect from responses")]
-    fn ucm_instructions(&self) -> String {
-        let response = ucm_instructions();
-        serde_json::to_string_pretty(&response).unwrap()
+    #[tool(
+        description = "Get UCM server status, version, and build information",
+        output_schema = output_schema::<StatusResponse>()
+    )]
+    fn ucm_status(&self) -> Result<CallToolResult, McpError> {
+        Ok(structured(&ucm_status()))
+    }
+
+    #[tool(
+        description = "Get instructions on how to use UCM tools and what to expect from responses",
+        output_schema = output_schema::<InstructionsResponse>()
+    )]
+    fn ucm_instructions(&self) -> Result<CallToolResult, McpError> {
+        Ok(structured(&ucm_instructions()))
+    }
+}
+
+impl Default for UcmServer {
+    fn default() -> Self {
+        Self::new(Ucm::new())
     }
 }
 
@@ -131,18 +924,280 @@ impl rmcp::ServerHandler for UcmServer {
                 "Universal Calendar Manager - Date/time calculations for Claude Desktop. \
                  Use ucm_instructions to learn how to use all tools. \
                  Use ucm_status for version/build info. \
-                 Core tools: ucm_now, ucm_parse, ucm_diff, ucm_add, ucm_convert, ucm_info."
+                 Core tools: ucm_now, ucm_parse, ucm_diff, ucm_add, ucm_convert, ucm_info, ucm_era, ucm_retail, ucm_week, ucm_sun, ucm_astro_events, ucm_batch, ucm_eval, ucm_period, ucm_nth_weekday, ucm_age, ucm_mark_set, ucm_mark_list, ucm_mark_delete, \
+                 ucm_event_create, ucm_event_update, ucm_event_delete, ucm_event_list, ucm_event_export, ucm_free_slots, ucm_remind, ucm_reminders, \
+                 ucm_cron, ucm_schedule_translate, ucm_timestamp. \
+                 Dates saved with ucm_mark_set can be passed to any tool as '@name'. \
+                 Due reminders arrive as log messages from the 'ucm.reminders' logger. \
+                 Pass 'now' (e.g. '2026-03-01') to a date tool to answer as of that time. \
+                 Resources: ucm://now, ucm://timezones, ucm://calendar/{year}/{month}, ucm://holidays/{country}/{year}. \
+                 Prompts: schedule-across-timezones, deadline-from-business-days, age-and-anniversaries, countdown."
                     .into(),
             ),
-            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_resources()
+                .enable_prompts()
+                .enable_logging()
+                .build(),
             ..Default::default()
         }
     }
+
+    /// Send reminders to the client once it is ready for notifications
+    async fn on_initialized(&self, context: NotificationContext<RoleServer>) {
+        self.reminders.subscribe(context.peer);
+    }
+
+    /// Reminders are the only log messages and are always sent, so the level
+    /// is accepted but not applied
+    async fn set_level(
+        &self,
+        _request: SetLevelRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        Ok(())
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListPromptsResult, McpError> {
+        let prompts = prompts::PROMPTS
+            .iter()
+            .map(|template| {
+                let arguments = template
+                    .arguments
+                    .iter()
+                    .map(|arg| PromptArgument {
+                        name: arg.name.to_string(),
+                        title: None,
+                        description: Some(arg.description.to_string()),
+                        required: Some(arg.required),
+                    })
+                    .collect();
+                let mut prompt = Prompt::new(template.name, Some(template.description), Some(arguments));
+                prompt.title = Some(template.title.to_string());
+                prompt
+            })
+            .collect();
+        Ok(ListPromptsResult::with_all_items(prompts))
+    }
+
+    async fn get_prompt(
+        &self,
+        request: GetPromptRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<GetPromptResult, McpError> {
+        let arguments = request
+            .arguments
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    serde_json::Value::String(text) => text,
+                    other => other.to_string(),
+                };
+                (key, value)
+            })
+            .collect();
+
+        match prompts::render_prompt(&request.name, &arguments) {
+            Ok(text) => Ok(GetPromptResult {
+                description: prompts::find_prompt(&request.name).map(|p| p.description.to_string()),
+                messages: vec![PromptMessage::new_text(PromptMessageRole::User, text)],
+            }),
+            Err(error) => {
+                let data = serde_json::to_value(&error).ok();
+                Err(McpError::invalid_params(error.message, data))
+            }
+        }
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, McpError> {
+        let resources = resources::resources(self.engine.today())
+            .into_iter()
+            .map(|info| {
+                let mut resource = RawResource::new(info.uri, info.name);
+                resource.description = Some(info.description);
+                resource.mime_type = Some(resources::MIME_TYPE.to_string());
+                resource.no_annotation()
+            })
+            .collect();
+        Ok(ListResourcesResult::with_all_items(resources))
+    }
+
+    async fn list_resource_templates(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourceTemplatesResult, McpError> {
+        let templates = resources::TEMPLATES
+            .iter()
+            .map(|info| {
+                RawResourceTemplate {
+                    uri_template: info.uri_template.to_string(),
+                    name: info.name.to_string(),
+                    title: None,
+                    description: Some(info.description.to_string()),
+                    mime_type: Some(resources::MIME_TYPE.to_string()),
+                    icons: None,
+                }
+                .no_annotation()
+            })
+            .collect();
+        Ok(ListResourceTemplatesResult::with_all_items(templates))
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, McpError> {
+        match resources::read_resource(&self.engine, &request.uri) {
+            Ok(text) => Ok(ReadResourceResult {
+                contents: vec![ResourceContents::TextResourceContents {
+                    uri: request.uri,
+                    mime_type: Some(resources::MIME_TYPE.to_string()),
+                    text,
+                    meta: None,
+                }],
+            }),
+            Err(error) => {
+                let data = serde_json::to_value(&error).ok();
+                if error.error == ErrorKind::UnknownResource {
+                    Err(McpError::resource_not_found(error.message, data))
+                } else {
+                    Err(McpError::invalid_params(error.message, data))
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+enum Transport {
+    /// JSON-RPC over stdin/stdout, for a single desktop client
+    Stdio,
+    /// Streamable HTTP with SSE, for a shared server
+    Http,
+}
+
+/// Universal Calendar Manager - MCP server for date/time calculations.
+/// Serves MCP when run without a command; with a command, runs that tool once
+/// and prints the result.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<cli::Command>,
+
+    /// Output format for commands
+    #[arg(long, global = true, value_enum, default_value = "table")]
+    format: cli::OutputFormat,
+
+    /// Transport to serve MCP over
+    #[arg(long, value_enum, default_value = "stdio", env = "UCM_TRANSPORT")]
+    transport: Transport,
+
+    /// Address to bind the HTTP transport to
+    #[arg(long, default_value = "127.0.0.1", env = "UCM_HOST")]
+    host: std::net::IpAddr,
+
+    /// Port to bind the HTTP transport to
+    #[arg(long, default_value_t = 8080, env = "UCM_PORT")]
+    port: u16,
+
+    /// Path of the MCP endpoint
+    #[arg(long, default_value = "/mcp", env = "UCM_HTTP_PATH")]
+    path: String,
+
+    /// Require this bearer token on HTTP requests
+    #[arg(long, env = "UCM_AUTH_TOKEN", hide_env_values = true)]
+    auth_token: Option<String>,
+
+    /// Allowed CORS origins, comma-separated; "*" allows any origin
+    #[arg(long = "cors-origin", env = "UCM_CORS_ORIGINS", value_delimiter = ',')]
+    cors_origins: Vec<String>,
+
+    /// Pin "now" for every request, e.g. "2026-03-01T09:00:00+09:00", for
+    /// reproducible answers and replaying conversations
+    #[arg(long, global = true, env = "UCM_NOW")]
+    now: Option<String>,
+
+    /// Directory for saved '@name' dates and events [default:
+    /// $XDG_DATA_HOME/ucm or ~/.local/share/ucm]
+    #[arg(long, global = true, env = "UCM_DATA_DIR")]
+    data_dir: Option<std::path::PathBuf>,
 }
 
 #[tokio::main]
-async fn main() -> anyhow::Result<()> {
-    let server = UcmServer::new().serve(stdio()).await?;
-    server.waiting().await?;
-    Ok(())
+async fn main() -> anyhow::Result<ExitCode> {
+    let cli = Cli::parse();
+    let mut engine = Ucm::new().at_reference(cli.now.as_deref())?;
+    if let Some(dir) = cli.data_dir.or_else(store::default_data_dir) {
+        engine = engine.with_data_dir(dir);
+    }
+
+    if let Some(command) = cli.command {
+        return Ok(cli::run(command, cli.format, &engine));
+    }
+
+    match cli.transport {
+        Transport::Stdio => {
+            let server = UcmServer::new(engine).serve(stdio()).await?;
+            server.waiting().await?;
+        }
+        Transport::Http => {
+            let options = http::HttpOptions {
+                bind: std::net::SocketAddr::new(cli.host, cli.port),
+                path: cli.path,
+                auth_token: cli.auth_token.filter(|token| !token.is_empty()),
+                cors_origins: cli.cors_origins,
+            };
+            http::serve(options, engine).await?;
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tool_result_success_and_error() {
+        let ucm = Ucm::new().at_reference(Some("2026-10-19T12:00:00+00:00")).unwrap();
+        let success = tool_result(ucm_parse(&ucm, "2026-10-20"));
+        assert_eq!(success.is_error, Some(false));
+        let content = success.structured_content.unwrap();
+        assert_eq!(content["date"], "2026-10-20");
+        let text = success.content[0].as_text().unwrap();
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&text.text).unwrap(), content);
+
+        let failure = tool_result(ucm_parse(&ucm, "not a date at all"));
+        assert_eq!(failure.is_error, Some(true));
+        assert!(failure.structured_content.is_none());
+        let error: serde_json::Value = serde_json::from_str(&failure.content[0].as_text().unwrap().text).unwrap();
+        assert_eq!(error["error"], "parse_error");
+    }
+
+    #[test]
+    fn test_output_schemas() {
+        for tool in UcmServer::tool_router().list_all() {
+            let schema = tool.output_schema.unwrap_or_else(|| panic!("{} has no output schema", tool.name));
+            assert_eq!(schema["type"], "object", "{}", tool.name);
+            if let Some(now) = tool.input_schema.get("properties").and_then(|properties| properties.get("now")) {
+                assert_eq!(now["description"], NOW_DESCRIPTION, "{}", tool.name);
+            }
+        }
+        assert!(try_output_schema::<ParseResponse>().unwrap().contains_key("properties"));
+        assert!(try_output_schema::<String>().unwrap_err().contains("root type 'object'"));
+        assert_eq!(output_schema::<String>()["type"], "object");
+    }
 }
@@ -0,0 +1,161 @@
+//! Delivery of due reminders to connected MCP clients, as log messages.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset};
+use rmcp::model::{LoggingLevel, LoggingMessageNotificationParam};
+use rmcp::service::Peer;
+use rmcp::RoleServer;
+use serde_json::json;
+use tokio::sync::Notify;
+
+use ucm::engine::clock::{Clock, SystemClock};
+use ucm::store::{Reminder, ReminderState, ReminderStore};
+use ucm::tools::reminders::reminder_info;
+use ucm::Ucm;
+
+/// Longest wait between checks, so reminders saved by the CLI or another
+/// process are picked up
+const POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// The clients reminders go to, shared by every session of a server so one
+/// task delivers each reminder. Every session shares the bearer token and
+/// data directory, so each connected client is sent every reminder.
+#[derive(Clone)]
+pub struct ReminderDelivery {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    engine: Ucm,
+    peers: Mutex<Vec<Peer<RoleServer>>>,
+    wake: Notify,
+    running: AtomicBool,
+}
+
+impl ReminderDelivery {
+    pub fn new(engine: Ucm) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                engine,
+                peers: Mutex::new(Vec::new()),
+                wake: Notify::new(),
+                running: AtomicBool::new(false),
+            }),
+        }
+    }
+
+    /// Send reminders to a client ready for notifications; the first one
+    /// starts the delivery task
+    pub fn subscribe(&self, peer: Peer<RoleServer>) {
+        let Ok(store) = self.inner.engine.reminders() else {
+            return;
+        };
+        self.inner.peers.lock().unwrap().push(peer);
+        if !self.inner.running.swap(true, Ordering::SeqCst) {
+            tokio::spawn(deliver_reminders(self.inner.clone(), store));
+        }
+        self.inner.wake.notify_one();
+    }
+}
+
+/// Send each reminder as it falls due by the system clock, since a pinned
+/// reference time never moves. Closed sessions are dropped each round.
+async fn deliver_reminders(inner: Arc<Inner>, store: ReminderStore) {
+    let started = SystemClock.now();
+    loop {
+        let now = deliver_due(&inner, &store, started).await;
+        let next = store
+            .list()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|reminder| reminder.state == ReminderState::Pending && reminder.due > now)
+            .map(|reminder| reminder.due)
+            .min();
+        let wait = next.and_then(|due| (due - now).to_std().ok()).map_or(POLL_INTERVAL, |wait| wait.min(POLL_INTERVAL));
+        tokio::select! {
+            _ = tokio::time::sleep(wait) => {}
+            _ = inner.wake.notified() => {}
+        }
+    }
+}
+
+/// One round of delivery, returning the time it ran at
+async fn deliver_due(inner: &Inner, store: &ReminderStore, started: DateTime<FixedOffset>) -> DateTime<FixedOffset> {
+    let peers = {
+        let mut peers = inner.peers.lock().unwrap();
+        peers.retain(|peer| !peer.is_transport_closed());
+        peers.clone()
+    };
+    let now = SystemClock.now();
+    for reminder in store.claim_due(now, !peers.is_empty()).unwrap_or_default() {
+        let mut delivered = false;
+        for peer in &peers {
+            delivered |= peer.notify_logging_message(notice(&inner.engine, &reminder, started)).await.is_ok();
+        }
+        if !delivered {
+            let _ = store.mark_missed(&reminder.id);
+        }
+    }
+    now
+}
+
+/// The log message for a reminder; ones missed earlier or due before
+/// delivery `started` are sent as missed
+fn notice(engine: &Ucm, reminder: &Reminder, started: DateTime<FixedOffset>) -> LoggingMessageNotificationParam {
+    let missed = reminder.state == ReminderState::Missed || reminder.due < started;
+    LoggingMessageNotificationParam {
+        level: if missed { LoggingLevel::Warning } else { LoggingLevel::Notice },
+        logger: Some("ucm.reminders".to_string()),
+        data: json!({
+            "text": format!("{}: {}", if missed { "Missed reminder" } else { "Reminder" }, reminder.message),
+            "missed": missed,
+            "reminder": reminder_info(engine, reminder),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notice_marks_missed_reminders() {
+        let at = |text: &str| DateTime::parse_from_rfc3339(text).unwrap();
+        let engine = Ucm::new().at_reference(Some("2026-10-18T09:00:00+00:00")).unwrap();
+        let mut reminder = Reminder {
+            id: "rem-1".to_string(),
+            message: "Stand up".to_string(),
+            due: at("2026-10-18T09:20:00Z"),
+            created: at("2026-10-18T09:00:00Z"),
+            state: ReminderState::Pending,
+        };
+
+        let on_time = notice(&engine, &reminder, at("2026-10-18T09:00:00Z"));
+        assert_eq!((on_time.level, on_time.data["text"].as_str()), (LoggingLevel::Notice, Some("Reminder: Stand up")));
+        let late = notice(&engine, &reminder, at("2026-10-18T10:00:00Z"));
+        assert_eq!((late.level, late.data["missed"].as_bool()), (LoggingLevel::Warning, Some(true)));
+        reminder.state = ReminderState::Missed;
+        assert_eq!(notice(&engine, &reminder, at("2026-10-18T09:00:00Z")).data["missed"], true);
+    }
+
+    #[tokio::test]
+    async fn test_delivery_uses_the_system_clock() {
+        let dir = std::env::temp_dir().join(format!("ucm-notify-{}", std::process::id()));
+        // Pinned far in the past: by the reference time nothing is ever due
+        let engine = Ucm::new().at_reference(Some("2000-01-01T00:00:00+00:00")).unwrap().with_data_dir(&dir);
+        let store = engine.reminders().unwrap();
+        let due = SystemClock.now() - chrono::Duration::minutes(1);
+        store
+            .create(Reminder { id: String::new(), message: "Stand up".to_string(), due, created: due, state: ReminderState::Pending })
+            .unwrap();
+
+        let delivery = ReminderDelivery::new(engine);
+        deliver_due(&delivery.inner, &store, SystemClock.now()).await;
+        // No client was connected, so the reminder is missed rather than left waiting
+        assert_eq!(store.list().unwrap()[0].state, ReminderState::Missed);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,125 @@
+use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
+
+use crate::calendar::japanese::{era_year_start, find_era, from_era_date};
+
+/// Parse a Japanese era date.
+///
+/// Accepts kanji ("令和8年10月18日", "令和元年5月"), abbreviated ("R8.10.18",
+/// "H31/4/30") and romanized ("Reiwa 8", "Heisei 31-4-30") forms. A missing
+/// month or day defaults to the first day of the era year or month.
+///
+/// Returns `Ok(None)` when the expression does not start with a known era,
+/// so callers can fall through to other parsers.
+pub fn parse_era_date(expression: &str) -> Result<Option<NaiveDate>> {
+    let normalized = normalize(expression);
+    let split_at = normalized
+        .find(|c: char| c.is_ascii_digit() || c == '元')
+        .unwrap_or(normalized.len());
+    let (prefix, rest) = normalized.split_at(split_at);
+
+    let Some(era) = find_era(prefix) else {
+        return Ok(None);
+    };
+
+    let fields = if rest.contains('年') {
+        kanji_fields(rest)
+    } else {
+        separated_fields(rest)
+    }
+    .ok_or_else(|| anyhow!("Could not parse '{}' as a {} era date", expression, era.name))?;
+
+    let date = match fields {
+        (year, None, _) => era_year_start(era, year)?,
+        (year, Some(month), day) => from_era_date(era, year, month, day.unwrap_or(1))?,
+    };
+
+    Ok(Some(date))
+}
+
+/// Convert full-width digits and punctuation to ASCII
+fn normalize(expression: &str) -> String {
+    expression
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '０'..='９' => char::from_u32(c as u32 - '０' as u32 + '0' as u32).unwrap(),
+            '．' => '.',
+            '／' => '/',
+            '－' => '-',
+            '　' => ' ',
+            _ => c,
+        })
+        .collect()
+}
+
+/// Parse "8年10月18日", "元年5月", "8年"
+fn kanji_fields(rest: &str) -> Option<(i32, Option<u32>, Option<u32>)> {
+    let (year, rest) = rest.split_once('年')?;
+    let year = match year.trim() {
+        "元" => 1,
+        y => y.parse().ok()?,
+    };
+
+    let (month, rest) = match rest.split_once('月') {
+        Some((m, r)) => (Some(m.trim().parse().ok()?), r),
+        None => (None, rest),
+    };
+
+    let day = match rest.split_once('日') {
+        Some((d, r)) if r.trim().is_empty() && month.is_some() => Some(d.trim().parse().ok()?),
+        None if rest.trim().is_empty() => None,
+        _ => return None,
+    };
+
+    Some((year, month, day))
+}
+
+/// Parse "8", "8.10.18", "31/4/30", "1-5-1"
+fn separated_fields(rest: &str) -> Option<(i32, Option<u32>, Option<u32>)> {
+    let parts: Vec<&str> = rest
+        .split(['.', '/', '-', ' '])
+        .filter(|p| !p.is_empty())
+        .collect();
+
+    match parts.as_slice() {
+        [year] => Some((year.parse().ok()?, None, None)),
+        [year, month, day] => Some((
+            year.parse().ok()?,
+            Some(month.parse().ok()?),
+            Some(day.parse().ok()?),
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_parse_kanji_era() {
+        assert_eq!(parse_era_date("令和8年10月18日").unwrap(), Some(date(2026, 10, 18)));
+        assert_eq!(parse_era_date("令和８年１０月１８日").unwrap(), Some(date(2026, 10, 18)));
+        assert_eq!(parse_era_date("令和元年").unwrap(), Some(date(2019, 5, 1)));
+        assert_eq!(parse_era_date("令和8年").unwrap(), Some(date(2026, 1, 1)));
+    }
+
+    #[test]
+    fn test_parse_short_and_romanized_era() {
+        assert_eq!(parse_era_date("R8.10.18").unwrap(), Some(date(2026, 10, 18)));
+        assert_eq!(parse_era_date("H31/4/30").unwrap(), Some(date(2019, 4, 30)));
+        assert_eq!(parse_era_date("Showa 64").unwrap(), Some(date(1989, 1, 1)));
+    }
+
+    #[test]
+    fn test_parse_non_era_falls_through() {
+        assert_eq!(parse_era_date("2026-10-18").unwrap(), None);
+        assert_eq!(parse_era_date("next wednesday").unwrap(), None);
+        assert!(parse_era_date("H31.5.1").is_err());
+    }
+}
@@ -0,0 +1,471 @@
+//! Date expression language used by `ucm_eval`.
+//!
+//! ```text
+//! expression := sum [ "in" unit ]
+//! sum        := product { ("+" | "-") product }
+//! product    := unary { ("*" | "/") unary }
+//! unary      := "-" unary | primary
+//! primary    := number [ unit [ "ago" ] ]    3, 2.5, 30d, 3 weeks, 2 business days, 2 weeks ago
+//!             | iso-date                       2026-10-01, 2026-10-01T09:30, 2026-W43-4
+//!             | name "(" [ expression { "," expression } ] ")"
+//!             | "(" expression ")"
+//!             | phrase                         today, next friday, october 22, FY27 Q2, R8.10.18, @launch
+//! unit       := s | min | h | d | w | mo | q | y (or their full names) | business day(s) | bd
+//! ```
+//!
+//! A phrase is a run of words and numbers, handed to the natural language
+//! date parser. Spans in errors are character offsets into the expression.
+
+use crate::types::UcmError;
+
+/// Most levels of parentheses, calls and operators in one expression, since
+/// parsing and evaluation recurse on each
+const MAX_DEPTH: usize = 100;
+
+/// Units a number can carry
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Unit {
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Quarters,
+    Years,
+    BusinessDays,
+}
+
+impl Unit {
+    /// Look up a unit by name or abbreviation, singular or plural
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_lowercase();
+        let unit = match name.as_str() {
+            "s" | "sec" | "secs" | "second" | "seconds" => Self::Seconds,
+            "min" | "mins" | "minute" | "minutes" => Self::Minutes,
+            "h" | "hr" | "hrs" | "hour" | "hours" => Self::Hours,
+            "d" | "day" | "days" => Self::Days,
+            "w" | "wk" | "wks" | "week" | "weeks" => Self::Weeks,
+            "mo" | "mos" | "month" | "months" => Self::Months,
+            "q" | "quarter" | "quarters" => Self::Quarters,
+            "y" | "yr" | "yrs" | "year" | "years" => Self::Years,
+            "bd" | "business_day" | "business_days" => Self::BusinessDays,
+            _ => return None,
+        };
+        Some(unit)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Seconds => "seconds",
+            Self::Minutes => "minutes",
+            Self::Hours => "hours",
+            Self::Days => "days",
+            Self::Weeks => "weeks",
+            Self::Months => "months",
+            Self::Quarters => "quarters",
+            Self::Years => "years",
+            Self::BusinessDays => "business days",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExprKind {
+    Number(f64),
+    /// A number with a unit; `ago` subtracts it from now
+    Quantity { value: f64, unit: Unit, ago: bool },
+    /// ISO date, datetime or week date literal
+    IsoDate(String),
+    /// Natural language date, parsed when evaluated
+    Phrase(String),
+    Negate(Box<Expr>),
+    Binary(Box<Expr>, BinaryOp, Box<Expr>),
+    Call(String, Vec<Expr>),
+    Convert(Box<Expr>, Unit),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinaryOp {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+/// A parsed expression node and the characters it came from
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expr {
+    pub kind: ExprKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    IsoDate(String),
+    Word(String),
+    Symbol(char),
+}
+
+#[derive(Debug, Clone)]
+struct Lexeme {
+    token: Token,
+    start: usize,
+    end: usize,
+}
+
+fn syntax_error(expression: &str, message: &str, start: usize, end: usize) -> UcmError {
+    UcmError::parse_error(expression, message).at(start, end)
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Lexeme>, UcmError> {
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let token = if c.is_ascii_digit() {
+            if let Some(len) = iso_date_len(&chars[i..]) {
+                i += len;
+                Token::IsoDate(chars[start..i].iter().collect())
+            } else {
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let value = text.parse().map_err(|_| {
+                    syntax_error(expression, &format!("Invalid number '{}'", text), start, i)
+                })?;
+                Token::Number(value)
+            }
+        } else if c.is_alphabetic() || c == '@' {
+            // Saved date names may contain '-', so "@code-freeze" is one word
+            i += 1;
+            while i < chars.len()
+                && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '.' || (c == '@' && chars[i] == '-'))
+            {
+                i += 1;
+            }
+            Token::Word(chars[start..i].iter().collect())
+        } else if "+-*/(),".contains(c) {
+            i += 1;
+            Token::Symbol(c)
+        } else {
+            return Err(syntax_error(expression, &format!("Unexpected character '{}'", c), i, i + 1));
+        };
+        tokens.push(Lexeme { token, start, end: i });
+    }
+
+    Ok(tokens)
+}
+
+/// Length of an ISO date (2026-10-01), datetime (2026-10-01T09:30[:00]) or
+/// week date (2026-W43[-4]) at the start of `chars`
+fn iso_date_len(chars: &[char]) -> Option<usize> {
+    let digits = |from: usize, count: usize| {
+        chars.len() >= from + count && chars[from..from + count].iter().all(|c| c.is_ascii_digit())
+    };
+    let at = |index: usize, c: char| chars.get(index) == Some(&c);
+
+    if !digits(0, 4) || !at(4, '-') {
+        return None;
+    }
+    if at(5, 'W') && digits(6, 2) {
+        return Some(if at(8, '-') && digits(9, 1) { 10 } else { 8 });
+    }
+    if !(digits(5, 2) && at(7, '-') && digits(8, 2)) {
+        return None;
+    }
+    if at(10, 'T') && digits(11, 2) && at(13, ':') && digits(14, 2) {
+        return Some(if at(16, ':') && digits(17, 2) { 19 } else { 16 });
+    }
+    Some(10)
+}
+
+struct Parser<'a> {
+    expression: &'a str,
+    tokens: Vec<Lexeme>,
+    position: usize,
+    depth: usize,
+}
+
+/// Parse an expression into a syntax tree
+pub fn parse_expression(expression: &str) -> Result<Expr, UcmError> {
+    let tokens = tokenize(expression)?;
+    if tokens.is_empty() {
+        return Err(UcmError::missing_parameter("expression", "The expression is empty"));
+    }
+
+    let mut parser = Parser { expression, tokens, position: 0, depth: 0 };
+    let expr = parser.expression()?;
+    match parser.tokens.get(parser.position) {
+        None => Ok(expr),
+        Some(extra) => Err(parser.error_at(extra, &format!("Unexpected '{}'", parser.text(extra)))),
+    }
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|lexeme| &lexeme.token)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.position + offset).map(|lexeme| &lexeme.token)
+    }
+
+    fn is_symbol(&self, symbol: char) -> bool {
+        self.peek() == Some(&Token::Symbol(symbol))
+    }
+
+    fn is_word(&self, offset: usize, word: &str) -> bool {
+        matches!(self.peek_at(offset), Some(Token::Word(w)) if w.eq_ignore_ascii_case(word))
+    }
+
+    fn text(&self, lexeme: &Lexeme) -> String {
+        self.expression.chars().skip(lexeme.start).take(lexeme.end - lexeme.start).collect()
+    }
+
+    fn error_at(&self, lexeme: &Lexeme, message: &str) -> UcmError {
+        syntax_error(self.expression, message, lexeme.start, lexeme.end)
+    }
+
+    fn end_error(&self, message: &str) -> UcmError {
+        let end = self.expression.chars().count();
+        syntax_error(self.expression, message, end, end)
+    }
+
+    /// Go one level deeper at `lexeme`; the caller goes back up with `ascend`
+    fn descend(&mut self, lexeme: &Lexeme) -> Result<(), UcmError> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            let message = format!("Expressions can have at most {} levels of operators and parentheses", MAX_DEPTH);
+            return Err(self.error_at(lexeme, &message));
+        }
+        Ok(())
+    }
+
+    fn ascend(&mut self) {
+        self.depth -= 1;
+    }
+
+    fn last_end(&self) -> usize {
+        self.tokens[self.position - 1].end
+    }
+
+    /// Unit at the current position, and how many tokens it spans
+    fn unit_at(&self, offset: usize) -> Option<(Unit, usize)> {
+        if self.is_word(offset, "business") {
+            if let Some(Token::Word(next)) = self.peek_at(offset + 1) {
+                if matches!(next.to_lowercase().as_str(), "day" | "days") {
+                    return Some((Unit::BusinessDays, 2));
+                }
+            }
+        }
+        match self.peek_at(offset) {
+            Some(Token::Word(word)) => Unit::from_name(word).map(|unit| (unit, 1)),
+            _ => None,
+        }
+    }
+
+    /// Whether the current token starts a trailing `in <unit>` conversion
+    fn at_conversion(&self) -> bool {
+        if !self.is_word(0, "in") {
+            return false;
+        }
+        match self.unit_at(1) {
+            Some((_, len)) => matches!(self.peek_at(1 + len), None | Some(Token::Symbol(')')) | Some(Token::Symbol(','))),
+            None => false,
+        }
+    }
+
+    fn expression(&mut self) -> Result<Expr, UcmError> {
+        let expr = self.sum()?;
+        if self.at_conversion() {
+            let (unit, len) = self.unit_at(1).unwrap();
+            self.position += 1 + len;
+            let end = self.last_end();
+            return Ok(Expr { start: expr.start, end, kind: ExprKind::Convert(Box::new(expr), unit) });
+        }
+        Ok(expr)
+    }
+
+    fn sum(&mut self) -> Result<Expr, UcmError> {
+        let depth = self.depth;
+        let mut left = self.product()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Symbol('+')) => BinaryOp::Add,
+                Some(Token::Symbol('-')) => BinaryOp::Subtract,
+                _ => {
+                    self.depth = depth;
+                    return Ok(left);
+                }
+            };
+            // Each operator nests the terms before it one level deeper
+            self.descend(&self.tokens[self.position].clone())?;
+            self.position += 1;
+            let right = self.product()?;
+            left = Expr { start: left.start, end: right.end, kind: ExprKind::Binary(Box::new(left), op, Box::new(right)) };
+        }
+    }
+
+    fn product(&mut self) -> Result<Expr, UcmError> {
+        let depth = self.depth;
+        let mut left = self.unary()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Symbol('*')) => BinaryOp::Multiply,
+                Some(Token::Symbol('/')) => BinaryOp::Divide,
+                _ => {
+                    self.depth = depth;
+                    return Ok(left);
+                }
+            };
+            self.descend(&self.tokens[self.position].clone())?;
+            self.position += 1;
+            let right = self.unary()?;
+            left = Expr { start: left.start, end: right.end, kind: ExprKind::Binary(Box::new(left), op, Box::new(right)) };
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr, UcmError> {
+        if self.is_symbol('-') {
+            let lexeme = self.tokens[self.position].clone();
+            self.descend(&lexeme)?;
+            self.position += 1;
+            let operand = self.unary()?;
+            self.ascend();
+            return Ok(Expr { start: lexeme.start, end: operand.end, kind: ExprKind::Negate(Box::new(operand)) });
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Expr, UcmError> {
+        let Some(lexeme) = self.tokens.get(self.position).cloned() else {
+            return Err(self.end_error("Expected a date, duration or number at the end of the expression"));
+        };
+        let start = lexeme.start;
+
+        match lexeme.token {
+            Token::Number(value) => {
+                self.position += 1;
+                let Some((unit, len)) = self.unit_at(0) else {
+                    return Ok(Expr { start, end: lexeme.end, kind: ExprKind::Number(value) });
+                };
+                self.position += len;
+                let ago = self.is_word(0, "ago");
+                if ago {
+                    self.position += 1;
+                }
+                Ok(Expr { start, end: self.last_end(), kind: ExprKind::Quantity { value, unit, ago } })
+            }
+            Token::IsoDate(text) => {
+                self.position += 1;
+                Ok(Expr { start, end: lexeme.end, kind: ExprKind::IsoDate(text) })
+            }
+            Token::Symbol('(') => {
+                self.descend(&lexeme)?;
+                self.position += 1;
+                let inner = self.expression()?;
+                self.ascend();
+                self.expect(')', "Expected ')'")?;
+                Ok(Expr { start, end: self.last_end(), ..inner })
+            }
+            Token::Word(ref name) if self.peek_at(1) == Some(&Token::Symbol('(')) => {
+                self.descend(&lexeme)?;
+                self.position += 2;
+                let mut args = Vec::new();
+                if !self.is_symbol(')') {
+                    loop {
+                        args.push(self.expression()?);
+                        if !self.is_symbol(',') {
+                            break;
+                        }
+                        self.position += 1;
+                    }
+                }
+                self.ascend();
+                self.expect(')', &format!("Expected ',' or ')' in the arguments of {}()", name))?;
+                Ok(Expr { start, end: self.last_end(), kind: ExprKind::Call(name.to_lowercase(), args) })
+            }
+            Token::Word(_) => {
+                let mut words = Vec::new();
+                while let Some(lexeme) = self.tokens.get(self.position) {
+                    if !matches!(lexeme.token, Token::Word(_) | Token::Number(_)) || self.at_conversion() {
+                        break;
+                    }
+                    words.push(self.text(lexeme));
+                    self.position += 1;
+                }
+                Ok(Expr { start, end: self.last_end(), kind: ExprKind::Phrase(words.join(" ")) })
+            }
+            Token::Symbol(symbol) => Err(self.error_at(
+                &lexeme,
+                &format!("Unexpected '{}'; expected a date, duration or number", symbol),
+            )),
+        }
+    }
+
+    fn expect(&mut self, symbol: char, message: &str) -> Result<(), UcmError> {
+        match self.tokens.get(self.position) {
+            Some(lexeme) if lexeme.token == Token::Symbol(symbol) => {
+                self.position += 1;
+                Ok(())
+            }
+            Some(lexeme) => Err(self.error_at(lexeme, message)),
+            None => Err(self.end_error(message)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_expression_tree() {
+        let expr = parse_expression("next friday + 2 business days").unwrap();
+        let ExprKind::Binary(left, BinaryOp::Add, right) = expr.kind else { panic!("{:?}", expr) };
+        assert_eq!(left.kind, ExprKind::Phrase("next friday".to_string()));
+        assert_eq!(right.kind, ExprKind::Quantity { value: 2.0, unit: Unit::BusinessDays, ago: false });
+
+        let expr = parse_expression("(2026-12-25 - today) in weeks").unwrap();
+        assert!(matches!(expr.kind, ExprKind::Convert(_, Unit::Weeks)));
+        assert_eq!((expr.start, expr.end), (0, 29));
+
+        let expr = parse_expression("max(2026-10-01, today + 30d)").unwrap();
+        let ExprKind::Call(name, args) = expr.kind else { panic!() };
+        assert_eq!((name.as_str(), args.len()), ("max", 2));
+        assert_eq!(args[0].kind, ExprKind::IsoDate("2026-10-01".to_string()));
+    }
+
+    #[test]
+    fn test_parse_expression_errors() {
+        let error = parse_expression("today + + 3d").unwrap_err();
+        assert_eq!(error.span.map(|s| (s.start, s.end)), Some((8, 9)));
+
+        let error = parse_expression("end_of_month(today").unwrap_err();
+        assert_eq!(error.span.map(|s| (s.start, s.end)), Some((18, 18)));
+
+        let error = parse_expression("today # 3").unwrap_err();
+        assert_eq!(error.span.map(|s| s.start), Some(6));
+
+        // Deep nesting is refused rather than overflowing the stack
+        let error = parse_expression(&format!("{}1{}", "(".repeat(5000), ")".repeat(5000))).unwrap_err();
+        assert_eq!(error.span.map(|s| s.start), Some(MAX_DEPTH));
+        let error = parse_expression(&format!("{}1", "-".repeat(5000))).unwrap_err();
+        assert_eq!(error.span.map(|s| s.start), Some(MAX_DEPTH));
+        assert!(parse_expression(&format!("{}1{}", "(".repeat(MAX_DEPTH), ")".repeat(MAX_DEPTH))).is_ok());
+        assert!(parse_expression(&format!("1{}", " + 1".repeat(5000))).is_err());
+        assert!(parse_expression(&format!("1{}", " * 2".repeat(MAX_DEPTH))).is_ok());
+    }
+}
@@ -0,0 +1,161 @@
+use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
+
+use crate::calendar::fiscal::FiscalCalendar;
+
+/// Parse a fiscal expression into the date range it names.
+///
+/// Accepts "FY27", "FY2027 Q2", "Q2 FY27", "FY27 P5", "fiscal Q3",
+/// "next fiscal year", "this fiscal quarter", optionally prefixed with
+/// "start of" or "end of". Without a prefix the start of the range is used
+/// by `parse_fiscal_date`.
+///
+/// Returns `Ok(None)` when the expression is not a fiscal expression.
+pub fn parse_fiscal_range(
+    expression: &str,
+    calendar: &FiscalCalendar,
+    today: NaiveDate,
+) -> Result<Option<(NaiveDate, NaiveDate)>> {
+    let lower = expression.trim().to_lowercase().replace(['-', ','], " ");
+    let (_, body) = split_anchor(&lower);
+    let tokens: Vec<&str> = body.split_whitespace().collect();
+
+    if !tokens.iter().any(|t| *t == "fiscal" || t.starts_with("fy")) {
+        return Ok(None);
+    }
+
+    let invalid = || anyhow!("Could not parse '{}' as a fiscal date", expression);
+    let current = calendar.locate(today)?;
+
+    let mut year = None;
+    let mut quarter = None;
+    let mut period = None;
+    let mut offset = 0;
+    let mut unit_quarter = false;
+    let mut tokens = tokens.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            "fiscal" | "the" | "of" => {}
+            "year" | "fy" => {
+                if let Some(value) = tokens.peek().and_then(|t| parse_year(t)) {
+                    year = Some(value);
+                    tokens.next();
+                }
+            }
+            "quarter" => match tokens.peek().and_then(|t| t.parse().ok()) {
+                Some(value) => {
+                    quarter = Some(value);
+                    tokens.next();
+                }
+                None => unit_quarter = true,
+            },
+            "period" => {
+                period = Some(tokens.next().and_then(|t| t.parse().ok()).ok_or_else(invalid)?);
+            }
+            "this" | "current" => offset = 0,
+            "next" => offset = 1,
+            "last" | "previous" | "prior" => offset = -1,
+            t if t.starts_with("fy") => year = Some(parse_year(&t[2..]).ok_or_else(invalid)?),
+            t if t.starts_with('q') => quarter = Some(t[1..].parse().map_err(|_| invalid())?),
+            t if t.starts_with('p') => period = Some(t[1..].parse().map_err(|_| invalid())?),
+            _ => return Err(invalid()),
+        }
+    }
+
+    let range = if unit_quarter && year.is_none() && quarter.is_none() {
+        // "next fiscal quarter" steps whole quarters from today
+        let index = current.fiscal_year * 4 + current.quarter as i32 - 1 + offset;
+        calendar.quarter_range(index.div_euclid(4), index.rem_euclid(4) as u32 + 1)?
+    } else {
+        let fiscal_year = year.unwrap_or(current.fiscal_year + offset);
+        match (quarter, period) {
+            (Some(q), _) => calendar.quarter_range(fiscal_year, q)?,
+            (None, Some(p)) => calendar.period_range(fiscal_year, p)?,
+            (None, None) => calendar.year_range(fiscal_year)?,
+        }
+    };
+
+    Ok(Some(range))
+}
+
+/// Parse a fiscal expression to a single date, honouring "start of"/"end of"
+pub fn parse_fiscal_date(
+    expression: &str,
+    calendar: &FiscalCalendar,
+    today: NaiveDate,
+) -> Result<Option<NaiveDate>> {
+    let lower = expression.trim().to_lowercase();
+    let (at_end, _) = split_anchor(&lower);
+
+    Ok(parse_fiscal_range(expression, calendar, today)?
+        .map(|(start, end)| if at_end { end } else { start }))
+}
+
+/// Strip a leading "start of"/"beginning of"/"end of", reporting whether it was "end of"
+fn split_anchor(expression: &str) -> (bool, &str) {
+    for prefix in ["start of ", "beginning of "] {
+        if let Some(rest) = expression.strip_prefix(prefix) {
+            return (false, rest);
+        }
+    }
+    match expression.strip_prefix("end of ") {
+        Some(rest) => (true, rest),
+        None => (false, expression),
+    }
+}
+
+/// "27" -> 2027, "2027" -> 2027
+fn parse_year(value: &str) -> Option<i32> {
+    let year: i32 = value.parse().ok()?;
+    match value.len() {
+        2 => Some(2000 + year),
+        4 => Some(year),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_parse_fy_quarter() {
+        let calendar = FiscalCalendar::from_name("us_federal").unwrap();
+        let today = date(2026, 10, 18);
+        assert_eq!(
+            parse_fiscal_range("FY27 Q2", &calendar, today).unwrap(),
+            Some((date(2027, 1, 1), date(2027, 3, 31)))
+        );
+        assert_eq!(
+            parse_fiscal_date("end of fiscal Q3", &calendar, today).unwrap(),
+            Some(date(2027, 6, 30))
+        );
+    }
+
+    #[test]
+    fn test_parse_relative_fiscal_year() {
+        let calendar = FiscalCalendar::from_name("australia").unwrap();
+        let today = date(2026, 10, 18);
+        assert_eq!(
+            parse_fiscal_date("start of next fiscal year", &calendar, today).unwrap(),
+            Some(date(2027, 7, 1))
+        );
+        assert_eq!(
+            parse_fiscal_date("end of last fiscal quarter", &calendar, today).unwrap(),
+            Some(date(2026, 9, 30))
+        );
+    }
+
+    #[test]
+    fn test_parse_non_fiscal() {
+        let calendar = FiscalCalendar::from_name("uk").unwrap();
+        let today = date(2026, 10, 18);
+        assert_eq!(parse_fiscal_range("next friday", &calendar, today).unwrap(), None);
+        assert!(parse_fiscal_range("FY27 Q5", &calendar, today).is_err());
+    }
+}
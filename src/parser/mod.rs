@@ -1,3 +1,9 @@
+pub mod era;
+pub mod expr;
+pub mod fiscal;
 pub mod natural;
+pub mod nth;
+pub mod period;
+pub mod week;
 
-pub use natural::{parse_to_date, parse_duration};
+pub use natural::{parse_duration, parse_to_date_in, parse_to_datetime_in};
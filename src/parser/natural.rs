@@ -1,7 +1,14 @@
 use anyhow::{anyhow, Result};
-use chrono::{Duration, Local, NaiveDate, NaiveDateTime};
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
 use two_timer::{parse, Config};
 
+use super::era::parse_era_date;
+use super::fiscal::parse_fiscal_date;
+use super::period::{parse_period, Anchor};
+use super::week::parse_iso_week_date;
+use crate::calendar::fiscal::FiscalCalendar;
+use crate::calendar::period::PeriodUnit;
+
 #[derive(Debug)]
 #[allow(dead_code)]
 pub struct ParsedDate {
@@ -10,9 +17,8 @@ pub struct ParsedDate {
     pub is_range: bool,
 }
 
-/// Parse a natural language date expression
-pub fn parse_natural(expression: &str) -> Result<ParsedDate> {
-    let now = Local::now().naive_local();
+/// Parse a natural language date expression relative to `now`
+pub fn parse_natural(expression: &str, now: NaiveDateTime) -> Result<ParsedDate> {
     let config = Config::new().now(now);
 
     match parse(expression, Some(config)) {
@@ -25,30 +31,113 @@ pub fn parse_natural(expression: &str) -> Result<ParsedDate> {
     }
 }
 
-/// Parse expression to just a date
-pub fn parse_to_date(expression: &str) -> Result<NaiveDate> {
+/// Parse expression to just a date relative to `now`, resolving fiscal
+/// expressions ("FY27 Q2", "end of fiscal Q3") against the given fiscal calendar
+/// and period anchors ("end of this month", "EOW") with Monday-start weeks
+pub fn parse_to_date_in(expression: &str, fiscal: &FiscalCalendar, now: NaiveDateTime) -> Result<NaiveDate> {
     // First try ISO format directly
     if let Ok(date) = NaiveDate::parse_from_str(expression, "%Y-%m-%d") {
         return Ok(date);
     }
 
-    let parsed = parse_natural(expression)?;
+    if let Some(date) = parse_iso_week_date(expression) {
+        return Ok(date);
+    }
+
+    if let Some(date) = parse_fiscal_date(expression, fiscal, now.date())? {
+        return Ok(date);
+    }
+
+    // Before eras, so "H2 2027" is a half-year rather than Heisei 2.
+    // Bare "next week" and "march 2027" are left to the natural language parser
+    if let Some(period) = parse_period(expression, fiscal, now.date(), Weekday::Mon)? {
+        if period.anchor != Anchor::Whole || matches!(period.unit, PeriodUnit::Quarter | PeriodUnit::Half) {
+            return Ok(period.date());
+        }
+    }
+
+    if let Some(date) = parse_era_date(expression)? {
+        return Ok(date);
+    }
+
+    let parsed = parse_natural(expression, now)?;
     Ok(parsed.start.date())
 }
 
-/// Parse expression to datetime
-#[allow(dead_code)]
-pub fn parse_to_datetime(expression: &str) -> Result<NaiveDateTime> {
-    // Try ISO format directly first
-    if let Ok(dt) = NaiveDateTime::parse_from_str(expression, "%Y-%m-%dT%H:%M:%S") {
-        return Ok(dt);
+/// Parse a date and time relative to `now`: an ISO datetime
+/// ("2026-10-20T09:30", "2026-10-20 09:30"), a date expression followed by a
+/// time ("next tuesday at 2pm", "FY27 Q2 09:00"), a bare time (today), or a
+/// date expression at midnight
+pub fn parse_to_datetime_in(expression: &str, fiscal: &FiscalCalendar, now: NaiveDateTime) -> Result<NaiveDateTime> {
+    let expression = expression.trim();
+    for format in ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"] {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(expression, format) {
+            return Ok(datetime);
+        }
     }
-    if let Ok(date) = NaiveDate::parse_from_str(expression, "%Y-%m-%d") {
-        return Ok(date.and_hms_opt(0, 0, 0).unwrap());
+
+    if let Some((date, time)) = split_time_of_day(expression) {
+        let date = if date.is_empty() { now.date() } else { parse_to_date_in(date, fiscal, now)? };
+        return Ok(date.and_time(time));
     }
 
-    let parsed = parse_natural(expression)?;
-    Ok(parsed.start)
+    match parse_natural(expression, now) {
+        Ok(parsed) => Ok(parsed.start),
+        Err(_) => Ok(parse_to_date_in(expression, fiscal, now)?.and_time(NaiveTime::MIN)),
+    }
+}
+
+/// Split a trailing time of day ("at 9:30", "2pm", "14:00", "noon") off an
+/// expression, returning the date part and the time
+pub fn split_time_of_day(expression: &str) -> Option<(&str, NaiveTime)> {
+    let expression = expression.trim();
+    let (rest, last) = match expression.rsplit_once(char::is_whitespace) {
+        Some((rest, last)) => (rest.trim_end(), last),
+        None => ("", expression),
+    };
+    // "9 am" is two words
+    let (rest, time) = match parse_time_of_day(last) {
+        Some(time) => (rest, time),
+        None => {
+            let (rest, hour) = rest.rsplit_once(char::is_whitespace).unwrap_or(("", rest));
+            (rest.trim_end(), parse_time_of_day(&format!("{}{}", hour, last)).filter(|_| !hour.is_empty())?)
+        }
+    };
+    let rest = match rest.rsplit_once(char::is_whitespace) {
+        Some((date, "at")) => date.trim_end(),
+        None if rest == "at" => "",
+        _ => rest,
+    };
+    Some((rest, time))
+}
+
+/// "9:30", "09:30:15", "2pm", "2:30pm", "noon" or "midnight"
+pub fn parse_time_of_day(text: &str) -> Option<NaiveTime> {
+    let text = text.trim().to_lowercase();
+    match text.as_str() {
+        "noon" | "midday" => return NaiveTime::from_hms_opt(12, 0, 0),
+        "midnight" => return Some(NaiveTime::MIN),
+        _ => {}
+    }
+    let (clock, meridiem) = match (text.strip_suffix("am"), text.strip_suffix("pm")) {
+        (Some(clock), _) => (clock.trim_end_matches('.'), Some(0)),
+        (_, Some(clock)) => (clock.trim_end_matches('.'), Some(12)),
+        _ => (text.as_str(), None),
+    };
+    let parts: Vec<&str> = clock.split(':').collect();
+    if parts.iter().any(|part| part.is_empty() || !part.chars().all(|c| c.is_ascii_digit())) {
+        return None;
+    }
+    let number = |index: usize| parts.get(index).map_or(Some(0), |part| part.parse::<u32>().ok());
+    let (hour, minute, second) = (number(0)?, number(1)?, number(2)?);
+    let hour = match meridiem {
+        // A bare number is only a time with am/pm
+        None if parts.len() < 2 || parts.len() > 3 => return None,
+        None => hour,
+        Some(offset) if (1..=12).contains(&hour) && parts.len() <= 2 => hour % 12 + offset,
+        Some(_) => return None,
+    };
+    NaiveTime::from_hms_opt(hour, minute, second)
 }
 
 /// Parse a duration string like "3 weeks", "2 months", "-5 days"
@@ -56,10 +145,9 @@ pub fn parse_duration(expression: &str) -> Result<Duration> {
     let expr = expression.trim().to_lowercase();
 
     // Handle negative durations
-    let (is_negative, expr) = if expr.starts_with('-') {
-        (true, expr[1..].trim())
-    } else {
-        (false, expr.as_str())
+    let (is_negative, expr) = match expr.strip_prefix('-') {
+        Some(rest) => (true, rest.trim()),
+        None => (false, expr.as_str()),
     };
 
     // Parse "N unit" format
@@ -108,18 +196,24 @@ fn apply_sign(duration: Duration, is_negative: bool) -> Duration {
 mod tests {
     use super::*;
 
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 3, 1).unwrap().and_hms_opt(9, 0, 0).unwrap()
+    }
+
+    fn parse_to_date(expression: &str) -> Result<NaiveDate> {
+        parse_to_date_in(expression, &FiscalCalendar::default_profile(), now())
+    }
+
     #[test]
     fn test_parse_today() {
-        let result = parse_natural("today").unwrap();
-        let today = Local::now().naive_local().date();
-        assert_eq!(result.start.date(), today);
+        let result = parse_natural("today", now()).unwrap();
+        assert_eq!(result.start.date(), now().date());
     }
 
     #[test]
     fn test_parse_tomorrow() {
-        let result = parse_natural("tomorrow").unwrap();
-        let tomorrow = Local::now().naive_local().date().succ_opt().unwrap();
-        assert_eq!(result.start.date(), tomorrow);
+        let result = parse_natural("tomorrow", now()).unwrap();
+        assert_eq!(result.start.date(), NaiveDate::from_ymd_opt(2026, 3, 2).unwrap());
     }
 
     #[test]
@@ -128,6 +222,36 @@ mod tests {
         assert_eq!(result, NaiveDate::from_ymd_opt(2026, 10, 22).unwrap());
     }
 
+    #[test]
+    fn test_parse_era_date() {
+        let result = parse_to_date("令和8年10月18日").unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2026, 10, 18).unwrap());
+    }
+
+    #[test]
+    fn test_parse_period_anchor() {
+        assert_eq!(parse_to_date("end of this month").unwrap(), NaiveDate::from_ymd_opt(2026, 3, 31).unwrap());
+        assert_eq!(parse_to_date("Q3 2027").unwrap(), NaiveDate::from_ymd_opt(2027, 7, 1).unwrap());
+        // Halves, not the Heisei era abbreviation, unless a month follows
+        assert_eq!(parse_to_date("H2 2027").unwrap(), NaiveDate::from_ymd_opt(2027, 7, 1).unwrap());
+        assert_eq!(parse_to_date("H1 2027").unwrap(), NaiveDate::from_ymd_opt(2027, 1, 1).unwrap());
+        assert_eq!(parse_to_date("H2").unwrap(), NaiveDate::from_ymd_opt(2026, 7, 1).unwrap());
+        assert_eq!(parse_to_date("H2.1.8").unwrap(), NaiveDate::from_ymd_opt(1990, 1, 8).unwrap());
+    }
+
+    #[test]
+    fn test_parse_datetime() {
+        let fiscal = FiscalCalendar::default_profile();
+        let at = |h: u32, m: u32| NaiveDate::from_ymd_opt(2026, 3, 3).unwrap().and_hms_opt(h, m, 0).unwrap();
+        assert_eq!(parse_to_datetime_in("2026-03-03T14:30", &fiscal, now()).unwrap(), at(14, 30));
+        assert_eq!(parse_to_datetime_in("2026-03-03 at 2:30pm", &fiscal, now()).unwrap(), at(14, 30));
+        assert_eq!(parse_to_datetime_in("2026-03-03 9 am", &fiscal, now()).unwrap(), at(9, 0));
+        assert_eq!(parse_to_datetime_in("2026-03-03", &fiscal, now()).unwrap(), at(0, 0));
+        assert_eq!(parse_to_datetime_in("noon", &fiscal, now()).unwrap().time(), at(12, 0).time());
+        assert_eq!(parse_time_of_day("13pm"), None);
+        assert_eq!(parse_time_of_day("2026"), None);
+    }
+
     #[test]
     fn test_parse_duration_weeks() {
         let dur = parse_duration("3 weeks").unwrap();
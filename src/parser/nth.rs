@@ -0,0 +1,138 @@
+use anyhow::{anyhow, Result};
+use chrono::Weekday;
+
+use crate::calendar::nth::DayKind;
+use crate::calendar::period::PeriodUnit;
+
+/// Where the nth day is counted
+#[derive(Debug, Clone, PartialEq)]
+pub enum NthScope {
+    /// Within one period, e.g. "of November 2026" or "of the quarter"
+    Period(String),
+    /// Within every period of a unit, e.g. "of each month in 2027"
+    Each { unit: PeriodUnit, within: Option<String> },
+    /// Counting forwards from a date ("after", "on or after") or backwards
+    /// ("before", "on or before")
+    From { date: String, forwards: bool, inclusive: bool },
+}
+
+/// A query such as "3rd Thursday of November 2026"
+#[derive(Debug, Clone, PartialEq)]
+pub struct NthQuery {
+    /// 1 for the first, -1 for the last, -2 for the second to last
+    pub n: i32,
+    pub kind: DayKind,
+    pub scope: NthScope,
+}
+
+/// Parse "<ordinal> <day> <scope>".
+///
+/// Ordinals are first to fifth, 1st..5th, last, penultimate and "second to
+/// last"; days are a weekday name, "business day"/"working day"/"weekday"
+/// or "day"; the scope is "of/in <period>", "of each/every <unit> [in
+/// <period>]", or "after/before/on or after/on or before <date>".
+pub fn parse_nth(expression: &str) -> Result<NthQuery> {
+    let lower = expression.trim().to_lowercase().replace(['-', ','], " ");
+    let tokens: Vec<&str> = lower.split_whitespace().collect();
+    let invalid = |what: &str| {
+        anyhow!(
+            "Could not read '{}': {}. Expected e.g. '3rd Thursday of November 2026', 'last Friday of each month in 2027' or 'first Monday after the 15th'",
+            expression,
+            what
+        )
+    };
+
+    let mut rest = &tokens[..];
+    let mut n = match rest.first().and_then(|t| ordinal(t)) {
+        Some(n) => n,
+        None if rest.first() == Some(&"last") => -1,
+        None if rest.first() == Some(&"penultimate") => -2,
+        None => return Err(invalid("expected an ordinal such as 'first', '3rd' or 'last'")),
+    };
+    rest = &rest[1..];
+    if n > 0 && rest.starts_with(&["to", "last"]) {
+        n = -n;
+        rest = &rest[2..];
+    }
+
+    let (kind, used) = day_kind(rest).ok_or_else(|| invalid("expected a weekday, 'business day' or 'day'"))?;
+    rest = &rest[used..];
+
+    let scope = match rest {
+        ["of" | "in", "each" | "every", unit, within @ ..] => {
+            let unit = PeriodUnit::from_name(unit.trim_end_matches('s'), Weekday::Mon)?;
+            let within = match within {
+                [] => None,
+                ["in" | "of" | "during", period @ ..] if !period.is_empty() => Some(period.join(" ")),
+                _ => return Err(invalid("expected 'in <period>' after the repeating unit")),
+            };
+            NthScope::Each { unit, within }
+        }
+        ["of" | "in", period @ ..] if !period.is_empty() => NthScope::Period(period.join(" ")),
+        ["on", "or", "after", date @ ..] if !date.is_empty() => from(date, true, true),
+        ["on", "or", "before", date @ ..] if !date.is_empty() => from(date, false, true),
+        ["after" | "following", date @ ..] if !date.is_empty() => from(date, true, false),
+        ["before" | "preceding", date @ ..] if !date.is_empty() => from(date, false, false),
+        _ => return Err(invalid("expected 'of <period>', 'after <date>' or 'before <date>'")),
+    };
+
+    Ok(NthQuery { n, kind, scope })
+}
+
+fn from(date: &[&str], forwards: bool, inclusive: bool) -> NthScope {
+    NthScope::From { date: date.join(" "), forwards, inclusive }
+}
+
+/// "first" -> 1, "3rd" -> 3
+pub fn ordinal(token: &str) -> Option<i32> {
+    let words = ["first", "second", "third", "fourth", "fifth"];
+    if let Some(i) = words.iter().position(|w| *w == token) {
+        return Some(i as i32 + 1);
+    }
+    let digits = token.trim_end_matches(|c: char| c.is_alphabetic());
+    let suffix = &token[digits.len()..];
+    if !matches!(suffix, "st" | "nd" | "rd" | "th") {
+        return None;
+    }
+    digits.parse().ok().filter(|n| *n > 0)
+}
+
+/// The day kind at the start of `tokens` and how many tokens it used
+fn day_kind(tokens: &[&str]) -> Option<(DayKind, usize)> {
+    match tokens {
+        ["business" | "working" | "work", "day" | "days", ..] => Some((DayKind::BusinessDay, 2)),
+        ["weekday" | "weekdays" | "workday" | "workdays", ..] => Some((DayKind::BusinessDay, 1)),
+        ["day" | "days", ..] => Some((DayKind::Day, 1)),
+        [name, ..] => name.trim_end_matches('s').parse().ok().map(|weekday| (DayKind::Weekday(weekday), 1)),
+        [] => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_nth_queries() {
+        let query = parse_nth("3rd Thursday of November 2026").unwrap();
+        assert_eq!(query.n, 3);
+        assert_eq!(query.kind, DayKind::Weekday(Weekday::Thu));
+        assert_eq!(query.scope, NthScope::Period("november 2026".to_string()));
+
+        let query = parse_nth("second-to-last business day of the quarter").unwrap();
+        assert_eq!((query.n, query.kind), (-2, DayKind::BusinessDay));
+
+        let query = parse_nth("last Friday of each month in 2027").unwrap();
+        assert_eq!(query.scope, NthScope::Each { unit: PeriodUnit::Month, within: Some("2027".to_string()) });
+
+        let query = parse_nth("first Monday after the 15th").unwrap();
+        assert_eq!(query.scope, NthScope::From { date: "the 15th".to_string(), forwards: true, inclusive: false });
+    }
+
+    #[test]
+    fn test_parse_nth_errors() {
+        assert!(parse_nth("thursday of november").is_err());
+        assert!(parse_nth("3rd blursday of november").is_err());
+        assert!(parse_nth("2nd tuesday").is_err());
+    }
+}
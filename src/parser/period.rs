@@ -0,0 +1,190 @@
+use anyhow::Result;
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::calendar::fiscal::{parse_month, FiscalCalendar};
+use crate::calendar::period::PeriodUnit;
+
+/// Which end of a period an expression points at
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Anchor {
+    Start,
+    End,
+    /// The period itself, e.g. "next quarter"
+    Whole,
+}
+
+/// A period named by an expression such as "end of this month"
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PeriodMatch {
+    pub anchor: Anchor,
+    pub unit: PeriodUnit,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+impl PeriodMatch {
+    /// The anchored date: the end for "end of", otherwise the start
+    pub fn date(&self) -> NaiveDate {
+        match self.anchor {
+            Anchor::End => self.end,
+            Anchor::Start | Anchor::Whole => self.start,
+        }
+    }
+}
+
+/// Parse a period expression.
+///
+/// Accepts "start of"/"beginning of"/"first day of" or "end of"/"last day of"
+/// followed by "[this|next|last] day/week/iso week/us week/month/quarter/half/year",
+/// a month ("February 2028"), "Q3 [2027]", "H2 [2027]" or a year; the
+/// abbreviations EOD, EOW, EOM, EOQ, EOY and SOW, SOM, SOQ, SOY (or BOW...);
+/// and the bare period forms ("next quarter", "Q3 2027").
+///
+/// Returns `Ok(None)` when the expression is not a period expression.
+/// Fiscal expressions are left to the fiscal parser.
+pub fn parse_period(
+    expression: &str,
+    fiscal: &FiscalCalendar,
+    today: NaiveDate,
+    week_start: Weekday,
+) -> Result<Option<PeriodMatch>> {
+    let lower = expression.trim().to_lowercase().replace(',', " ");
+    let (anchor, body) = match abbreviation(&lower) {
+        Some((anchor, unit)) => (anchor, unit.to_string()),
+        None => {
+            let (anchor, body) = split_anchor(&lower);
+            (anchor, body.to_string())
+        }
+    };
+
+    let tokens: Vec<&str> = body.split_whitespace().filter(|t| *t != "the").collect();
+    if tokens.is_empty() || tokens.iter().any(|t| *t == "fiscal" || t.starts_with("fy")) {
+        return Ok(None);
+    }
+
+    let Some((unit, date, offset)) = period_body(&tokens, today, week_start) else {
+        return Ok(None);
+    };
+    let (start, end) = unit.relative_range(date, offset, fiscal)?;
+    Ok(Some(PeriodMatch { anchor, unit, start, end }))
+}
+
+/// "eom" -> end of this month
+fn abbreviation(expression: &str) -> Option<(Anchor, &'static str)> {
+    let (anchor, unit) = match expression.strip_prefix("eo") {
+        Some(unit) => (Anchor::End, unit),
+        None => (Anchor::Start, expression.strip_prefix("so").or_else(|| expression.strip_prefix("bo"))?),
+    };
+    let unit = match unit {
+        "d" => "day",
+        "w" => "week",
+        "m" => "month",
+        "q" => "quarter",
+        "y" => "year",
+        _ => return None,
+    };
+    Some((anchor, unit))
+}
+
+fn split_anchor(expression: &str) -> (Anchor, &str) {
+    for prefix in ["start of ", "beginning of ", "first day of "] {
+        if let Some(rest) = expression.strip_prefix(prefix) {
+            return (Anchor::Start, rest);
+        }
+    }
+    for prefix in ["end of ", "last day of "] {
+        if let Some(rest) = expression.strip_prefix(prefix) {
+            return (Anchor::End, rest);
+        }
+    }
+    (Anchor::Whole, expression)
+}
+
+/// The unit, a date inside the period, and how many units to move
+fn period_body(tokens: &[&str], today: NaiveDate, week_start: Weekday) -> Option<(PeriodUnit, NaiveDate, i32)> {
+    let year_at = |index: usize| match tokens.get(index) {
+        None => Some(today.year()),
+        Some(token) if token.len() == 4 => token.parse().ok(),
+        Some(_) => None,
+    };
+    let first = tokens[0];
+
+    // Q3 [2027], H2 [2027], February [2028], 2028
+    if tokens.len() <= 2 {
+        let numbered = |prefix: char, count: u32| {
+            first
+                .strip_prefix(prefix)
+                .and_then(|n| n.parse::<u32>().ok())
+                .filter(|n| (1..=count).contains(n))
+        };
+        if let Some(quarter) = numbered('q', 4) {
+            let date = NaiveDate::from_ymd_opt(year_at(1)?, (quarter - 1) * 3 + 1, 1)?;
+            return Some((PeriodUnit::Quarter, date, 0));
+        }
+        if let Some(half) = numbered('h', 2) {
+            let date = NaiveDate::from_ymd_opt(year_at(1)?, (half - 1) * 6 + 1, 1)?;
+            return Some((PeriodUnit::Half, date, 0));
+        }
+        if first.chars().all(|c| c.is_alphabetic()) {
+            if let Some(month) = parse_month(first) {
+                let date = NaiveDate::from_ymd_opt(year_at(1)?, month, 1)?;
+                return Some((PeriodUnit::Month, date, 0));
+            }
+        }
+        if tokens.len() == 1 && first.len() == 4 && first.chars().all(|c| c.is_ascii_digit()) {
+            let date = NaiveDate::from_ymd_opt(first.parse().ok()?, 1, 1)?;
+            return Some((PeriodUnit::Year, date, 0));
+        }
+    }
+
+    // [this|next|last] [iso|us] unit
+    let (offset, rest) = match first {
+        "this" | "current" => (0, &tokens[1..]),
+        "next" => (1, &tokens[1..]),
+        "last" | "previous" | "prior" => (-1, &tokens[1..]),
+        _ => (0, tokens),
+    };
+    let name = rest.join("_");
+    let unit = match name.as_str() {
+        "day" | "week" | "iso_week" | "us_week" | "month" | "quarter" | "half" | "year" => {
+            PeriodUnit::from_name(&name, week_start).ok()?
+        }
+        _ => return None,
+    };
+    Some((unit, today, offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn anchored(expression: &str) -> Option<NaiveDate> {
+        let fiscal = FiscalCalendar::from_name("calendar").unwrap();
+        parse_period(expression, &fiscal, date(2026, 10, 18), Weekday::Mon)
+            .unwrap()
+            .map(|period| period.date())
+    }
+
+    #[test]
+    fn test_parse_period_anchors() {
+        assert_eq!(anchored("end of this month"), Some(date(2026, 10, 31)));
+        assert_eq!(anchored("start of next quarter"), Some(date(2027, 1, 1)));
+        assert_eq!(anchored("last day of February 2028"), Some(date(2028, 2, 29)));
+        assert_eq!(anchored("beginning of Q3"), Some(date(2026, 7, 1)));
+        assert_eq!(anchored("EOW"), Some(date(2026, 10, 18)));
+        assert_eq!(anchored("end of last us week"), Some(date(2026, 10, 17)));
+        assert_eq!(anchored("end of H1 2027"), Some(date(2027, 6, 30)));
+    }
+
+    #[test]
+    fn test_parse_period_non_periods() {
+        assert_eq!(anchored("next friday"), None);
+        assert_eq!(anchored("end of fiscal Q3"), None);
+        assert_eq!(anchored("tomorrow"), None);
+        assert_eq!(anchored("march 3"), None);
+    }
+}
@@ -0,0 +1,52 @@
+use chrono::{NaiveDate, Weekday};
+
+/// Parse an ISO 8601 week date: "2026-W43-4", "2026W434", "2026-W43" or
+/// "2026W43". A missing weekday means Monday.
+pub fn parse_iso_week_date(expression: &str) -> Option<NaiveDate> {
+    let upper = expression.trim().to_uppercase();
+    let (year, rest) = upper.split_once('W')?;
+    let year: i32 = year.strip_suffix('-').unwrap_or(year).parse().ok()?;
+
+    let (week, day) = match rest.split_once('-') {
+        Some((week, day)) => (week, Some(day)),
+        // Slicing by bytes needs ASCII digits
+        None if rest.len() == 3 && rest.bytes().all(|b| b.is_ascii_digit()) => (&rest[..2], Some(&rest[2..])),
+        None => (rest, None),
+    };
+
+    if week.len() != 2 {
+        return None;
+    }
+    let week: u32 = week.parse().ok()?;
+    let weekday = match day {
+        Some(day) => match day.parse::<u8>().ok()? {
+            1 => Weekday::Mon,
+            2 => Weekday::Tue,
+            3 => Weekday::Wed,
+            4 => Weekday::Thu,
+            5 => Weekday::Fri,
+            6 => Weekday::Sat,
+            7 => Weekday::Sun,
+            _ => return None,
+        },
+        None => Weekday::Mon,
+    };
+
+    NaiveDate::from_isoywd_opt(year, week, weekday)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_iso_week_date() {
+        let expected = NaiveDate::from_ymd_opt(2026, 10, 22);
+        assert_eq!(parse_iso_week_date("2026-W43-4"), expected);
+        assert_eq!(parse_iso_week_date("2026w434"), expected);
+        assert_eq!(parse_iso_week_date("2026-W43"), NaiveDate::from_ymd_opt(2026, 10, 19));
+        assert_eq!(parse_iso_week_date("2026-W54-1"), None);
+        assert_eq!(parse_iso_week_date("next week"), None);
+        assert_eq!(parse_iso_week_date("2026W1é"), None);
+    }
+}
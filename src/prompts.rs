@@ -0,0 +1,191 @@
+use std::collections::BTreeMap;
+
+use crate::types::UcmError;
+
+/// An argument a prompt template accepts
+#[derive(Debug, Clone, Copy)]
+pub struct PromptArg {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub required: bool,
+}
+
+/// A reusable workflow that tells the model which ucm_* tools to call, in order
+#[derive(Debug, Clone, Copy)]
+pub struct PromptTemplate {
+    pub name: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+    pub arguments: &'static [PromptArg],
+}
+
+const fn required(name: &'static str, description: &'static str) -> PromptArg {
+    PromptArg { name, description, required: true }
+}
+
+const fn optional(name: &'static str, description: &'static str) -> PromptArg {
+    PromptArg { name, description, required: false }
+}
+
+pub const PROMPTS: [PromptTemplate; 4] = [
+    PromptTemplate {
+        name: "schedule-across-timezones",
+        title: "Schedule across time zones",
+        description: "Find the local time of a meeting for participants in several time zones",
+        arguments: &[
+            required("datetime", "Meeting date and time, e.g. 'next tuesday 15:00'"),
+            required("timezone", "Organizer's IANA time zone, e.g. 'America/New_York'"),
+            required("participant_timezones", "Comma-separated IANA time zones of the participants"),
+        ],
+    },
+    PromptTemplate {
+        name: "deadline-from-business-days",
+        title: "Deadline from business days",
+        description: "Count a number of business days from a start date, skipping weekends and public holidays",
+        arguments: &[
+            required("start", "Start date, e.g. 'today' or '2026-10-19'"),
+            required("business_days", "Number of business days to add"),
+            optional("country", "Holiday calendar: US, GB, CA or AU (default US)"),
+        ],
+    },
+    PromptTemplate {
+        name: "age-and-anniversaries",
+        title: "Age and anniversaries",
+        description: "Work out someone's exact age and their upcoming birthdays or anniversaries",
+        arguments: &[
+            required("date", "Birth date or anniversary date"),
+            optional("as_of", "Date to measure the age at (default today)"),
+        ],
+    },
+    PromptTemplate {
+        name: "countdown",
+        title: "Countdown",
+        description: "Count down to an event in days, weeks and months",
+        arguments: &[
+            required("event", "Name of the event"),
+            required("date", "Date of the event"),
+        ],
+    },
+];
+
+/// Look up a prompt template by name
+pub fn find_prompt(name: &str) -> Option<&'static PromptTemplate> {
+    PROMPTS.iter().find(|prompt| prompt.name == name)
+}
+
+/// Render a prompt template with its arguments into the user message text
+pub fn render_prompt(name: &str, arguments: &BTreeMap<String, String>) -> Result<String, UcmError> {
+    let prompt = find_prompt(name).ok_or_else(|| UcmError::unknown_prompt(name))?;
+
+    for arg in prompt.arguments.iter().filter(|arg| arg.required) {
+        if arguments.get(arg.name).is_none_or(|value| value.trim().is_empty()) {
+            return Err(UcmError::missing_parameter(
+                arg.name,
+                &format!("Prompt '{}' requires '{}': {}", name, arg.name, arg.description),
+            ));
+        }
+    }
+
+    let arg = |key: &str, default: &str| -> String {
+        arguments
+            .get(key)
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+            .unwrap_or(default)
+            .to_string()
+    };
+
+    let text = match prompt.name {
+        "schedule-across-timezones" => format!(
+            "Schedule a meeting at {datetime} ({timezone}) for participants in: {participants}.\n\n\
+             1. Call ucm_now to establish the current date.\n\
+             2. Call ucm_parse with '{datetime}' to resolve the meeting date.\n\
+             3. Read the ucm://timezones resource and look up the UTC offsets of {timezone} and \
+             of each participant zone.\n\
+             4. Convert the meeting time to each participant's local time, noting any that fall \
+             on a different calendar day.\n\
+             5. For each participant zone, call ucm_sun with that timezone to flag meetings \
+             that fall before sunrise or after sunset.\n\n\
+             Present a table of participant zone, local date, local time and day of week.",
+            datetime = arg("datetime", ""),
+            timezone = arg("timezone", ""),
+            participants = arg("participant_timezones", ""),
+        ),
+        "deadline-from-business-days" => format!(
+            "Find the date {days} business days after {start}, using the {country} holiday calendar.\n\n\
+             1. Call ucm_parse with '{start}' to resolve the start date.\n\
+             2. Read ucm://holidays/{country}/{{year}} for the start year, and the following year \
+             if the deadline may cross into it. Use the observed date when a holiday has one.\n\
+             3. Call ucm_add with '{days} days' for a first estimate, then walk forward with \
+             ucm_info, skipping days where is_weekend is true or that are holidays, until \
+             {days} business days have been counted.\n\
+             4. Call ucm_diff from the start date to the deadline to report calendar days elapsed.\n\n\
+             Report the deadline date, its day of week, and every holiday that was skipped.",
+            days = arg("business_days", ""),
+            start = arg("start", ""),
+            country = arg("country", "US").to_uppercase(),
+        ),
+        "age-and-anniversaries" => format!(
+            "Work out the age and upcoming anniversaries for {date}, as of {as_of}.\n\n\
+             1. Call ucm_age with date '{date}' and as_of '{as_of}'; years and breakdown give \
+             the exact age, total_days the age in days, and next_anniversary the next birthday \
+             with its day of week. Ask which leap_day policy applies if the date is 29 February.\n\
+             2. For the next decade birthday, call ucm_age again with turns set to that age.\n\
+             3. For the 10,000-day milestone, call ucm_add on {date} with '10000 days'.\n\n\
+             Report the exact age, the next anniversary, and the next milestone.",
+            date = arg("date", ""),
+            as_of = arg("as_of", "today"),
+        ),
+        "countdown" => format!(
+            "Count down to {event} on {date}.\n\n\
+             1. Call ucm_now to establish the current date and time.\n\
+             2. Call ucm_parse with '{date}' to resolve the event date; if is_past is true, say \
+             how long ago it was instead.\n\
+             3. Call ucm_diff from 'today' to the event date for total days and the \
+             months/weeks/days breakdown.\n\
+             4. Call ucm_convert with the total days and unit 'days' for hours, minutes and weeks.\n\
+             5. Call ucm_info on the event date for its day of week and whether it is a weekend.\n\n\
+             Report the countdown in the most natural unit first, then the detail. If the \
+             event will come up again, offer to save it with ucm_mark_set so later \
+             conversations can refer to it as '@name'.",
+            event = arg("event", ""),
+            date = arg("date", ""),
+        ),
+        _ => unreachable!("every prompt in PROMPTS has a template"),
+    };
+
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ErrorKind;
+
+    fn args(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_every_prompt_renders() {
+        for prompt in PROMPTS {
+            let all: Vec<(&str, &str)> = prompt.arguments.iter().map(|a| (a.name, "x")).collect();
+            let text = render_prompt(prompt.name, &args(&all)).unwrap();
+            assert!(text.contains("ucm_"), "{} names no tools", prompt.name);
+        }
+    }
+
+    #[test]
+    fn test_prompt_defaults_and_errors() {
+        let text = render_prompt(
+            "deadline-from-business-days",
+            &args(&[("start", "2026-12-22"), ("business_days", "5")]),
+        )
+        .unwrap();
+        assert!(text.contains("ucm://holidays/US/{year}"));
+
+        let error = render_prompt("countdown", &args(&[("event", "Launch")])).unwrap_err();
+        assert_eq!(error.error, ErrorKind::MissingParameter);
+        assert_eq!(render_prompt("weather", &args(&[])).unwrap_err().error, ErrorKind::UnknownPrompt);
+    }
+}
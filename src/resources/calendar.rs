@@ -0,0 +1,72 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::types::{CalendarMonthResponse, CalendarWeek, UcmError};
+
+/// Month grid laid out in ISO weeks (Monday first)
+pub fn calendar_month(year: i32, month: u32) -> Result<CalendarMonthResponse, UcmError> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1).ok_or_else(|| {
+        UcmError::out_of_range(&format!("{}-{}", year, month), "Invalid year or month")
+    })?;
+    let days_in_month = first
+        .checked_add_months(chrono::Months::new(1))
+        .map(|next| (next - first).num_days() as u32)
+        .unwrap_or(31);
+
+    let lead = first.weekday().num_days_from_monday();
+    let mut weeks: Vec<CalendarWeek> = Vec::new();
+    for (i, day) in (1..=days_in_month).enumerate() {
+        let slot = (lead as usize + i) % 7;
+        if slot == 0 || weeks.is_empty() {
+            let date = first.with_day(day).unwrap();
+            weeks.push(CalendarWeek {
+                iso_week: date.iso_week().week(),
+                days: vec![None; 7],
+            });
+        }
+        weeks.last_mut().unwrap().days[slot] = Some(day);
+    }
+
+    let title = first.format("%B %Y").to_string();
+    let mut grid = format!("{:^20}\nMo Tu We Th Fr Sa Su\n", title);
+    for week in &weeks {
+        let line: Vec<String> = week
+            .days
+            .iter()
+            .map(|day| day.map(|d| format!("{:>2}", d)).unwrap_or_else(|| "  ".to_string()))
+            .collect();
+        grid.push_str(line.join(" ").trim_end());
+        grid.push('\n');
+    }
+
+    Ok(CalendarMonthResponse {
+        year,
+        month,
+        month_name: first.format("%B").to_string(),
+        days_in_month,
+        first_day_of_week: first.format("%A").to_string(),
+        weeks,
+        grid,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calendar_month_grid() {
+        let october = calendar_month(2026, 10).unwrap();
+        assert_eq!(october.days_in_month, 31);
+        assert_eq!(october.first_day_of_week, "Thursday");
+        assert_eq!(october.weeks.len(), 5);
+        assert_eq!(october.weeks[0].iso_week, 40);
+        assert_eq!(october.weeks[0].days, vec![None, None, None, Some(1), Some(2), Some(3), Some(4)]);
+        assert!(october.grid.contains("Mo Tu We Th Fr Sa Su\n          1  2  3  4\n 5  6"));
+    }
+
+    #[test]
+    fn test_calendar_february_leap_year() {
+        assert_eq!(calendar_month(2028, 2).unwrap().days_in_month, 29);
+        assert_eq!(calendar_month(2026, 2).unwrap().days_in_month, 28);
+    }
+}
@@ -0,0 +1,27 @@
+use crate::calendar::holidays::find_country;
+use crate::types::{HolidayInfo, HolidaysResponse, UcmError};
+
+/// Public holidays for a country and year
+pub fn holidays(country: &str, year: i32) -> Result<HolidaysResponse, UcmError> {
+    let calendar = find_country(country)
+        .map_err(|e| UcmError::invalid_calendar(country, &e.to_string()))?;
+
+    let holidays: Vec<HolidayInfo> = calendar
+        .holidays(year)
+        .into_iter()
+        .map(|holiday| HolidayInfo {
+            name: holiday.name.to_string(),
+            date: holiday.date.format("%Y-%m-%d").to_string(),
+            day_of_week: holiday.date.format("%A").to_string(),
+            observed: holiday.observed.map(|d| d.format("%Y-%m-%d").to_string()),
+        })
+        .collect();
+
+    Ok(HolidaysResponse {
+        country: calendar.code.to_string(),
+        country_name: calendar.name.to_string(),
+        year,
+        count: holidays.len(),
+        holidays,
+    })
+}
@@ -0,0 +1,142 @@
+pub mod calendar;
+pub mod holidays;
+pub mod timezones;
+
+use chrono::{Datelike, NaiveDate, Utc};
+use serde::Serialize;
+
+use crate::calendar::holidays::COUNTRIES;
+use crate::engine::Ucm;
+use crate::tools::ucm_now;
+use crate::types::UcmError;
+
+pub use calendar::calendar_month;
+pub use holidays::holidays;
+pub use timezones::timezones;
+
+pub const MIME_TYPE: &str = "application/json";
+
+/// A concrete resource a client can read
+#[derive(Debug, Clone)]
+pub struct ResourceInfo {
+    pub uri: String,
+    pub name: String,
+    pub description: String,
+}
+
+/// A parameterised resource URI (RFC 6570 template)
+#[derive(Debug, Clone, Copy)]
+pub struct TemplateInfo {
+    pub uri_template: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+pub const TEMPLATES: [TemplateInfo; 2] = [
+    TemplateInfo {
+        uri_template: "ucm://calendar/{year}/{month}",
+        name: "calendar_month",
+        description: "Month grid with ISO week numbers, e.g. ucm://calendar/2026/10",
+    },
+    TemplateInfo {
+        uri_template: "ucm://holidays/{country}/{year}",
+        name: "holidays",
+        description: "Public holidays with observed dates for US, GB (UK), CA or AU, e.g. ucm://holidays/US/2026",
+    },
+];
+
+/// Resources listed for the current date: now, time zones, this month's
+/// calendar and this year's holidays for each supported country
+pub fn resources(today: NaiveDate) -> Vec<ResourceInfo> {
+    let mut list = vec![
+        ResourceInfo {
+            uri: "ucm://now".to_string(),
+            name: "now".to_string(),
+            description: "Current date, time, time zone, ISO week and quarter".to_string(),
+        },
+        ResourceInfo {
+            uri: "ucm://timezones".to_string(),
+            name: "timezones".to_string(),
+            description: "IANA time zone ids with their current UTC offsets".to_string(),
+        },
+        ResourceInfo {
+            uri: format!("ucm://calendar/{}/{}", today.year(), today.month()),
+            name: format!("calendar_{}_{:02}", today.year(), today.month()),
+            description: format!("Month grid for {}", today.format("%B %Y")),
+        },
+    ];
+
+    list.extend(COUNTRIES.iter().map(|country| ResourceInfo {
+        uri: format!("ucm://holidays/{}/{}", country.code, today.year()),
+        name: format!("holidays_{}_{}", country.code.to_lowercase(), today.year()),
+        description: format!("{} public holidays in {}", country.name, today.year()),
+    }));
+
+    list
+}
+
+fn parse_year(segment: &str) -> Result<i32, UcmError> {
+    segment
+        .parse::<i32>()
+        .ok()
+        .filter(|year| (1..=9999).contains(year))
+        .ok_or_else(|| UcmError::out_of_range(segment, "Year must be between 1 and 9999"))
+}
+
+fn parse_month(segment: &str) -> Result<u32, UcmError> {
+    segment
+        .parse::<u32>()
+        .ok()
+        .filter(|month| (1..=12).contains(month))
+        .ok_or_else(|| UcmError::out_of_range(segment, "Month must be between 1 and 12"))
+}
+
+fn to_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string_pretty(value).unwrap()
+}
+
+/// Read a ucm:// resource and return its JSON content
+pub fn read_resource(ucm: &Ucm, uri: &str) -> Result<String, UcmError> {
+    let path = uri
+        .strip_prefix("ucm://")
+        .ok_or_else(|| UcmError::unknown_resource(uri))?;
+    let segments: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+
+    match segments.as_slice() {
+        ["now"] => ucm_now(ucm, None).map(|response| to_json(&response)),
+        ["timezones"] => Ok(to_json(&timezones(ucm.now().with_timezone(&Utc)))),
+        ["calendar", year, month] => {
+            calendar_month(parse_year(year)?, parse_month(month)?).map(|response| to_json(&response))
+        }
+        ["holidays", country, year] => {
+            holidays(country, parse_year(year)?).map(|response| to_json(&response))
+        }
+        _ => Err(UcmError::unknown_resource(uri)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ErrorKind;
+
+    #[test]
+    fn test_resources_list() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        let list = resources(today);
+        assert!(list.iter().any(|r| r.uri == "ucm://calendar/2026/10"));
+        assert!(list.iter().any(|r| r.uri == "ucm://holidays/US/2026"));
+    }
+
+    #[test]
+    fn test_read_resource() {
+        let ucm = Ucm::new().at_reference(Some("2026-10-19T09:30:00+00:00")).unwrap();
+        let calendar = read_resource(&ucm, "ucm://calendar/2026/10").unwrap();
+        assert!(calendar.contains("\"month_name\": \"October\""));
+        assert!(read_resource(&ucm, "ucm://now").is_ok());
+
+        assert_eq!(read_resource(&ucm, "ucm://calendar/2026/13").unwrap_err().error, ErrorKind::OutOfRange);
+        assert_eq!(read_resource(&ucm, "ucm://weather").unwrap_err().error, ErrorKind::UnknownResource);
+        assert_eq!(read_resource(&ucm, "file:///etc/passwd").unwrap_err().error, ErrorKind::UnknownResource);
+    }
+}
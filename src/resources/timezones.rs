@@ -0,0 +1,43 @@
+use chrono::{DateTime, Offset, Utc};
+use chrono_tz::TZ_VARIANTS;
+
+use crate::types::{TimezoneInfo, TimezonesResponse};
+
+/// All IANA time zones with their UTC offsets at `now`
+pub fn timezones(now: DateTime<Utc>) -> TimezonesResponse {
+    let mut timezones: Vec<TimezoneInfo> = TZ_VARIANTS
+        .iter()
+        .map(|tz| {
+            let local = now.with_timezone(tz);
+            TimezoneInfo {
+                id: tz.name().to_string(),
+                abbreviation: local.format("%Z").to_string(),
+                utc_offset: local.offset().fix().to_string(),
+            }
+        })
+        .collect();
+    timezones.sort_by(|a, b| a.id.cmp(&b.id));
+
+    TimezonesResponse {
+        as_of: now.to_rfc3339(),
+        count: timezones.len(),
+        timezones,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timezones_include_utc() {
+        let now = DateTime::parse_from_rfc3339("2026-01-15T12:00:00Z").unwrap().with_timezone(&Utc);
+        let response = timezones(now);
+        let offset = |id: &str| response.timezones.iter().find(|tz| tz.id == id).unwrap().utc_offset.clone();
+        assert_eq!(offset("UTC"), "+00:00");
+        // Offsets are as of the reference time, so January is standard time
+        assert_eq!(offset("America/New_York"), "-05:00");
+        assert_eq!(response.as_of, "2026-01-15T12:00:00+00:00");
+        assert!(response.count > 300);
+    }
+}
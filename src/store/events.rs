@@ -0,0 +1,90 @@
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use super::{read_json, update_json};
+use crate::types::UcmError;
+
+/// A calendar event; times are local to `timezone`, or to the engine's zone
+/// when it has none
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Event {
+    pub id: String,
+    pub title: String,
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+    #[serde(default)]
+    pub all_day: bool,
+    /// IANA time zone name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// RRULE value, e.g. "FREQ=WEEKLY;BYDAY=TU"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct EventFile {
+    #[serde(default)]
+    last_id: u64,
+    events: Vec<Event>,
+}
+
+/// Events kept in `events.json` in the data directory; changes hold
+/// `events.lock`
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventStore {
+    path: PathBuf,
+}
+
+impl EventStore {
+    pub fn in_dir(dir: &Path) -> Self {
+        Self { path: dir.join("events.json") }
+    }
+
+    /// All events by start time
+    pub fn list(&self) -> Result<Vec<Event>, UcmError> {
+        let mut events = read_json::<EventFile>(&self.path)?.events;
+        events.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.id.cmp(&b.id)));
+        Ok(events)
+    }
+
+    pub fn get(&self, id: &str) -> Result<Event, UcmError> {
+        self.list()?.into_iter().find(|event| event.id == id).ok_or_else(|| unknown(id))
+    }
+
+    /// Save a new event under the next free id ("evt-1", "evt-2", ...)
+    pub fn create(&self, mut event: Event) -> Result<Event, UcmError> {
+        update_json(&self.path, |file: &mut EventFile| {
+            file.last_id += 1;
+            event.id = format!("evt-{}", file.last_id);
+            file.events.push(event.clone());
+            Ok(event)
+        })
+    }
+
+    /// Replace the event with the same id
+    pub fn update(&self, event: Event) -> Result<(), UcmError> {
+        update_json(&self.path, |file: &mut EventFile| {
+            let slot = file.events.iter_mut().find(|e| e.id == event.id).ok_or_else(|| unknown(&event.id))?;
+            *slot = event;
+            Ok(())
+        })
+    }
+
+    pub fn delete(&self, id: &str) -> Result<Event, UcmError> {
+        update_json(&self.path, |file: &mut EventFile| {
+            let index = file.events.iter().position(|e| e.id == id).ok_or_else(|| unknown(id))?;
+            Ok(file.events.remove(index))
+        })
+    }
+}
+
+fn unknown(id: &str) -> UcmError {
+    UcmError::invalid_reference(id, &format!("No event has id '{}'; use ucm_event_list to find events", id))
+}
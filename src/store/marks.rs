@@ -0,0 +1,121 @@
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::{read_json, update_json};
+use crate::types::UcmError;
+
+const MAX_NAME_LENGTH: usize = 64;
+
+/// A named date, referenced as `@name` in date expressions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mark {
+    pub name: String,
+    pub date: NaiveDate,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct MarkFile {
+    marks: Vec<Mark>,
+}
+
+/// Named dates kept in `marks.json` in the data directory; changes hold
+/// `marks.lock`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkStore {
+    path: PathBuf,
+}
+
+impl MarkStore {
+    pub fn in_dir(dir: &Path) -> Self {
+        Self { path: dir.join("marks.json") }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// All marks, soonest first
+    pub fn list(&self) -> Result<Vec<Mark>, UcmError> {
+        let mut marks = read_json::<MarkFile>(&self.path)?.marks;
+        marks.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.name.cmp(&b.name)));
+        Ok(marks)
+    }
+
+    pub fn get(&self, name: &str) -> Result<Mark, UcmError> {
+        let key = normalize_name(name)?;
+        self.list()?.into_iter().find(|mark| mark.name == key).ok_or_else(|| unknown(name))
+    }
+
+    /// Save a mark, returning the one it replaced
+    pub fn set(&self, mut mark: Mark) -> Result<Option<Mark>, UcmError> {
+        mark.name = normalize_name(&mark.name)?;
+        update_json(&self.path, |file: &mut MarkFile| {
+            let previous = file.marks.iter().position(|m| m.name == mark.name).map(|i| file.marks.remove(i));
+            file.marks.push(mark);
+            Ok(previous)
+        })
+    }
+
+    pub fn delete(&self, name: &str) -> Result<Mark, UcmError> {
+        let key = normalize_name(name)?;
+        update_json(&self.path, |file: &mut MarkFile| {
+            let index = file.marks.iter().position(|m| m.name == key).ok_or_else(|| unknown(name))?;
+            Ok(file.marks.remove(index))
+        })
+    }
+}
+
+fn unknown(name: &str) -> UcmError {
+    UcmError::invalid_reference(
+        name,
+        &format!("No date is saved as '@{}'; use ucm_mark_list to see saved dates", name.trim_start_matches('@')),
+    )
+}
+
+/// Lowercase a mark name, dropping a leading '@'; names use letters,
+/// digits, '_' and '-'
+pub fn normalize_name(name: &str) -> Result<String, UcmError> {
+    let name = name.trim().trim_start_matches('@').to_lowercase();
+    let valid = !name.is_empty()
+        && name.len() <= MAX_NAME_LENGTH
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-');
+    if valid {
+        Ok(name)
+    } else {
+        Err(UcmError::parse_error(
+            &name,
+            &format!("Mark names are 1-{} letters, digits, '_' or '-', e.g. 'launch'", MAX_NAME_LENGTH),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ErrorKind;
+
+    #[test]
+    fn test_mark_store_round_trip() {
+        let dir = std::env::temp_dir().join(format!("ucm-marks-{}", std::process::id()));
+        let store = MarkStore::in_dir(&dir);
+        let date = |d: u32| NaiveDate::from_ymd_opt(2026, 12, d).unwrap();
+
+        assert_eq!(store.set(Mark { name: "@Launch".to_string(), date: date(1), note: None }).unwrap(), None);
+        store.set(Mark { name: "freeze".to_string(), date: date(20), note: Some("code freeze".to_string()) }).unwrap();
+        let replaced = store.set(Mark { name: "launch".to_string(), date: date(24), note: None }).unwrap();
+        assert_eq!(replaced.map(|m| m.date), Some(date(1)));
+
+        let names: Vec<String> = store.list().unwrap().into_iter().map(|m| m.name).collect();
+        assert_eq!(names, ["freeze", "launch"]);
+        assert_eq!(store.get("@LAUNCH").unwrap().date, date(24));
+
+        store.delete("launch").unwrap();
+        assert_eq!(store.get("launch").unwrap_err().error, ErrorKind::InvalidReference);
+        assert_eq!(normalize_name("two words").unwrap_err().error, ErrorKind::ParseError);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
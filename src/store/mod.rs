@@ -0,0 +1,81 @@
+//! Small JSON files under the data directory for state kept between
+//! conversations.
+
+pub mod events;
+pub mod marks;
+pub mod reminders;
+
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+pub use events::{Event, EventStore};
+pub use marks::{Mark, MarkStore};
+pub use reminders::{Reminder, ReminderState, ReminderStore};
+
+use crate::types::UcmError;
+
+/// `$XDG_DATA_HOME/ucm`, `~/.local/share/ucm`, or `%APPDATA%\ucm` on Windows
+pub fn default_data_dir() -> Option<PathBuf> {
+    let from_env = |name: &str| std::env::var_os(name).filter(|value| !value.is_empty()).map(PathBuf::from);
+    if cfg!(windows) {
+        return from_env("APPDATA").map(|dir| dir.join("ucm"));
+    }
+    from_env("XDG_DATA_HOME")
+        .or_else(|| from_env("HOME").map(|home| home.join(".local").join("share")))
+        .map(|dir| dir.join("ucm"))
+}
+
+fn storage_error(path: &Path, action: &str, error: impl std::fmt::Display) -> UcmError {
+    UcmError::storage_error(&path.display().to_string(), &format!("Could not {} {}: {}", action, path.display(), error))
+}
+
+/// Read a JSON file, or the default value when it does not exist yet
+pub fn read_json<T: DeserializeOwned + Default>(path: &Path) -> Result<T, UcmError> {
+    match fs::read_to_string(path) {
+        Ok(text) => serde_json::from_str(&text).map_err(|e| storage_error(path, "read", e)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(T::default()),
+        Err(e) => Err(storage_error(path, "read", e)),
+    }
+}
+
+/// Write a JSON file, creating its directory; the file is replaced in one
+/// step so readers never see a partial write
+pub fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), UcmError> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| storage_error(dir, "create", e))?;
+    }
+    let text = serde_json::to_string_pretty(value).map_err(|e| storage_error(path, "write", e))?;
+    // A temp file of its own, so concurrent writers never rename each other's
+    static WRITES: AtomicU64 = AtomicU64::new(0);
+    let temp = path.with_extension(format!("json.{}-{}.tmp", std::process::id(), WRITES.fetch_add(1, Ordering::Relaxed)));
+    fs::write(&temp, text).map_err(|e| storage_error(&temp, "write", e))?;
+    fs::rename(&temp, path).map_err(|e| storage_error(path, "write", e))
+}
+
+/// Read, change and write a JSON file while holding a lock file beside it,
+/// since several sessions and processes change the same files
+pub fn update_json<T, R>(path: &Path, change: impl FnOnce(&mut T) -> Result<R, UcmError>) -> Result<R, UcmError>
+where
+    T: Serialize + DeserializeOwned + Default,
+{
+    let lock_path = path.with_extension("lock");
+    if let Some(dir) = lock_path.parent() {
+        fs::create_dir_all(dir).map_err(|e| storage_error(dir, "create", e))?;
+    }
+    let lock = File::options()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&lock_path)
+        .map_err(|e| storage_error(&lock_path, "open", e))?;
+    lock.lock().map_err(|e| storage_error(&lock_path, "lock", e))?;
+
+    let mut value = read_json(path)?;
+    let result = change(&mut value)?;
+    write_json(path, &value)?;
+    Ok(result)
+}
@@ -0,0 +1,192 @@
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Duration, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+use super::{read_json, update_json};
+use crate::types::UcmError;
+
+/// How long delivered reminders are kept before they are pruned
+const KEEP_DELIVERED_DAYS: i64 = 30;
+
+/// Where a reminder is in delivery
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReminderState {
+    #[default]
+    Pending,
+    Delivered,
+    /// Fell due with no client connected to receive it; sent to the next one
+    Missed,
+}
+
+/// A message to deliver at a point in time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Reminder {
+    pub id: String,
+    pub message: String,
+    pub due: DateTime<FixedOffset>,
+    pub created: DateTime<FixedOffset>,
+    #[serde(default)]
+    pub state: ReminderState,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ReminderFile {
+    #[serde(default)]
+    last_id: u64,
+    reminders: Vec<Reminder>,
+}
+
+/// Reminders kept in `reminders.json` in the data directory; changes hold
+/// `reminders.lock`, since several sessions and processes deliver from it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReminderStore {
+    path: PathBuf,
+}
+
+impl ReminderStore {
+    pub fn in_dir(dir: &Path) -> Self {
+        Self { path: dir.join("reminders.json") }
+    }
+
+    /// Every reminder, soonest first
+    pub fn list(&self) -> Result<Vec<Reminder>, UcmError> {
+        let mut reminders = read_json::<ReminderFile>(&self.path)?.reminders;
+        reminders.sort_by(|a, b| a.due.cmp(&b.due).then_with(|| a.id.cmp(&b.id)));
+        Ok(reminders)
+    }
+
+    /// Save a new reminder under the next free id ("rem-1", "rem-2", ...)
+    pub fn create(&self, mut reminder: Reminder) -> Result<Reminder, UcmError> {
+        self.update(|file| {
+            file.last_id += 1;
+            reminder.id = format!("rem-{}", file.last_id);
+            file.reminders.push(reminder.clone());
+            Ok(reminder)
+        })
+    }
+
+    /// Remove a reminder when it is cancelled
+    pub fn delete(&self, id: &str) -> Result<Reminder, UcmError> {
+        self.update(|file| {
+            let index = file.reminders.iter().position(|r| r.id == id).ok_or_else(|| {
+                UcmError::invalid_reference(id, &format!("No reminder has id '{}'; use ucm_reminders to list them", id))
+            })?;
+            Ok(file.reminders.remove(index))
+        })
+    }
+
+    /// Take the reminders to send at `now`, marking them delivered so no
+    /// other session sends them too: pending ones that are due, and missed
+    /// ones. Without a client to `deliver` to, due ones are marked missed
+    /// instead. The reminders are returned as they were before.
+    pub fn claim_due(&self, now: DateTime<FixedOffset>, deliver: bool) -> Result<Vec<Reminder>, UcmError> {
+        self.update(|file| {
+            let mut claimed = Vec::new();
+            for reminder in &mut file.reminders {
+                let due = match reminder.state {
+                    ReminderState::Pending => reminder.due <= now,
+                    ReminderState::Missed => deliver,
+                    ReminderState::Delivered => false,
+                };
+                if !due {
+                    continue;
+                }
+                if deliver {
+                    claimed.push(reminder.clone());
+                    reminder.state = ReminderState::Delivered;
+                } else {
+                    reminder.state = ReminderState::Missed;
+                }
+            }
+            let cutoff = now - Duration::days(KEEP_DELIVERED_DAYS);
+            file.reminders.retain(|r| r.state != ReminderState::Delivered || r.due > cutoff);
+            claimed.sort_by(|a, b| a.due.cmp(&b.due).then_with(|| a.id.cmp(&b.id)));
+            Ok(claimed)
+        })
+    }
+
+    /// Record that a claimed reminder reached no client
+    pub fn mark_missed(&self, id: &str) -> Result<(), UcmError> {
+        self.update(|file| {
+            for reminder in file.reminders.iter_mut().filter(|r| r.id == id) {
+                reminder.state = ReminderState::Missed;
+            }
+            Ok(())
+        })
+    }
+
+    fn update<T>(&self, change: impl FnOnce(&mut ReminderFile) -> Result<T, UcmError>) -> Result<T, UcmError> {
+        update_json(&self.path, change)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(text: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(text).unwrap()
+    }
+
+    fn reminder(message: &str, due: &str) -> Reminder {
+        Reminder {
+            id: String::new(),
+            message: message.to_string(),
+            due: at(due),
+            created: at("2026-10-18T09:00:00Z"),
+            state: ReminderState::Pending,
+        }
+    }
+
+    #[test]
+    fn test_claim_due_once_and_missed() {
+        let dir = std::env::temp_dir().join(format!("ucm-reminders-{}", std::process::id()));
+        let store = ReminderStore::in_dir(&dir);
+        store.create(reminder("Stand up", "2026-10-18T09:20:00Z")).unwrap();
+        store.create(reminder("Call the bank", "2026-10-19T08:00:00Z")).unwrap();
+
+        // Nobody connected: the due reminder is missed, the later one stays pending
+        assert!(store.claim_due(at("2026-10-18T10:00:00Z"), false).unwrap().is_empty());
+        let states: Vec<ReminderState> = store.list().unwrap().iter().map(|r| r.state).collect();
+        assert_eq!(states, [ReminderState::Missed, ReminderState::Pending]);
+
+        // The next client gets the missed one, and only one claim sends it
+        let claimed = store.claim_due(at("2026-10-18T10:00:15Z"), true).unwrap();
+        assert_eq!((claimed.len(), claimed[0].state), (1, ReminderState::Missed));
+        assert!(store.claim_due(at("2026-10-18T10:00:30Z"), true).unwrap().is_empty());
+
+        let claimed = store.claim_due(at("2026-10-19T08:00:00Z"), true).unwrap();
+        assert_eq!(claimed[0].message, "Call the bank");
+        store.mark_missed(&claimed[0].id).unwrap();
+        assert_eq!(store.list().unwrap()[1].state, ReminderState::Missed);
+
+        // Delivered reminders are pruned after a while
+        store.claim_due(at("2026-12-01T00:00:00Z"), true).unwrap();
+        assert!(store.list().unwrap().is_empty());
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_concurrent_changes_keep_every_reminder() {
+        let dir = std::env::temp_dir().join(format!("ucm-reminders-lock-{}", std::process::id()));
+        let threads: Vec<_> = (0..8)
+            .map(|n| {
+                let store = ReminderStore::in_dir(&dir);
+                std::thread::spawn(move || {
+                    for _ in 0..5 {
+                        store.create(reminder(&format!("thread {}", n), "2026-10-19T08:00:00Z")).unwrap();
+                    }
+                })
+            })
+            .collect();
+        threads.into_iter().for_each(|thread| thread.join().unwrap());
+
+        let mut ids: Vec<String> = ReminderStore::in_dir(&dir).list().unwrap().into_iter().map(|r| r.id).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), 40);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
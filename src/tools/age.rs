@@ -0,0 +1,117 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::calendar::anniversary::{anniversary, years_completed, LeapDayPolicy};
+use crate::engine::Ucm;
+use crate::types::{AgeResponse, AnniversaryInfo, DurationBreakdown, UcmError};
+
+/// Most upcoming anniversaries returned in one call
+const MAX_ANNIVERSARIES: u32 = 100;
+
+/// Age or tenure from a birth or start date, with upcoming anniversaries
+pub fn ucm_age(
+    ucm: &Ucm,
+    date: &str,
+    as_of: Option<&str>,
+    turns: Option<u32>,
+    leap_day: Option<&str>,
+    anniversaries: Option<u32>,
+) -> Result<AgeResponse, UcmError> {
+    let policy = match leap_day {
+        Some(name) => LeapDayPolicy::from_name(name).map_err(|e| UcmError::parse_error(name, &e.to_string()))?,
+        None => LeapDayPolicy::default(),
+    };
+    let origin = ucm.parse_date(date)?;
+    let as_of_date = ucm.parse_date(as_of.unwrap_or("today"))?;
+    if as_of_date < origin {
+        return Err(UcmError::out_of_range(
+            as_of.unwrap_or("today"),
+            &format!("The as-of date {} is before {}", as_of_date, origin),
+        ));
+    }
+
+    let count = anniversaries.unwrap_or(3);
+    if count > MAX_ANNIVERSARIES {
+        return Err(UcmError::out_of_range(
+            &count.to_string(),
+            &format!("At most {} anniversaries can be listed", MAX_ANNIVERSARIES),
+        ));
+    }
+
+    let anniversary_on = |number: i32| {
+        origin.year().checked_add(number).and_then(|year| anniversary(origin, year, policy)).ok_or_else(|| {
+            UcmError::out_of_range(date, "Anniversary is outside the supported date range")
+        })
+    };
+    let info = |number: i32| anniversary_on(number).map(|date| anniversary_info(number, date, as_of_date));
+
+    let years = years_completed(origin, as_of_date, policy);
+    let last_date = anniversary_on(years)?;
+    let mut breakdown = DurationBreakdown::between_dates(last_date, as_of_date);
+    breakdown.years = years;
+    let months_since = breakdown.months;
+
+    Ok(AgeResponse {
+        date: origin.format("%Y-%m-%d").to_string(),
+        as_of: as_of_date.format("%Y-%m-%d").to_string(),
+        leap_day_policy: policy.name().to_string(),
+        years,
+        breakdown_string: breakdown.to_string_breakdown(),
+        breakdown,
+        total_days: (as_of_date - origin).num_days(),
+        total_months: years as i64 * 12 + months_since as i64,
+        is_anniversary: last_date == as_of_date && years > 0,
+        next_anniversary: info(years + 1)?,
+        turns: turns
+            .map(|age| {
+                let age = i32::try_from(age)
+                    .map_err(|_| UcmError::out_of_range(&age.to_string(), "Anniversary is outside the supported date range"))?;
+                info(age)
+            })
+            .transpose()?,
+        upcoming: (1..=count as i32).map(|k| info(years + k)).collect::<Result<_, _>>()?,
+    })
+}
+
+fn anniversary_info(number: i32, date: NaiveDate, as_of: NaiveDate) -> AnniversaryInfo {
+    AnniversaryInfo {
+        number,
+        date: date.format("%Y-%m-%d").to_string(),
+        day_of_week: date.format("%A").to_string(),
+        days_from_as_of: (date - as_of).num_days(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_age_and_next_birthday() {
+        let ucm = Ucm::new().at_reference(Some("2026-10-18")).unwrap();
+        let result = ucm_age(&ucm, "1961-11-02", None, Some(65), None, Some(2)).unwrap();
+        assert_eq!(result.years, 64);
+        assert_eq!(result.breakdown_string, "64 years, 11 months, 2 weeks, 2 days");
+        assert_eq!(result.next_anniversary.date, "2026-11-02");
+        assert_eq!(result.next_anniversary.days_from_as_of, 15);
+        assert_eq!(result.turns.unwrap().date, "2026-11-02");
+        assert_eq!(result.upcoming.len(), 2);
+    }
+
+    #[test]
+    fn test_age_leap_day_policy() {
+        let ucm = Ucm::new();
+        let feb = ucm_age(&ucm, "2008-02-29", Some("2026-02-28"), None, None, None).unwrap();
+        assert_eq!((feb.years, feb.is_anniversary), (18, true));
+
+        let mar = ucm_age(&ucm, "2008-02-29", Some("2026-02-28"), Some(18), Some("march_1"), None).unwrap();
+        assert_eq!(mar.years, 17);
+        assert_eq!(mar.total_months, 215);
+        assert_eq!(mar.turns.unwrap().date, "2026-03-01");
+
+        assert!(ucm_age(&ucm, "2026-01-01", Some("2025-01-01"), None, None, None).is_err());
+        for turns in [i32::MAX as u32, u32::MAX] {
+            let error = ucm_age(&ucm, "2008-02-29", Some("2026-02-28"), Some(turns), None, None).unwrap_err();
+            assert_eq!(error.error, crate::types::ErrorKind::OutOfRange);
+        }
+    }
+}
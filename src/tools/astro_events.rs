@@ -0,0 +1,166 @@
+use chrono::{Datelike, Duration, Local, NaiveDateTime, TimeZone};
+use chrono_tz::Tz;
+
+use crate::astro::eclipse::{eclipses_between, EclipseKind};
+use crate::astro::moon::{phases_between, Phase};
+use crate::astro::seasons::{season_instant, Season};
+use crate::engine::Ucm;
+use crate::tools::sun::{resolve_timezone, to_local};
+use crate::types::{AstroEvent, AstroEventsResponse, UcmError};
+
+/// Longest range searched in one call
+const MAX_RANGE_DAYS: i64 = 50 * 366;
+
+/// Event categories and keys accepted in `kinds`
+const VALID_KINDS: [&str; 14] = [
+    "moon_phases", "seasons", "eclipses", "new_moon", "first_quarter", "full_moon",
+    "last_quarter", "march_equinox", "june_solstice", "september_equinox",
+    "december_solstice", "solar_eclipse", "lunar_eclipse", "all",
+];
+
+/// List moon phases, equinoxes, solstices and optionally eclipses between two dates
+pub fn ucm_astro_events(
+    ucm: &Ucm,
+    from: &str,
+    to: Option<&str>,
+    kinds: Option<&[String]>,
+    timezone: Option<&str>,
+) -> Result<AstroEventsResponse, UcmError> {
+    let from_date = ucm.parse_date(from)?;
+    let to_date = match to {
+        Some(to) => ucm.parse_date(to)?,
+        None => ucm.add(from_date, Duration::days(366))?,
+    };
+
+    if to_date < from_date || (to_date - from_date).num_days() > MAX_RANGE_DAYS {
+        return Err(UcmError::out_of_range(
+            to.unwrap_or(from),
+            "The end date must be on or after the start date and within 50 years of it",
+        ));
+    }
+
+    let kinds: Vec<String> = match kinds {
+        Some(kinds) if !kinds.is_empty() => kinds.iter().map(|k| k.trim().to_lowercase()).collect(),
+        _ => vec!["moon_phases".to_string(), "seasons".to_string()],
+    };
+    if let Some(invalid) = kinds.iter().find(|k| !VALID_KINDS.contains(&k.as_str())) {
+        return Err(UcmError::parse_error(
+            invalid,
+            &format!("Unknown event kind. Valid kinds: {}", VALID_KINDS.join(", ")),
+        ));
+    }
+    let wanted = |category: &str, key: &str| {
+        kinds.iter().any(|k| k == "all" || k == category || k == key)
+    };
+
+    let tz = resolve_timezone(timezone)?;
+    let start = local_midnight(from_date.and_hms_opt(0, 0, 0).unwrap(), tz);
+    let end = local_midnight(ucm.add(to_date, Duration::days(1))?.and_hms_opt(0, 0, 0).unwrap(), tz)
+        - Duration::seconds(1);
+    let today = ucm.today();
+
+    let event = |instant: NaiveDateTime, key: &str, name: &str, category: &str| AstroEvent {
+        event: key.to_string(),
+        name: name.to_string(),
+        category: category.to_string(),
+        datetime: to_local(instant, tz),
+        days_from_now: (local_date(instant, tz) - today).num_days(),
+        eclipse_type: None,
+        magnitude: None,
+    };
+
+    let mut found: Vec<(NaiveDateTime, AstroEvent)> = Vec::new();
+
+    for phase in Phase::ALL {
+        if wanted("moon_phases", phase.key()) {
+            for (_, instant) in phases_between(start, end, phase) {
+                found.push((instant, event(instant, phase.key(), phase.name(), "moon_phases")));
+            }
+        }
+    }
+
+    for year in start.year()..=end.year() {
+        for season in Season::ALL {
+            if !wanted("seasons", season.key()) {
+                continue;
+            }
+            if let Some(instant) = season_instant(year, season).filter(|i| (start..=end).contains(i)) {
+                found.push((instant, event(instant, season.key(), season.name(), "seasons")));
+            }
+        }
+    }
+
+    for eclipse in eclipses_between(start, end) {
+        let (key, name) = match eclipse.kind {
+            EclipseKind::Solar => ("solar_eclipse", "Solar Eclipse"),
+            EclipseKind::Lunar => ("lunar_eclipse", "Lunar Eclipse"),
+        };
+        if wanted("eclipses", key) {
+            found.push((
+                eclipse.instant,
+                AstroEvent {
+                    eclipse_type: Some(eclipse.eclipse_type.to_string()),
+                    magnitude: eclipse.magnitude.map(|m| (m * 1000.0).round() / 1000.0),
+                    ..event(eclipse.instant, key, name, "eclipses")
+                },
+            ));
+        }
+    }
+
+    found.sort_by_key(|(instant, _)| *instant);
+    let events: Vec<AstroEvent> = found.into_iter().map(|(_, event)| event).collect();
+
+    Ok(AstroEventsResponse {
+        from: from_date.format("%Y-%m-%d").to_string(),
+        to: to_date.format("%Y-%m-%d").to_string(),
+        timezone: tz.map(|tz| tz.name().to_string()).unwrap_or_else(|| "local".to_string()),
+        count: events.len(),
+        events,
+    })
+}
+
+/// UTC instant of a local wall-clock midnight
+fn local_midnight(local: NaiveDateTime, tz: Option<Tz>) -> NaiveDateTime {
+    match tz {
+        Some(tz) => tz.from_local_datetime(&local).earliest().map(|dt| dt.naive_utc()),
+        None => Local.from_local_datetime(&local).earliest().map(|dt| dt.naive_utc()),
+    }
+    .unwrap_or(local)
+}
+
+fn local_date(utc: NaiveDateTime, tz: Option<Tz>) -> chrono::NaiveDate {
+    match tz {
+        Some(tz) => tz.from_utc_datetime(&utc).date_naive(),
+        None => Local.from_utc_datetime(&utc).date_naive(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_astro_events_default_kinds() {
+        let result = ucm_astro_events(&Ucm::new(), "2026-01-01", Some("2026-12-31"), None, Some("UTC")).unwrap();
+        let full_moons = result.events.iter().filter(|e| e.event == "full_moon").count();
+        assert_eq!(full_moons, 13);
+        assert_eq!(result.events.iter().filter(|e| e.category == "seasons").count(), 4);
+        assert!(result.events.iter().all(|e| e.category != "eclipses"));
+    }
+
+    #[test]
+    fn test_astro_events_next_full_moon() {
+        let kinds = vec!["full_moon".to_string()];
+        let result = ucm_astro_events(&Ucm::new(), "2026-10-18", None, Some(&kinds), Some("UTC")).unwrap();
+        assert!(result.events[0].datetime.starts_with("2026-10-26"));
+    }
+
+    #[test]
+    fn test_astro_events_eclipses() {
+        let kinds = vec!["eclipses".to_string()];
+        let result = ucm_astro_events(&Ucm::new(), "2026-01-01", Some("2026-12-31"), Some(&kinds), Some("UTC")).unwrap();
+        assert_eq!(result.count, 4);
+        assert_eq!(result.events[0].eclipse_type.as_deref(), Some("annular"));
+        assert!(ucm_astro_events(&Ucm::new(), "2026-01-01", None, Some(&["comets".to_string()]), None).is_err());
+    }
+}
@@ -0,0 +1,305 @@
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::engine::Ucm;
+use crate::tools::{ucm_add, ucm_convert, ucm_diff, ucm_info, ucm_parse};
+use crate::types::{BatchItem, BatchResponse, UcmError};
+
+/// Most operations accepted in one call
+const MAX_OPERATIONS: usize = 100;
+
+/// Tools that can run inside a batch
+const BATCH_TOOLS: [&str; 5] = ["parse", "diff", "add", "info", "convert"];
+
+/// One operation in a ucm_batch call
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct BatchOperation {
+    #[schemars(description = "Tool to run: parse, diff, add, info or convert (the ucm_ prefix is optional)")]
+    pub tool: String,
+    #[schemars(description = "The tool's parameters. String values may reference earlier results, e.g. '$1.result_date'")]
+    #[serde(default)]
+    pub params: Map<String, Value>,
+}
+
+#[derive(Deserialize)]
+struct ParseArgs {
+    expression: String,
+}
+
+#[derive(Deserialize)]
+struct DiffArgs {
+    from: String,
+    to: String,
+}
+
+#[derive(Deserialize)]
+struct AddArgs {
+    date: String,
+    add: String,
+}
+
+#[derive(Deserialize)]
+struct InfoArgs {
+    date: String,
+    fiscal_calendar: Option<String>,
+    retail_calendar: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ConvertArgs {
+    value: f64,
+    from_unit: String,
+}
+
+/// Run several parse/diff/add/info/convert operations in order; a failed
+/// operation is reported in place without stopping the rest
+pub fn ucm_batch(ucm: &Ucm, operations: &[BatchOperation]) -> Result<BatchResponse, UcmError> {
+    if operations.is_empty() {
+        return Err(UcmError::missing_parameter("operations", "Provide at least one operation"));
+    }
+    if operations.len() > MAX_OPERATIONS {
+        return Err(UcmError::out_of_range(
+            &operations.len().to_string(),
+            &format!("A batch can contain at most {} operations", MAX_OPERATIONS),
+        ));
+    }
+
+    let mut results: Vec<BatchItem> = Vec::with_capacity(operations.len());
+    for (i, operation) in operations.iter().enumerate() {
+        let outcome = resolve(&Value::Object(operation.params.clone()), &results)
+            .and_then(|params| run(ucm, &operation.tool, params));
+        let (result, error) = match outcome {
+            Ok(value) => (Some(value), None),
+            Err(error) => (None, Some(error)),
+        };
+        results.push(BatchItem {
+            index: i + 1,
+            tool: operation.tool.clone(),
+            ok: error.is_none(),
+            result,
+            error,
+        });
+    }
+
+    let succeeded = results.iter().filter(|item| item.ok).count();
+    Ok(BatchResponse {
+        count: results.len(),
+        succeeded,
+        failed: results.len() - succeeded,
+        results,
+    })
+}
+
+/// Parse a JSON array of operations, as given on the command line
+pub fn parse_operations(json: &str) -> Result<Vec<BatchOperation>, UcmError> {
+    serde_json::from_str(json).map_err(|e| {
+        UcmError::parse_error(json, &format!("Expected a JSON array of {{\"tool\", \"params\"}} objects: {}", e))
+    })
+}
+
+fn run(ucm: &Ucm, tool: &str, params: Value) -> Result<Value, UcmError> {
+    let name = tool.trim().strip_prefix("ucm_").unwrap_or(tool.trim());
+    match name {
+        "parse" => {
+            let args: ParseArgs = args(name, params)?;
+            ucm_parse(ucm, &args.expression).map(to_value)
+        }
+        "diff" => {
+            let args: DiffArgs = args(name, params)?;
+            ucm_diff(ucm, &args.from, &args.to).map(to_value)
+        }
+        "add" => {
+            let args: AddArgs = args(name, params)?;
+            ucm_add(ucm, &args.date, &args.add).map(to_value)
+        }
+        "info" => {
+            let args: InfoArgs = args(name, params)?;
+            ucm_info(ucm, &args.date, args.fiscal_calendar.as_deref(), args.retail_calendar.as_deref())
+                .map(to_value)
+        }
+        "convert" => {
+            let args: ConvertArgs = args(name, params)?;
+            ucm_convert(args.value, &args.from_unit).map(to_value)
+        }
+        _ => Err(UcmError::parse_error(
+            tool,
+            &format!("Unknown batch tool '{}'. Valid tools: {}", tool, BATCH_TOOLS.join(", ")),
+        )),
+    }
+}
+
+fn args<T: DeserializeOwned>(tool: &str, params: Value) -> Result<T, UcmError> {
+    serde_json::from_value(params).map_err(|e| {
+        UcmError::missing_parameter(tool, &format!("Invalid parameters for '{}': {}", tool, e))
+    })
+}
+
+fn to_value<T: Serialize>(response: T) -> Value {
+    serde_json::to_value(response).unwrap()
+}
+
+/// Substitute `$N.field` references in string values with fields of
+/// earlier results. A string that is only a reference takes the referenced
+/// value as-is, so numbers stay numbers.
+fn resolve(value: &Value, results: &[BatchItem]) -> Result<Value, UcmError> {
+    match value {
+        Value::String(text) => resolve_text(text, results),
+        Value::Array(items) => items
+            .iter()
+            .map(|item| resolve(item, results))
+            .collect::<Result<_, _>>()
+            .map(Value::Array),
+        Value::Object(map) => map
+            .iter()
+            .map(|(key, item)| Ok((key.clone(), resolve(item, results)?)))
+            .collect::<Result<Map<_, _>, UcmError>>()
+            .map(Value::Object),
+        other => Ok(other.clone()),
+    }
+}
+
+fn resolve_text(text: &str, results: &[BatchItem]) -> Result<Value, UcmError> {
+    let mut out = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('$') {
+        let Some(reference) = reference_at(&rest[start..]) else {
+            out.push_str(&rest[..=start]);
+            rest = &rest[start + 1..];
+            continue;
+        };
+        let value = lookup(reference, results)?;
+        if start == 0 && reference.len() == rest.len() && out.is_empty() {
+            return Ok(value.clone());
+        }
+        out.push_str(&rest[..start]);
+        match value {
+            Value::String(s) => out.push_str(s),
+            Value::Number(_) | Value::Bool(_) => out.push_str(&value.to_string()),
+            _ => {
+                return Err(UcmError::invalid_reference(
+                    reference,
+                    "Only text, number and boolean fields can be inserted into a larger string",
+                ))
+            }
+        }
+        rest = &rest[start + reference.len()..];
+    }
+
+    out.push_str(rest);
+    Ok(Value::String(out))
+}
+
+/// The `$N.field` (or `$N.field.field`) reference at the start of `text`,
+/// if any; a bare `$5` is left as text
+fn reference_at(text: &str) -> Option<&str> {
+    let bytes = text.as_bytes();
+    let digits = bytes[1..].iter().take_while(|b| b.is_ascii_digit()).count();
+    if digits == 0 {
+        return None;
+    }
+
+    let mut end = 1 + digits;
+    while bytes.get(end) == Some(&b'.') {
+        let segment = bytes[end + 1..]
+            .iter()
+            .take_while(|b| b.is_ascii_alphanumeric() || **b == b'_')
+            .count();
+        if segment == 0 {
+            break;
+        }
+        end += 1 + segment;
+    }
+    (end > 1 + digits).then(|| &text[..end])
+}
+
+fn lookup<'a>(reference: &str, results: &'a [BatchItem]) -> Result<&'a Value, UcmError> {
+    let mut segments = reference[1..].split('.');
+    let index: usize = segments.next().and_then(|n| n.parse().ok()).unwrap_or(0);
+
+    let item = results
+        .get(index.wrapping_sub(1))
+        .filter(|_| index >= 1)
+        .ok_or_else(|| {
+            UcmError::invalid_reference(
+                reference,
+                &format!("'{}' must refer to an earlier operation (1 to {})", reference, results.len()),
+            )
+        })?;
+    let mut value = item.result.as_ref().ok_or_else(|| {
+        UcmError::invalid_reference(reference, &format!("Operation {} failed, so '{}' has no value", index, reference))
+    })?;
+
+    for segment in segments {
+        value = match value {
+            Value::Object(map) => map.get(segment),
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            UcmError::invalid_reference(
+                reference,
+                &format!("Operation {} ({}) has no field '{}'", index, item.tool, segment),
+            )
+        })?;
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ErrorKind;
+
+    fn operations(json: &str) -> Vec<BatchOperation> {
+        parse_operations(json).unwrap()
+    }
+
+    #[test]
+    fn test_batch_references() {
+        let ops = operations(
+            r#"[
+                {"tool": "add", "params": {"date": "2026-01-13", "add": "3 weeks"}},
+                {"tool": "ucm_add", "params": {"date": "$1.result_date", "add": "10 days"}},
+                {"tool": "diff", "params": {"from": "$1.result_date", "to": "$2.result_date"}},
+                {"tool": "convert", "params": {"value": "$3.total_days", "from_unit": "days"}},
+                {"tool": "parse", "params": {"expression": "$2.result_date (a $2.day_of_week)"}}
+            ]"#,
+        );
+        let response = ucm_batch(&Ucm::new(), &ops).unwrap();
+        assert_eq!(response.failed, 1);
+        let result = |i: usize| response.results[i].result.as_ref().unwrap();
+        assert_eq!(result(1)["result_date"], "2026-02-13");
+        assert_eq!(result(2)["total_days"], 10);
+        assert_eq!(result(3)["input_value"], 10.0);
+        // The substituted text is not a parseable date
+        assert_eq!(response.results[4].error.as_ref().unwrap().error, ErrorKind::ParseError);
+    }
+
+    #[test]
+    fn test_batch_item_errors() {
+        let ops = operations(
+            r#"[
+                {"tool": "parse", "params": {"expression": "flurbnesday"}},
+                {"tool": "add", "params": {"date": "$1.date", "add": "1 day"}},
+                {"tool": "info", "params": {"date": "$3.iso"}},
+                {"tool": "add", "params": {"date": "2026-01-01", "add": "1 day", "x": "cost $5"}},
+                {"tool": "weather", "params": {}},
+                {"tool": "diff", "params": {"from": "today"}}
+            ]"#,
+        );
+        let response = ucm_batch(&Ucm::new(), &ops).unwrap();
+        let kind = |i: usize| response.results[i].error.as_ref().map(|e| e.error);
+        assert_eq!(kind(0), Some(ErrorKind::ParseError));
+        assert_eq!(kind(1), Some(ErrorKind::InvalidReference));
+        assert_eq!(kind(2), Some(ErrorKind::InvalidReference));
+        assert_eq!(kind(3), None);
+        assert_eq!(kind(4), Some(ErrorKind::ParseError));
+        assert_eq!(kind(5), Some(ErrorKind::MissingParameter));
+        assert_eq!(response.succeeded, 1);
+
+        assert!(ucm_batch(&Ucm::new(), &[]).is_err());
+    }
+}
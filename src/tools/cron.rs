@@ -0,0 +1,100 @@
+use chrono::{DateTime, Local};
+
+use crate::calendar::cron::{CronRun, CronSchedule, DstShift};
+use crate::engine::Ucm;
+use crate::tools::sun::resolve_timezone;
+use crate::types::{CronField, CronResponse, CronRunInfo, UcmError};
+
+const DEFAULT_RUNS: usize = 5;
+const MAX_RUNS: usize = 100;
+
+/// Explain a cron expression and list its next (or previous) runs in a zone
+pub fn ucm_cron(
+    ucm: &Ucm,
+    expression: &str,
+    count: Option<usize>,
+    timezone: Option<&str>,
+    from: Option<&str>,
+    previous: bool,
+) -> Result<CronResponse, UcmError> {
+    let schedule = CronSchedule::parse(expression).map_err(|e| UcmError::parse_error(expression, &e.to_string()))?;
+    let count = count.unwrap_or(DEFAULT_RUNS);
+    if count == 0 || count > MAX_RUNS {
+        return Err(UcmError::out_of_range(&count.to_string(), &format!("count must be 1 to {}", MAX_RUNS)));
+    }
+    let ucm = match resolve_timezone(timezone)? {
+        Some(tz) => ucm.clone().with_timezone(tz),
+        None => ucm.clone(),
+    };
+    let from = match from.map(str::trim) {
+        Some(text) => match DateTime::parse_from_rfc3339(text) {
+            Ok(instant) => instant,
+            Err(_) => ucm.localize(ucm.parse_datetime(text)?),
+        },
+        None => ucm.now(),
+    };
+
+    let runs = match (ucm.timezone(), previous) {
+        (Some(tz), false) => schedule.next_runs(&tz, from, count),
+        (Some(tz), true) => schedule.previous_runs(&tz, from, count),
+        (None, false) => schedule.next_runs(&Local, from, count),
+        (None, true) => schedule.previous_runs(&Local, from, count),
+    };
+    if runs.is_empty() {
+        return Err(UcmError::out_of_range(
+            expression,
+            &format!("The schedule never runs {} {} within 100 years", if previous { "before" } else { "after" }, from.to_rfc3339()),
+        ));
+    }
+
+    Ok(CronResponse {
+        expression: expression.trim().to_string(),
+        dialect: schedule.dialect.name().to_string(),
+        description: schedule.describe(),
+        fields: schedule
+            .fields()
+            .into_iter()
+            .map(|(name, value)| CronField { name: name.to_string(), value: value.to_string() })
+            .collect(),
+        timezone: ucm.timezone_name(),
+        direction: if previous { "previous" } else { "next" }.to_string(),
+        from: from.to_rfc3339(),
+        runs: runs.iter().map(run_info).collect(),
+    })
+}
+
+fn run_info(run: &CronRun) -> CronRunInfo {
+    let scheduled = run.scheduled.format("%H:%M").to_string();
+    CronRunInfo {
+        time: run.at.to_rfc3339(),
+        day_of_week: run.at.format("%A").to_string(),
+        dst_note: run.dst.map(|shift| match shift {
+            DstShift::Gap => format!("{} is skipped as clocks go forward; runs when they do", scheduled),
+            DstShift::RepeatedOnce => format!("{} happens twice as clocks go back; runs the first time only", scheduled),
+            DstShift::Repeated { second: false } => format!("{} happens twice as clocks go back; first run", scheduled),
+            DstShift::Repeated { second: true } => format!("{} happens twice as clocks go back; second run", scheduled),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ErrorKind;
+
+    #[test]
+    fn test_cron_runs_in_zone() {
+        let ucm = Ucm::new().at_reference(Some("2026-10-18T12:00:00+00:00")).unwrap();
+        let result = ucm_cron(&ucm, "0 9 * * MON-FRI", Some(2), Some("Europe/London"), None, false).unwrap();
+        assert_eq!(result.description, "At 09:00, Monday through Friday");
+        assert_eq!(result.runs[0].time, "2026-10-19T09:00:00+01:00");
+        // British clocks go back on 25 October
+        let result = ucm_cron(&ucm, "30 1 * * *", Some(1), Some("Europe/London"), Some("2026-10-25"), false).unwrap();
+        assert!(result.runs[0].dst_note.as_deref().unwrap().contains("first time only"));
+
+        let previous = ucm_cron(&ucm, "@hourly", Some(1), Some("UTC"), None, true).unwrap();
+        assert_eq!(previous.runs[0].time, "2026-10-18T11:00:00+00:00");
+        assert_eq!(ucm_cron(&ucm, "0 0 31 2 *", None, None, None, false).unwrap_err().error, ErrorKind::OutOfRange);
+        assert_eq!(ucm_cron(&ucm, "0 0 * *", None, None, None, false).unwrap_err().error, ErrorKind::ParseError);
+    }
+}
@@ -0,0 +1,61 @@
+use chrono::Datelike;
+
+use crate::calendar::japanese::{to_era_date, EraDate};
+use crate::engine::Ucm;
+use crate::types::{EraResponse, JapaneseEraInfo, UcmError};
+
+/// Convert a date to or from a Japanese imperial era
+pub fn ucm_era(ucm: &Ucm, date: &str) -> Result<EraResponse, UcmError> {
+    let parsed_date = ucm.parse_date(date)?;
+
+    let era_date = to_era_date(parsed_date).ok_or_else(|| {
+        UcmError::out_of_range(date, "Dates before the Meiji era (1868-10-23) have no supported era")
+    })?;
+
+    Ok(EraResponse {
+        iso: parsed_date.format("%Y-%m-%d").to_string(),
+        gregorian_year: parsed_date.year(),
+        day_of_week: parsed_date.format("%A").to_string(),
+        japanese_era: japanese_era_info(&era_date),
+        parsed_expression: date.to_string(),
+    })
+}
+
+/// Build the era fields for a date on or after Meiji 1
+pub fn japanese_era_info(era_date: &EraDate) -> JapaneseEraInfo {
+    JapaneseEraInfo {
+        era: era_date.era.name.clone(),
+        era_kanji: era_date.era.kanji.clone(),
+        era_abbreviation: era_date.era.abbreviation.clone(),
+        era_year: era_date.year,
+        era_start: era_date.era.start.format("%Y-%m-%d").to_string(),
+        formatted: era_date.to_kanji_string(),
+        formatted_short: era_date.to_short_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_era_from_gregorian() {
+        let result = ucm_era(&Ucm::new(), "2026-10-18").unwrap();
+        assert_eq!(result.japanese_era.era, "Reiwa");
+        assert_eq!(result.japanese_era.era_year, 8);
+        assert_eq!(result.japanese_era.formatted_short, "R8.10.18");
+    }
+
+    #[test]
+    fn test_era_to_gregorian() {
+        let result = ucm_era(&Ucm::new(), "平成31年4月30日").unwrap();
+        assert_eq!(result.iso, "2019-04-30");
+        assert_eq!(result.japanese_era.era_kanji, "平成");
+    }
+
+    #[test]
+    fn test_era_before_meiji() {
+        let result = ucm_era(&Ucm::new(), "1850-01-01");
+        assert!(result.is_err());
+    }
+}
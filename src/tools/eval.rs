@@ -0,0 +1,69 @@
+use crate::engine::{Ucm, Value};
+use crate::types::{EvalResponse, UcmError};
+
+/// Evaluate a date expression, e.g. "end_of_month(today) - 1 week"
+pub fn ucm_eval(ucm: &Ucm, expression: &str) -> Result<EvalResponse, UcmError> {
+    let value = ucm.eval(expression)?;
+    let mut response = EvalResponse {
+        expression: expression.to_string(),
+        kind: value.kind().to_string(),
+        value: String::new(),
+        day_of_week: None,
+        days_from_today: None,
+        total_days: None,
+        number: None,
+    };
+
+    let date = match value {
+        Value::Date(date) => {
+            response.value = date.format("%Y-%m-%d").to_string();
+            Some(date)
+        }
+        Value::DateTime(datetime) => {
+            response.value = ucm.localize(datetime).to_rfc3339();
+            Some(datetime.date())
+        }
+        Value::Duration(interval) => {
+            response.value = interval.describe();
+            response.total_days = Some(round(interval.approximate_days()));
+            None
+        }
+        Value::Number(number) => {
+            response.value = round(number).to_string();
+            response.number = Some(round(number));
+            None
+        }
+    };
+    if let Some(date) = date {
+        response.day_of_week = Some(date.format("%A").to_string());
+        response.days_from_today = Some((date - ucm.today()).num_days());
+    }
+
+    Ok(response)
+}
+
+fn round(value: f64) -> f64 {
+    (value * 1000.0).round() / 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ErrorKind;
+
+    #[test]
+    fn test_eval_response() {
+        let ucm = Ucm::new().at_reference(Some("2026-10-18")).unwrap();
+        let result = ucm_eval(&ucm, "2026-10-23 + 2 business days").unwrap();
+        assert_eq!((result.kind.as_str(), result.value.as_str()), ("date", "2026-10-27"));
+        assert_eq!(result.day_of_week.as_deref(), Some("Tuesday"));
+        assert_eq!(result.days_from_today, Some(9));
+
+        let weeks = ucm_eval(&ucm, "(2026-12-25 - today) in weeks").unwrap();
+        assert_eq!(weeks.number, Some(9.714));
+
+        let error = ucm_eval(&ucm, "today + 3 parsecs").unwrap_err();
+        assert_eq!(error.error, ErrorKind::ParseError);
+        assert!(error.span.is_some());
+    }
+}
@@ -0,0 +1,461 @@
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, Utc, Weekday};
+use chrono_tz::Tz;
+
+use crate::calendar::ics::write_calendar;
+use crate::calendar::recurrence::Recurrence;
+use crate::engine::Ucm;
+use crate::parser::fiscal::parse_fiscal_range;
+use crate::parser::period::parse_period;
+use crate::store::Event;
+use crate::tools::sun::resolve_timezone;
+use crate::types::{
+    EventConflict, EventDeleteResponse, EventExportResponse, EventInfo, EventListResponse, EventOccurrence,
+    EventResponse, UcmError,
+};
+
+/// How far ahead a new or changed event is checked for conflicts
+const CONFLICT_HORIZON_DAYS: i64 = 365;
+/// Most occurrences of one event expanded for a query
+const MAX_OCCURRENCES: usize = 10_000;
+/// Longest range ucm_event_list and ucm_free_slots cover
+const MAX_LIST_DAYS: i64 = 366;
+
+/// Event fields to set; on create `title` and `start` are required, on
+/// update only the given fields change
+#[derive(Debug, Default)]
+pub struct EventFields {
+    pub title: Option<String>,
+    /// Date and time expression, or a date for all-day events
+    pub start: Option<String>,
+    /// Date and time expression; for all-day events the last day
+    pub end: Option<String>,
+    /// Length such as "90 minutes", instead of `end`
+    pub duration: Option<String>,
+    pub all_day: Option<bool>,
+    /// IANA time zone; "local" or "" for the server's zone
+    pub timezone: Option<String>,
+    /// RRULE or "weekly", "every 2 weeks"...; "none" or "" removes it
+    pub recurrence: Option<String>,
+    pub tags: Option<Vec<String>>,
+    /// "" removes the note
+    pub note: Option<String>,
+}
+
+pub fn ucm_event_create(ucm: &Ucm, fields: EventFields) -> Result<EventResponse, UcmError> {
+    if fields.title.as_deref().is_none_or(|title| title.trim().is_empty()) {
+        return Err(UcmError::missing_parameter("title", "An event needs a title"));
+    }
+    if fields.start.is_none() {
+        return Err(UcmError::missing_parameter("start", "An event needs a start, e.g. 'next tuesday at 14:00'"));
+    }
+    let placeholder = ucm.now().naive_local();
+    let mut event = Event {
+        id: String::new(),
+        title: String::new(),
+        start: placeholder,
+        end: placeholder,
+        all_day: false,
+        timezone: None,
+        recurrence: None,
+        tags: Vec::new(),
+        note: None,
+    };
+    apply_fields(ucm, &mut event, fields)?;
+
+    let events = ucm.events()?;
+    let event = events.create(event)?;
+    respond(ucm, event, &events.list()?)
+}
+
+pub fn ucm_event_update(ucm: &Ucm, id: &str, fields: EventFields) -> Result<EventResponse, UcmError> {
+    let events = ucm.events()?;
+    let mut event = events.get(id)?;
+    apply_fields(ucm, &mut event, fields)?;
+    events.update(event.clone())?;
+    respond(ucm, event, &events.list()?)
+}
+
+pub fn ucm_event_delete(ucm: &Ucm, id: &str) -> Result<EventDeleteResponse, UcmError> {
+    let deleted = ucm.events()?.delete(id)?;
+    Ok(EventDeleteResponse {
+        deleted: event_info(ucm, &deleted)?,
+    })
+}
+
+/// Occurrences between two dates, or in a period such as "next tuesday" or
+/// "this week", with any overlaps among them
+pub fn ucm_event_list(
+    ucm: &Ucm,
+    when: Option<&str>,
+    from: Option<&str>,
+    to: Option<&str>,
+    tag: Option<&str>,
+) -> Result<EventListResponse, UcmError> {
+    let (first, last) = list_range(ucm, when, from, to)?;
+    let tag = tag.map(|tag| tag.trim().to_lowercase());
+    let events: Vec<Event> = ucm
+        .events()?
+        .list()?
+        .into_iter()
+        .filter(|event| tag.as_ref().is_none_or(|tag| event.tags.contains(tag)))
+        .collect();
+    let window = (ucm.start_of_day(first), ucm.start_of_day(ucm.add(last, Duration::days(1))?));
+    let mut instances = Vec::new();
+    for event in &events {
+        instances.extend(expand(ucm, event, window)?);
+    }
+    instances.sort_by_key(|instance| instance.start);
+
+    let conflicts = overlaps(&instances, None);
+    Ok(EventListResponse {
+        from: first.format("%Y-%m-%d").to_string(),
+        to: last.format("%Y-%m-%d").to_string(),
+        count: instances.len(),
+        occurrences: instances.iter().map(occurrence).collect(),
+        conflicts,
+    })
+}
+
+/// Stored events as an iCalendar file
+pub fn ucm_event_export(ucm: &Ucm, tag: Option<&str>) -> Result<EventExportResponse, UcmError> {
+    let tag = tag.map(|tag| tag.trim().to_lowercase());
+    let events: Vec<Event> = ucm
+        .events()?
+        .list()?
+        .into_iter()
+        .filter(|event| tag.as_ref().is_none_or(|tag| event.tags.contains(tag)))
+        .collect();
+    let default_timezone = ucm.timezone_name();
+    let default_timezone = Some(default_timezone.as_str()).filter(|name| *name != "local");
+    Ok(EventExportResponse {
+        count: events.len(),
+        ics: write_calendar(&events, default_timezone, ucm.now().with_timezone(&Utc)),
+    })
+}
+
+fn apply_fields(ucm: &Ucm, event: &mut Event, fields: EventFields) -> Result<(), UcmError> {
+    let length = event.end - event.start;
+
+    if let Some(title) = fields.title {
+        event.title = title.trim().to_string();
+    }
+    if let Some(name) = fields.timezone {
+        event.timezone = match name.trim() {
+            "" | "local" => None,
+            name => resolve_timezone(Some(name))?.map(|tz| tz.name().to_string()),
+        };
+    }
+    if let Some(all_day) = fields.all_day {
+        event.all_day = all_day;
+    }
+    if let Some(start) = &fields.start {
+        event.start = parse_time(ucm, start, event.all_day)?;
+    } else if fields.all_day == Some(true) {
+        event.start = event.start.date().into();
+    }
+
+    event.end = match (&fields.end, &fields.duration) {
+        (Some(_), Some(_)) => {
+            return Err(UcmError::parse_error("end", "Give either end or duration, not both"));
+        }
+        // An all-day event's end is the day after its last day
+        (Some(end), None) if event.all_day => shift(parse_time(ucm, end, true)?, Duration::days(1), end)?,
+        (Some(end), None) => parse_time(ucm, end, false)?,
+        (None, Some(duration)) => shift(event.start, ucm.parse_duration(duration)?, duration)?,
+        (None, None) if event.id.is_empty() && event.all_day => {
+            shift(event.start, Duration::days(1), &event.start.to_string())?
+        }
+        (None, None) if event.id.is_empty() => shift(event.start, Duration::hours(1), &event.start.to_string())?,
+        (None, None) => shift(event.start, length, &event.start.to_string())?,
+    };
+    if event.end <= event.start {
+        return Err(UcmError::out_of_range(
+            fields.end.as_deref().or(fields.duration.as_deref()).unwrap_or_default(),
+            &format!("The event must end after it starts ({})", event.start.format("%Y-%m-%d %H:%M")),
+        ));
+    }
+
+    if let Some(rule) = fields.recurrence {
+        event.recurrence = match rule.trim() {
+            "" | "none" => None,
+            rule => Some(
+                Recurrence::parse(rule)
+                    .map_err(|e| UcmError::parse_error(rule, &e.to_string()))?
+                    .to_rrule(),
+            ),
+        };
+    }
+    if let Some(tags) = fields.tags {
+        let mut tags: Vec<String> =
+            tags.iter().map(|tag| tag.trim().to_lowercase()).filter(|tag| !tag.is_empty()).collect();
+        tags.sort();
+        tags.dedup();
+        event.tags = tags;
+    }
+    if let Some(note) = fields.note {
+        event.note = Some(note.trim().to_string()).filter(|note| !note.is_empty());
+    }
+    Ok(())
+}
+
+/// `time` moved by `by`, or an error naming the `input` that went too far
+fn shift(time: NaiveDateTime, by: Duration, input: &str) -> Result<NaiveDateTime, UcmError> {
+    time.checked_add_signed(by)
+        .ok_or_else(|| UcmError::out_of_range(input, "Result is outside the supported date range"))
+}
+
+fn parse_time(ucm: &Ucm, expression: &str, all_day: bool) -> Result<NaiveDateTime, UcmError> {
+    if all_day {
+        Ok(ucm.parse_date(expression)?.into())
+    } else {
+        ucm.parse_datetime(expression)
+    }
+}
+
+/// The saved event with conflicts over the next year
+fn respond(ucm: &Ucm, event: Event, all: &[Event]) -> Result<EventResponse, UcmError> {
+    let from = ucm.now().max(ucm.localize_in(event.start, event_zone(&event)?));
+    let window = (from, from + Duration::days(CONFLICT_HORIZON_DAYS));
+    let mut instances = Vec::new();
+    for other in all {
+        instances.extend(expand(ucm, other, window)?);
+    }
+    instances.sort_by_key(|instance| instance.start);
+
+    Ok(EventResponse {
+        conflicts: overlaps(&instances, Some(&event.id)),
+        event: event_info(ucm, &event)?,
+    })
+}
+
+/// One occurrence of an event
+pub struct Instance<'a> {
+    pub event: &'a Event,
+    pub start: DateTime<FixedOffset>,
+    pub end: DateTime<FixedOffset>,
+}
+
+fn event_zone(event: &Event) -> Result<Option<Tz>, UcmError> {
+    resolve_timezone(event.timezone.as_deref())
+}
+
+/// Every occurrence of an event, in order
+fn instances<'a>(ucm: &'a Ucm, event: &'a Event) -> Result<impl Iterator<Item = Instance<'a>> + 'a, UcmError> {
+    let zone = event_zone(event)?;
+    let length = event.end - event.start;
+    let starts: Box<dyn Iterator<Item = NaiveDateTime>> = match event.recurrence.as_deref() {
+        Some(rule) => Box::new(
+            Recurrence::parse_rrule(rule)
+                .map_err(|e| UcmError::parse_error(rule, &e.to_string()))?
+                .occurrences(event.start),
+        ),
+        None => Box::new(std::iter::once(event.start)),
+    };
+    Ok(starts.map(move |start| Instance {
+        event,
+        start: ucm.localize_in(start, zone),
+        end: ucm.localize_in(start + length, zone),
+    }))
+}
+
+/// Occurrences of an event that overlap `window`
+pub fn expand<'a>(
+    ucm: &'a Ucm,
+    event: &'a Event,
+    window: (DateTime<FixedOffset>, DateTime<FixedOffset>),
+) -> Result<Vec<Instance<'a>>, UcmError> {
+    Ok(instances(ucm, event)?
+        .take_while(|instance| instance.start < window.1)
+        .filter(|instance| instance.end > window.0)
+        .take(MAX_OCCURRENCES)
+        .collect())
+}
+
+/// Overlapping pairs of timed occurrences sorted by start, optionally only
+/// those involving one event; all-day events never conflict
+fn overlaps(instances: &[Instance], involving: Option<&str>) -> Vec<EventConflict> {
+    let timed: Vec<&Instance> = instances.iter().filter(|i| !i.event.all_day).collect();
+    let mut conflicts = Vec::new();
+    for (index, first) in timed.iter().enumerate() {
+        for second in timed[index + 1..].iter().take_while(|second| second.start < first.end) {
+            let relevant = involving.is_none_or(|id| first.event.id == id || second.event.id == id);
+            if first.event.id == second.event.id || !relevant {
+                continue;
+            }
+            let (start, end) = (second.start, first.end.min(second.end));
+            conflicts.push(EventConflict {
+                first_id: first.event.id.clone(),
+                first_title: first.event.title.clone(),
+                second_id: second.event.id.clone(),
+                second_title: second.event.title.clone(),
+                start: start.to_rfc3339(),
+                end: end.to_rfc3339(),
+                minutes: (end - start).num_minutes(),
+            });
+        }
+    }
+    conflicts
+}
+
+fn occurrence(instance: &Instance) -> EventOccurrence {
+    let event = instance.event;
+    EventOccurrence {
+        id: event.id.clone(),
+        title: event.title.clone(),
+        start: instance.start.to_rfc3339(),
+        end: instance.end.to_rfc3339(),
+        day_of_week: instance.start.format("%A").to_string(),
+        all_day: event.all_day,
+        recurring: event.recurrence.is_some(),
+        tags: event.tags.clone(),
+    }
+}
+
+fn event_info(ucm: &Ucm, event: &Event) -> Result<EventInfo, UcmError> {
+    let now = ucm.now();
+    let next = instances(ucm, event)?.find(|instance| instance.start >= now);
+    let (end, format) = if event.all_day {
+        (event.end - Duration::days(1), "%Y-%m-%d")
+    } else {
+        (event.end, "%Y-%m-%dT%H:%M")
+    };
+    Ok(EventInfo {
+        id: event.id.clone(),
+        title: event.title.clone(),
+        start: event.start.format(format).to_string(),
+        end: end.format(format).to_string(),
+        all_day: event.all_day,
+        timezone: event.timezone.clone().unwrap_or_else(|| ucm.timezone_name()),
+        recurrence: event.recurrence.clone(),
+        next_start: next.map(|instance| instance.start.to_rfc3339()),
+        tags: event.tags.clone(),
+        note: event.note.clone(),
+    })
+}
+
+/// Days covered by a `when` period, or by `from` (default today) to `to`
+/// (default six days later)
+pub fn list_range(
+    ucm: &Ucm,
+    when: Option<&str>,
+    from: Option<&str>,
+    to: Option<&str>,
+) -> Result<(NaiveDate, NaiveDate), UcmError> {
+    let (first, last) = match when {
+        Some(when) => date_range(ucm, when)?,
+        None => {
+            let first = ucm.parse_date(from.unwrap_or("today"))?;
+            let last = match to {
+                Some(to) => ucm.parse_date(to)?,
+                None => ucm.add(first, Duration::days(6))?,
+            };
+            (first, last)
+        }
+    };
+    if last < first {
+        return Err(UcmError::out_of_range(to.unwrap_or_default(), &format!("{} is before {}", last, first)));
+    }
+    if (last - first).num_days() >= MAX_LIST_DAYS {
+        return Err(UcmError::out_of_range(
+            &format!("{}..{}", first, last),
+            &format!("At most {} days can be covered at once", MAX_LIST_DAYS),
+        ));
+    }
+    Ok((first, last))
+}
+
+/// First and last day of a fiscal expression, a period ("this week",
+/// "November 2026"), or a single date ("next tuesday")
+pub fn date_range(ucm: &Ucm, text: &str) -> Result<(NaiveDate, NaiveDate), UcmError> {
+    let invalid = |e: anyhow::Error| UcmError::parse_error(text, &e.to_string());
+    if let Some(range) = parse_fiscal_range(text, ucm.fiscal_calendar(), ucm.today()).map_err(invalid)? {
+        return Ok(range);
+    }
+    if let Some(period) = parse_period(text, ucm.fiscal_calendar(), ucm.today(), Weekday::Mon).map_err(invalid)? {
+        return Ok((period.start, period.end));
+    }
+    let date = ucm.parse_date(text)?;
+    Ok((date, date))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ErrorKind;
+
+    fn ucm(name: &str) -> (Ucm, std::path::PathBuf) {
+        let dir = std::env::temp_dir().join(format!("ucm-events-{}-{}", name, std::process::id()));
+        let ucm = Ucm::new()
+            .at_reference(Some("2026-10-18T08:00:00+01:00"))
+            .unwrap()
+            .with_timezone(chrono_tz::Europe::London)
+            .with_data_dir(&dir);
+        (ucm, dir)
+    }
+
+    fn fields(title: &str, start: &str) -> EventFields {
+        EventFields { title: Some(title.to_string()), start: Some(start.to_string()), ..EventFields::default() }
+    }
+
+    #[test]
+    fn test_events_conflicts_and_queries() {
+        let (ucm, dir) = ucm("queries");
+        let standup = EventFields {
+            duration: Some("30 minutes".to_string()),
+            recurrence: Some("weekdays".to_string()),
+            tags: Some(vec!["Work".to_string()]),
+            ..fields("Standup", "2026-10-19 09:00")
+        };
+        let standup = ucm_event_create(&ucm, standup).unwrap().event;
+        assert_eq!(standup.recurrence.as_deref(), Some("FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR"));
+        assert_eq!(standup.next_start.as_deref(), Some("2026-10-19T09:00:00+01:00"));
+
+        // 09:00 in New York is 14:00 in London, clear of the standup
+        let call = EventFields { timezone: Some("America/New_York".to_string()), ..fields("Call", "2026-10-20 09:00") };
+        assert!(ucm_event_create(&ucm, call).unwrap().conflicts.is_empty());
+
+        let review = ucm_event_create(&ucm, fields("Review", "2026-10-20 at 9:15")).unwrap();
+        assert_eq!(review.conflicts.len(), 1);
+        assert_eq!(review.conflicts[0].minutes, 15);
+
+        let tuesday = ucm_event_list(&ucm, Some("2026-10-20"), None, None, None).unwrap();
+        let titles: Vec<&str> = tuesday.occurrences.iter().map(|o| o.title.as_str()).collect();
+        assert_eq!(titles, ["Standup", "Review", "Call"]);
+        assert_eq!(tuesday.conflicts.len(), 1);
+
+        let week = ucm_event_list(&ucm, None, Some("2026-10-19"), Some("2026-10-25"), Some("work")).unwrap();
+        assert_eq!(week.count, 5);
+
+        let moved = EventFields { start: Some("2026-10-20 11:00".to_string()), ..EventFields::default() };
+        let moved = ucm_event_update(&ucm, &review.event.id, moved).unwrap();
+        assert!(moved.conflicts.is_empty());
+        assert_eq!(moved.event.end, "2026-10-20T12:00");
+
+        let ics = ucm_event_export(&ucm, None).unwrap();
+        assert_eq!(ics.count, 3);
+        assert!(ics.ics.contains("DTSTART;TZID=America/New_York:20261020T090000"));
+
+        ucm_event_delete(&ucm, &standup.id).unwrap();
+        assert_eq!(ucm_event_delete(&ucm, &standup.id).unwrap_err().error, ErrorKind::InvalidReference);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_event_validation() {
+        let (ucm, dir) = ucm("validation");
+        let backwards = EventFields { end: Some("2026-10-20 08:00".to_string()), ..fields("Oops", "2026-10-20 09:00") };
+        assert_eq!(ucm_event_create(&ucm, backwards).unwrap_err().error, ErrorKind::OutOfRange);
+        let zone = EventFields { timezone: Some("Mars/Olympus".to_string()), ..fields("Oops", "2026-10-20") };
+        assert_eq!(ucm_event_create(&ucm, zone).unwrap_err().error, ErrorKind::InvalidTimezone);
+        let untitled = EventFields { title: None, ..fields("", "2026-10-20") };
+        assert_eq!(ucm_event_create(&ucm, untitled).unwrap_err().error, ErrorKind::MissingParameter);
+
+        let holiday = EventFields { all_day: Some(true), end: Some("2026-10-23".to_string()), ..fields("Leave", "2026-10-21") };
+        let holiday = ucm_event_create(&ucm, holiday).unwrap().event;
+        assert_eq!((holiday.start.as_str(), holiday.end.as_str()), ("2026-10-21", "2026-10-23"));
+
+        let endless = EventFields { duration: Some("1000000000 days".to_string()), ..fields("Oops", "2026-10-20") };
+        assert_eq!(ucm_event_create(&ucm, endless).unwrap_err().error, ErrorKind::OutOfRange);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
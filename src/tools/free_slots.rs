@@ -0,0 +1,265 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset};
+
+use crate::calendar::free_busy::{merge, parse_time_range, subtract, WorkingHours};
+use crate::calendar::holidays::find_country;
+use crate::calendar::ics::read_calendar;
+use crate::engine::Ucm;
+use crate::parser::natural::parse_time_of_day;
+use crate::store::Event;
+use crate::tools::events::{expand, list_range};
+use crate::tools::sun::resolve_timezone;
+use crate::types::{FreeSlot, FreeSlotsResponse, TimeBlock, UcmError};
+
+/// Slots returned when no limit is given
+const DEFAULT_SLOTS: usize = 20;
+const MAX_SLOTS: usize = 500;
+
+type Interval = (DateTime<FixedOffset>, DateTime<FixedOffset>);
+
+/// What to find free time for; only `duration` is required
+#[derive(Debug, Default)]
+pub struct SlotQuery {
+    /// Shortest useful slot, e.g. "90 minutes"
+    pub duration: String,
+    /// Day or period, e.g. "this week"; otherwise `from` to `to`
+    pub when: Option<String>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    /// Busy intervals as "start/end", e.g. "2026-10-20 09:00/10:30"
+    pub busy: Vec<String>,
+    /// .ics file in the data directory whose events are busy time
+    pub ics_file: Option<String>,
+    /// Whether stored events are busy time; by default they are when a
+    /// data directory is configured
+    pub include_events: Option<bool>,
+    /// e.g. "09:00-17:00" (the default)
+    pub working_hours: Option<String>,
+    /// e.g. "mon-fri" (the default)
+    pub working_days: Option<String>,
+    /// IANA zone of the working hours and inline times
+    pub timezone: Option<String>,
+    /// Time of day to keep free, e.g. "12:00-13:00"
+    pub lunch: Option<String>,
+    pub buffer_before: Option<String>,
+    pub buffer_after: Option<String>,
+    /// Country whose public holidays are not working days
+    pub country: Option<String>,
+    pub limit: Option<usize>,
+}
+
+/// Free slots of at least a duration within working hours, around busy time
+/// from inline intervals, an .ics file and stored events
+pub fn ucm_free_slots(ucm: &Ucm, query: SlotQuery) -> Result<FreeSlotsResponse, UcmError> {
+    let ucm = match resolve_timezone(query.timezone.as_deref())? {
+        Some(tz) => ucm.clone().with_timezone(tz),
+        None => ucm.clone(),
+    };
+    let duration = ucm.parse_duration(&query.duration)?;
+    if duration <= Duration::zero() {
+        return Err(UcmError::out_of_range(&query.duration, "The duration must be positive"));
+    }
+    let buffer = |text: Option<&str>| -> Result<Duration, UcmError> {
+        let Some(text) = text else {
+            return Ok(Duration::zero());
+        };
+        let buffer = ucm.parse_duration(text)?;
+        if buffer < Duration::zero() {
+            return Err(UcmError::out_of_range(text, "Buffers cannot be negative"));
+        }
+        Ok(buffer)
+    };
+    let (before, after) = (buffer(query.buffer_before.as_deref())?, buffer(query.buffer_after.as_deref())?);
+    let hours = WorkingHours::parse(query.working_hours.as_deref(), query.working_days.as_deref()).map_err(|e| {
+        UcmError::parse_error(query.working_hours.as_deref().or(query.working_days.as_deref()).unwrap_or_default(), &e.to_string())
+    })?;
+    let lunch = query
+        .lunch
+        .as_deref()
+        .map(|lunch| parse_time_range(lunch).map_err(|e| UcmError::parse_error(lunch, &e.to_string())))
+        .transpose()?;
+    let holidays = query
+        .country
+        .as_deref()
+        .map(|code| find_country(code).map_err(|e| UcmError::invalid_calendar(code, &e.to_string())))
+        .transpose()?;
+    let limit = query.limit.unwrap_or(DEFAULT_SLOTS);
+    if limit == 0 || limit > MAX_SLOTS {
+        return Err(UcmError::out_of_range(&limit.to_string(), &format!("limit must be 1 to {}", MAX_SLOTS)));
+    }
+
+    let (first, last) = list_range(&ucm, query.when.as_deref(), query.from.as_deref(), query.to.as_deref())?;
+    let window = (ucm.start_of_day(first), ucm.start_of_day(ucm.add(last, Duration::days(1))?));
+
+    let mut busy = Vec::new();
+    for text in &query.busy {
+        busy.push(inline_interval(&ucm, text)?);
+    }
+    let mut events = match &query.ics_file {
+        // Only files in the data directory, since clients may be remote
+        Some(path) => {
+            let text = std::fs::read_to_string(ucm.data_file(path)?)
+                .map_err(|e| UcmError::storage_error(path, &format!("Could not read {}: {}", path, e)))?;
+            read_calendar(&text).map_err(|e| UcmError::parse_error(path, &e.to_string()))?
+        }
+        None => Vec::new(),
+    };
+    events.extend(stored_events(&ucm, query.include_events)?);
+    for event in events.iter().filter(|event| !event.all_day) {
+        busy.extend(expand(&ucm, event, window)?.into_iter().map(|instance| (instance.start, instance.end)));
+    }
+    let busy: Vec<Interval> = merge(busy).into_iter().filter(|(start, end)| *end > window.0 && *start < window.1).collect();
+
+    // Time that cannot be booked: busy time with its buffers, lunch, and the past
+    let mut blocked = busy
+        .iter()
+        .map(|(start, end)| start.checked_sub_signed(before).zip(end.checked_add_signed(after)))
+        .collect::<Option<Vec<Interval>>>()
+        .ok_or_else(|| {
+            UcmError::out_of_range(
+                query.buffer_before.as_deref().or(query.buffer_after.as_deref()).unwrap_or_default(),
+                "Buffers reach outside the supported date range",
+            )
+        })?;
+    blocked.push((window.0, ucm.now()));
+    let mut working = Vec::new();
+    for day in first.iter_days().take_while(|day| *day <= last) {
+        if !hours.days.contains(&day.weekday()) || holidays.is_some_and(|country| country.is_holiday(day)) {
+            continue;
+        }
+        working.push((ucm.localize(day.and_time(hours.start)), ucm.localize(day.and_time(hours.end))));
+        if let Some((start, end)) = lunch {
+            blocked.push((ucm.localize(day.and_time(start)), ucm.localize(day.and_time(end))));
+        }
+    }
+    let free: Vec<Interval> =
+        subtract(&working, &merge(blocked)).into_iter().filter(|(start, end)| *end - *start >= duration).collect();
+
+    Ok(FreeSlotsResponse {
+        from: first.format("%Y-%m-%d").to_string(),
+        to: last.format("%Y-%m-%d").to_string(),
+        timezone: ucm.timezone_name(),
+        duration_minutes: duration.num_minutes(),
+        working_hours: format!("{}-{}", hours.start.format("%H:%M"), hours.end.format("%H:%M")),
+        working_days: hours.days.iter().map(|day| format!("{:?}", day)).collect(),
+        busy: busy
+            .iter()
+            .map(|(start, end)| TimeBlock { start: start.to_rfc3339(), end: end.to_rfc3339() })
+            .collect(),
+        count: free.len().min(limit),
+        truncated: free.len() > limit,
+        slots: free
+            .iter()
+            .take(limit)
+            .map(|(start, end)| FreeSlot {
+                start: start.to_rfc3339(),
+                end: end.to_rfc3339(),
+                minutes: (*end - *start).num_minutes(),
+                day_of_week: start.format("%A").to_string(),
+            })
+            .collect(),
+    })
+}
+
+/// "2026-10-20 09:00/10:30" or "2026-10-20T09:00/2026-10-20T10:30"
+fn inline_interval(ucm: &Ucm, text: &str) -> Result<Interval, UcmError> {
+    let (start, end) = text.split_once('/').ok_or_else(|| {
+        UcmError::parse_error(text, "Busy intervals are 'start/end', e.g. '2026-10-20 09:00/10:30'")
+    })?;
+    let start = ucm.parse_datetime(start)?;
+    let end = match parse_time_of_day(end) {
+        Some(time) => start.date().and_time(time),
+        None => ucm.parse_datetime(end)?,
+    };
+    if end <= start {
+        return Err(UcmError::out_of_range(text, "A busy interval must end after it starts"));
+    }
+    Ok((ucm.localize(start), ucm.localize(end)))
+}
+
+fn stored_events(ucm: &Ucm, include: Option<bool>) -> Result<Vec<Event>, UcmError> {
+    match (include, ucm.events()) {
+        (Some(false), _) => Ok(Vec::new()),
+        (_, Ok(store)) => store.list(),
+        (None, Err(_)) => Ok(Vec::new()),
+        (Some(true), Err(error)) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ErrorKind;
+
+    fn ucm() -> Ucm {
+        Ucm::new().at_reference(Some("2026-10-18")).unwrap().with_timezone(chrono_tz::UTC)
+    }
+
+    #[test]
+    fn test_free_slots_around_busy_time() {
+        let query = SlotQuery {
+            duration: "90 minutes".to_string(),
+            when: Some("2026-10-20".to_string()),
+            busy: vec!["2026-10-20 09:30/11:00".to_string(), "2026-10-20 14:00/2026-10-20 15:00".to_string()],
+            lunch: Some("12:00-13:00".to_string()),
+            buffer_after: Some("15 minutes".to_string()),
+            ..SlotQuery::default()
+        };
+        let result = ucm_free_slots(&ucm(), query).unwrap();
+        let slots: Vec<(&str, i64)> = result.slots.iter().map(|s| (&s.start[11..16], s.minutes)).collect();
+        // 09:00-09:30 and 11:15-12:00 are too short
+        assert_eq!(slots, [("15:15", 105)]);
+        assert_eq!(result.busy.len(), 2);
+    }
+
+    #[test]
+    fn test_free_slots_working_days_and_errors() {
+        let query = SlotQuery {
+            duration: "8 hours".to_string(),
+            from: Some("2026-12-21".to_string()),
+            to: Some("2026-12-27".to_string()),
+            country: Some("GB".to_string()),
+            include_events: Some(false),
+            ..SlotQuery::default()
+        };
+        let result = ucm_free_slots(&ucm(), query).unwrap();
+        // Christmas Day and Boxing Day (observed Monday 28th) aside, Monday to Thursday
+        assert_eq!(result.count, 4);
+        assert_eq!(result.slots[3].day_of_week, "Thursday");
+
+        let query = SlotQuery { duration: "1 hour".to_string(), include_events: Some(true), ..SlotQuery::default() };
+        assert_eq!(ucm_free_slots(&ucm(), query).unwrap_err().error, ErrorKind::StorageError);
+        let query = SlotQuery { duration: "1 hour".to_string(), busy: vec!["today".to_string()], ..SlotQuery::default() };
+        assert_eq!(ucm_free_slots(&ucm(), query).unwrap_err().error, ErrorKind::ParseError);
+        let query = SlotQuery {
+            duration: "1 hour".to_string(),
+            busy: vec!["2026-10-20 09:30/11:00".to_string()],
+            buffer_before: Some("1000000000 days".to_string()),
+            include_events: Some(false),
+            ..SlotQuery::default()
+        };
+        assert_eq!(ucm_free_slots(&ucm(), query).unwrap_err().error, ErrorKind::OutOfRange);
+    }
+
+    #[test]
+    fn test_free_slots_ics_file_in_data_dir() {
+        let dir = std::env::temp_dir().join(format!("ucm-free-slots-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let ics = "BEGIN:VEVENT\r\nDTSTART:20261020T090000Z\r\nDTEND:20261020T160000Z\r\nEND:VEVENT\r\n";
+        std::fs::write(dir.join("busy.ics"), ics).unwrap();
+        let ucm = ucm().with_data_dir(&dir);
+        let query = |path: &str| SlotQuery {
+            duration: "1 hour".to_string(),
+            when: Some("2026-10-20".to_string()),
+            ics_file: Some(path.to_string()),
+            include_events: Some(false),
+            ..SlotQuery::default()
+        };
+
+        let result = ucm_free_slots(&ucm, query("busy.ics")).unwrap();
+        assert_eq!(result.slots[0].start, "2026-10-20T16:00:00+00:00");
+        for path in ["/etc/hosts", "../busy.ics", "missing.ics"] {
+            assert_eq!(ucm_free_slots(&ucm, query(path)).unwrap_err().error, ErrorKind::StorageError, "{}", path);
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
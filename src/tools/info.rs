@@ -1,55 +1,83 @@
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::Datelike;
 
-use crate::parser::parse_to_date;
-use crate::types::{InfoResponse, UcmError};
+use crate::astro::moon::phase_name;
+use crate::calendar::fiscal::{FiscalCalendar, FiscalDate};
+use crate::calendar::week::iso_week_date;
+use crate::engine::Ucm;
+use crate::tools::era::japanese_era_info;
+use crate::tools::retail::{resolve_retail_calendar, retail_info};
+use crate::types::{FiscalInfo, InfoResponse, UcmError};
 
-/// Get detailed information about a date
-pub fn ucm_info(date: &str) -> Result<InfoResponse, UcmError> {
-    let parsed_date = parse_to_date(date).map_err(|e| {
-        UcmError::parse_error(date, &e.to_string())
-    })?;
+/// Get detailed information about a date, optionally within fiscal and
+/// retail calendars
+pub fn ucm_info(
+    ucm: &Ucm,
+    date: &str,
+    fiscal_calendar: Option<&str>,
+    retail_calendar: Option<&str>,
+) -> Result<InfoResponse, UcmError> {
+    let fiscal = fiscal_calendar.map(resolve_fiscal_calendar).transpose()?;
+    let retail = retail_calendar.map(resolve_retail_calendar).transpose()?;
 
-    let today = Local::now().naive_local().date();
-    let days_from_now = (parsed_date - today).num_days();
-
-    let is_leap_year = parsed_date.leap_year();
-    let days_in_month = days_in_month(parsed_date.year(), parsed_date.month());
-    let is_weekend = matches!(
-        parsed_date.weekday(),
-        chrono::Weekday::Sat | chrono::Weekday::Sun
-    );
+    let with_fiscal = fiscal.is_some();
+    let ucm = match fiscal {
+        Some(calendar) => ucm.clone().with_fiscal_calendar(calendar),
+        None => ucm.clone(),
+    };
+    let parsed_date = ucm.parse_date(date)?;
+    let info = ucm.info(parsed_date)?;
 
     let month_name = parsed_date.format("%B").to_string();
+    let retail = retail
+        .map(|calendar| retail_info(parsed_date, &calendar))
+        .transpose()?;
 
     Ok(InfoResponse {
-        iso: parsed_date.format("%Y-%m-%d").to_string(),
+        iso: info.date.format("%Y-%m-%d").to_string(),
         day_of_week: parsed_date.format("%A").to_string(),
         day_of_month: parsed_date.day(),
         day_of_year: parsed_date.ordinal(),
         week_of_year: parsed_date.iso_week().week(),
+        iso_week_year: parsed_date.iso_week().year(),
+        iso_week_date: iso_week_date(parsed_date),
         month: parsed_date.month(),
         month_name,
         year: parsed_date.year(),
-        quarter: ((parsed_date.month() - 1) / 3) + 1,
-        is_leap_year,
-        days_in_month,
-        is_weekend,
-        days_from_now,
-        is_past: days_from_now < 0,
+        quarter: info.quarter,
+        is_leap_year: parsed_date.leap_year(),
+        days_in_month: info.days_in_month,
+        is_weekend: info.is_weekend,
+        days_from_now: info.days_from_today,
+        is_past: info.days_from_today < 0,
+        fiscal: with_fiscal.then(|| fiscal_info(&ucm.fiscal_calendar().name, &info.fiscal)),
+        retail,
+        japanese_era: info.era.as_ref().map(japanese_era_info),
+        moon_phase: Some(phase_name(info.moon_illumination, info.moon_waxing).to_string()),
+        moon_illumination: Some((info.moon_illumination * 100.0).round() / 100.0),
     })
 }
 
-fn days_in_month(year: i32, month: u32) -> u32 {
-    let next_month = if month == 12 {
-        NaiveDate::from_ymd_opt(year + 1, 1, 1)
-    } else {
-        NaiveDate::from_ymd_opt(year, month + 1, 1)
-    };
+/// Look up a fiscal calendar profile by name or starting month
+pub fn resolve_fiscal_calendar(name: &str) -> Result<FiscalCalendar, UcmError> {
+    FiscalCalendar::from_name(name).map_err(|e| {
+        UcmError::invalid_calendar(name, &e.to_string())
+    })
+}
 
-    next_month
-        .and_then(|d| d.pred_opt())
-        .map(|d| d.day())
-        .unwrap_or(30)
+/// Build the fiscal fields for a located date
+pub fn fiscal_info(calendar: &str, fiscal: &FiscalDate) -> FiscalInfo {
+    FiscalInfo {
+        calendar: calendar.to_string(),
+        fiscal_year: fiscal.fiscal_year,
+        fiscal_year_label: format!("FY{}", fiscal.fiscal_year),
+        fiscal_quarter: fiscal.quarter,
+        fiscal_period: fiscal.period,
+        fiscal_week: fiscal.week,
+        fiscal_year_start: fiscal.year_start.format("%Y-%m-%d").to_string(),
+        fiscal_year_end: fiscal.year_end.format("%Y-%m-%d").to_string(),
+        fiscal_quarter_start: fiscal.quarter_start.format("%Y-%m-%d").to_string(),
+        fiscal_quarter_end: fiscal.quarter_end.format("%Y-%m-%d").to_string(),
+    }
 }
 
 #[cfg(test)]
@@ -58,23 +86,59 @@ mod tests {
 
     #[test]
     fn test_info_specific_date() {
-        let result = ucm_info("2026-10-22").unwrap();
+        let result = ucm_info(&Ucm::new(), "2026-10-22", None, None).unwrap();
         assert_eq!(result.day_of_week, "Thursday");
         assert_eq!(result.month, 10);
         assert_eq!(result.month_name, "October");
         assert_eq!(result.quarter, 4);
+        assert_eq!(result.iso_week_date, "2026-W43-4");
     }
 
     #[test]
     fn test_info_leap_year() {
-        let result = ucm_info("2024-02-29").unwrap();
+        let result = ucm_info(&Ucm::new(), "2024-02-29", None, None).unwrap();
         assert!(result.is_leap_year);
         assert_eq!(result.days_in_month, 29);
     }
 
     #[test]
     fn test_info_weekend() {
-        let result = ucm_info("2026-01-17").unwrap(); // Saturday
+        let result = ucm_info(&Ucm::new(), "2026-01-17", None, None).unwrap(); // Saturday
         assert!(result.is_weekend);
     }
+
+    #[test]
+    fn test_info_japanese_era() {
+        let result = ucm_info(&Ucm::new(), "2026-10-18", None, None).unwrap();
+        let era = result.japanese_era.unwrap();
+        assert_eq!(era.era, "Reiwa");
+        assert_eq!(era.era_year, 8);
+    }
+
+    #[test]
+    fn test_info_fiscal_calendar() {
+        let result = ucm_info(&Ucm::new(), "FY27 Q2", Some("us_federal"), None).unwrap();
+        assert_eq!(result.iso, "2027-01-01");
+        assert_eq!(result.quarter, 1);
+        let fiscal = result.fiscal.unwrap();
+        assert_eq!(fiscal.fiscal_year, 2027);
+        assert_eq!(fiscal.fiscal_quarter, 2);
+        assert_eq!(fiscal.fiscal_period, 4);
+    }
+
+    #[test]
+    fn test_info_retail_calendar() {
+        let result = ucm_info(&Ucm::new(), "2024-02-01", None, Some("nrf")).unwrap();
+        let retail = result.retail.unwrap();
+        assert_eq!(retail.retail_year, 2023);
+        assert_eq!(retail.retail_week, 53);
+        assert!(retail.is_53_week_year);
+    }
+
+    #[test]
+    fn test_info_moon_phase() {
+        let result = ucm_info(&Ucm::new(), "2026-10-26", None, None).unwrap();
+        assert_eq!(result.moon_phase.as_deref(), Some("Full Moon"));
+        assert!(result.moon_illumination.unwrap() > 0.98);
+    }
 }
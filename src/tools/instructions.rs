@@ -1,3 +1,4 @@
+use crate::prompts::PROMPTS;
 use crate::types::{InstructionsResponse, ToolInstruction};
 
 /// Get instructions on how to use UCM tools
@@ -11,9 +12,11 @@ pub fn ucm_instructions() -> InstructionsResponse {
             ToolInstruction {
                 name: "ucm_now".to_string(),
                 description: "Get the current date and time from the system.".to_string(),
-                parameters: "None".to_string(),
+                parameters: "fiscal_calendar: Option<String> - us_federal, uk, japan, australia, india, \
+                    calendar, or a starting month".to_string(),
                 example: "Returns current datetime with ISO format, unix timestamp, timezone, \
-                    day of week, day of year, week number, and quarter.".to_string(),
+                    day of week, day of year, week number, and quarter. With fiscal_calendar, \
+                    also returns fiscal year, quarter, period, and week.".to_string(),
             },
             ToolInstruction {
                 name: "ucm_parse".to_string(),
@@ -46,9 +49,214 @@ pub fn ucm_instructions() -> InstructionsResponse {
             ToolInstruction {
                 name: "ucm_info".to_string(),
                 description: "Get detailed information about a specific date.".to_string(),
-                parameters: "date: String - natural language or ISO format".to_string(),
+                parameters: "date: String - natural language or ISO format; \
+                    fiscal_calendar: Option<String> - fiscal profile or starting month; \
+                    retail_calendar: Option<String> - 'nrf' or retail spec".to_string(),
                 example: "ucm_info('2026-07-04') returns day_of_week, quarter, is_leap_year, \
-                    days_in_month, is_weekend, and days_from_now.".to_string(),
+                    days_in_month, is_weekend, days_from_now, moon_phase and moon_illumination. \
+                    ucm_info('FY27 Q2', 'us_federal') adds fiscal year/quarter/period/week.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_era".to_string(),
+                description: "Convert between Gregorian dates and Japanese imperial eras (Meiji through Reiwa).".to_string(),
+                parameters: "date: String - Gregorian or era date, e.g., '2026-10-18', '令和8年10月18日', 'R8.10.18'".to_string(),
+                example: "ucm_era('R8.10.18') returns iso '2026-10-18' plus era name, kanji, \
+                    era year, and formatted '令和8年10月18日'.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_retail".to_string(),
+                description: "Map dates to and from retail 52/53-week calendars (4-4-5, 4-5-4, 5-4-4).".to_string(),
+                parameters: "calendar: String - 'nrf' or e.g. '4-5-4 last saturday january'; \
+                    date: Option<String>; or year: Option<i32>, period: Option<u32>, week: Option<u32>".to_string(),
+                example: "ucm_retail('nrf', date='2024-02-01') returns retail year 2023, period 12, \
+                    week 53, is_53_week_year true. ucm_retail('nrf', year=2026, period=3) returns \
+                    that period's start_date and end_date.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_week".to_string(),
+                description: "Convert dates to and from ISO, US (Sunday-start) and broadcast week numbers.".to_string(),
+                parameters: "date: Option<String>; or year: Option<i32>, week: Option<u32>; \
+                    system: Option<String> (iso, us, broadcast); add_weeks: Option<i64>; \
+                    align: Option<String> (weekday, 'start', 'end')".to_string(),
+                example: "ucm_week(date='today', add_weeks=3, align='monday') returns the Monday of \
+                    the week 3 weeks from now, with its ISO week date and week ranges in all systems.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_sun".to_string(),
+                description: "Get sunrise, sunset, solar noon, day length and twilight times, computed offline.".to_string(),
+                parameters: "date: String; city: Option<String> or latitude/longitude: Option<f64>; \
+                    timezone: Option<String> (IANA name)".to_string(),
+                example: "ucm_sun('tomorrow', city='Denver') returns sunrise, sunset and civil/nautical/\
+                    astronomical twilight in America/Denver time. daylight is 'polar_day' or \
+                    'polar_night' when the sun does not rise or set.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_astro_events".to_string(),
+                description: "List moon phases, equinoxes, solstices and eclipses in a date range.".to_string(),
+                parameters: "from: String; to: Option<String> (default one year later); \
+                    kinds: Option<Vec<String>> (moon_phases, seasons, eclipses, all, or e.g. full_moon); \
+                    timezone: Option<String>".to_string(),
+                example: "ucm_astro_events('2026-10-18', kinds=['full_moon']) lists full moons for \
+                    the next year; the first is the next full moon. Each event has days_from_now.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_batch".to_string(),
+                description: "Run many parse, diff, add, info or convert operations in one call.".to_string(),
+                parameters: "operations: Vec<{tool, params}> - at most 100; string params may use \
+                    $N.field to insert a field of result N (1-based)".to_string(),
+                example: "ucm_batch([{tool: 'add', params: {date: '2026-01-13', add: '3 weeks'}}, \
+                    {tool: 'info', params: {date: '$1.result_date'}}]) returns results in order; \
+                    a failed operation has ok false and an error, and the rest still run.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_eval".to_string(),
+                description: "Evaluate a date expression with +, -, *, /, functions and 'in <unit>'.".to_string(),
+                parameters: "expression: String - dates (ISO or natural language), durations \
+                    ('3 weeks', '30d', '2 business days'), numbers; functions start_of_/end_of_ \
+                    day, week, month, quarter, half, year, fiscal_year, min, max, days_in_month, \
+                    business_days".to_string(),
+                example: "ucm_eval('end_of_month(today) - 1 week') returns a date; \
+                    ucm_eval('(2026-12-25 - today) in weeks') returns a number. Errors include the \
+                    span of the offending part.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_period".to_string(),
+                description: "Get the start and end of the period containing a date, or the next/previous one.".to_string(),
+                parameters: "date: Option<String> (default today); unit: Option<String> (day, week, \
+                    iso_week, us_week, month, quarter, half, year, fiscal_year); offset: Option<i32>; \
+                    week_start: Option<String>; fiscal_calendar: Option<String>".to_string(),
+                example: "ucm_period(unit='quarter', offset=1) returns next quarter's start and end; \
+                    ucm_period('last day of February 2028') works without a unit. ucm_parse also \
+                    understands 'end of this month', 'beginning of Q3' and 'EOW'.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_nth_weekday".to_string(),
+                description: "Find the nth or last weekday, business day or day of a period, or counted from a date.".to_string(),
+                parameters: "expression: String; country: Option<String> - US, GB, CA or AU to skip \
+                    public holidays when counting business days".to_string(),
+                example: "ucm_nth_weekday('3rd Thursday of November 2026') returns 2026-11-19; \
+                    'last Friday of each month in 2027' returns twelve dates, each with its period.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_age".to_string(),
+                description: "Get an exact age or tenure, the next birthday or anniversary, and when an age is reached.".to_string(),
+                parameters: "date: String; as_of: Option<String> (default today); turns: Option<u32>; \
+                    leap_day: Option<String> (february_28 or march_1); anniversaries: Option<u32> (default 3)".to_string(),
+                example: "ucm_age('1961-11-02', turns=65) returns years, a years/months/days breakdown, \
+                    next_anniversary with days_from_as_of, and the date of the 65th birthday.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_mark_set".to_string(),
+                description: "Save a named date that persists across conversations; any tool then \
+                    accepts '@name' in place of a date.".to_string(),
+                parameters: "name: String (letters, digits, '_' or '-'); date: String; note: Option<String>".to_string(),
+                example: "ucm_mark_set('launch', '2026-12-01') then ucm_diff('today', '@launch') counts \
+                    the days to launch.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_mark_list".to_string(),
+                description: "List saved dates, soonest first, with days_from_now and a countdown.".to_string(),
+                parameters: "None".to_string(),
+                example: "Returns each mark's name, date, days_from_now, countdown ('in 6 weeks, 2 days') \
+                    and note.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_mark_delete".to_string(),
+                description: "Delete a saved date.".to_string(),
+                parameters: "name: String ('launch' or '@launch')".to_string(),
+                example: "ucm_mark_delete('launch') returns the deleted mark.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_event_create".to_string(),
+                description: "Create an event in the local calendar; the response lists overlapping \
+                    events over the next year.".to_string(),
+                parameters: "title: String; start: String; end or duration: Option<String> (default 1 hour); \
+                    all_day: Option<bool>; timezone: Option<String> (IANA); recurrence: Option<String> \
+                    (RRULE or 'weekly', 'weekdays', 'every 2 weeks'); tags: Option<Vec<String>>; note: Option<String>".to_string(),
+                example: "ucm_event_create('Standup', '2026-10-19 09:00', duration='15 minutes', \
+                    recurrence='weekdays') returns the event with id 'evt-1', next_start and conflicts.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_event_update".to_string(),
+                description: "Change an event's fields; a new start keeps the event's length.".to_string(),
+                parameters: "id: String; any ucm_event_create field; recurrence 'none' makes it one-off".to_string(),
+                example: "ucm_event_update('evt-2', start='2026-10-20 11:00') moves the event and reports conflicts.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_event_delete".to_string(),
+                description: "Delete an event with all its occurrences.".to_string(),
+                parameters: "id: String".to_string(),
+                example: "ucm_event_delete('evt-2') returns the deleted event.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_event_list".to_string(),
+                description: "List event occurrences on a day, in a period or between dates, with overlaps.".to_string(),
+                parameters: "when: Option<String> ('next tuesday', 'this week'); from, to: Option<String> \
+                    (default the next 7 days); tag: Option<String>".to_string(),
+                example: "ucm_event_list(when='next tuesday') answers 'what do I have next Tuesday' with \
+                    occurrences and conflicts.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_event_export".to_string(),
+                description: "Export events as iCalendar text to import into other calendar apps.".to_string(),
+                parameters: "tag: Option<String>".to_string(),
+                example: "ucm_event_export() returns count and ics; save ics as a .ics file.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_free_slots".to_string(),
+                description: "Find free time of at least a duration in working hours, around busy time \
+                    from inline intervals, an .ics file in the data directory and stored events.".to_string(),
+                parameters: "duration: String; when or from/to: Option<String>; busy: Option<Vec<String>> \
+                    ('start/end'); ics_file: Option<String>; include_events: Option<bool>; working_hours, \
+                    working_days, timezone, lunch, buffer_before, buffer_after, country: Option<String>; \
+                    limit: Option<usize>".to_string(),
+                example: "ucm_free_slots('90 minutes', when='this week', lunch='12:00-13:00', \
+                    buffer_after='15 minutes') answers 'when can I fit a 90-minute review this week'.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_remind".to_string(),
+                description: "Save a reminder. While the server is connected it is sent as a log message \
+                    from the 'ucm.reminders' logger when it falls due.".to_string(),
+                parameters: "when: String ('tomorrow at 9am', RFC 3339, or 'in 20 minutes'); message: String"
+                    .to_string(),
+                example: "ucm_remind('in 25 minutes', 'Stretch') returns id 'rem-1' and the due time.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_reminders".to_string(),
+                description: "List pending reminders or cancel one. Reminders that fell due with no client \
+                    connected are listed under 'missed' until the next client is sent them.".to_string(),
+                parameters: "cancel: Option<String> (reminder id)".to_string(),
+                example: "ucm_reminders(cancel='rem-1') cancels it and lists the rest.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_cron".to_string(),
+                description: "Explain a cron expression in English and list its next or previous runs in a \
+                    time zone. Accepts 5 fields, 6 with seconds, Quartz ('?', year, Sunday = 1), L, W, # \
+                    and @daily-style macros.".to_string(),
+                parameters: "expression: String; count: Option<usize> (default 5); timezone: Option<String>; \
+                    from: Option<String>; previous: Option<bool>".to_string(),
+                example: "ucm_cron('30 2 * * *', timezone='America/New_York', from='2026-03-07') shows the \
+                    8 March run moved to 03:00 by the DST gap, with a dst_note.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_schedule_translate".to_string(),
+                description: "Translate a schedule between cron, RRULE (with DTSTART) and English. Each \
+                    translation says whether it is exact, notes what the notation cannot express, and lists \
+                    its first occurrences next to the source's.".to_string(),
+                parameters: "schedule: String; source, target: Option<String> (cron, rrule, english); \
+                    timezone: Option<String>; start: Option<String>; samples: Option<usize> (default 5)".to_string(),
+                example: "ucm_schedule_translate('every 2nd Tuesday at 09:00 Europe/London') gives \
+                    FREQ=WEEKLY;INTERVAL=2;BYDAY=TU, and cron '0 9 * * TUE' noted as not exact.".to_string(),
+            },
+            ToolInstruction {
+                name: "ucm_timestamp".to_string(),
+                description: "Convert a raw timestamp to a date and time, or a date and time to raw \
+                    timestamps: Unix s/ms/us/ns, Windows FILETIME, .NET ticks, Excel/Lotus serials, Mac HFS+, \
+                    NTP and GPS. Without a unit, it is detected from the number's size and other plausible \
+                    readings are listed.".to_string(),
+                parameters: "value: String; unit: Option<String>; timezone: Option<String>".to_string(),
+                example: "ucm_timestamp('1792402200250') reads Unix milliseconds as 2026-10-19T09:30:00.250Z \
+                    and gives the FILETIME 134368758002500000.".to_string(),
             },
             ToolInstruction {
                 name: "ucm_status".to_string(),
@@ -65,17 +273,33 @@ pub fn ucm_instructions() -> InstructionsResponse {
         ],
 
         response_format: "All tools return JSON responses. Success responses contain the \
-            requested data. Error responses contain 'error' (code), 'message' (description), \
-            and optionally 'input' (the problematic value).".to_string(),
+            requested data as structured content matching the tool's output schema, with the \
+            same JSON as text. Error responses have isError set and contain 'error' (code), \
+            'message' (description), and optionally 'input' (the problematic value) and 'span' \
+            (start and end character offsets of the offending part of an expression).".to_string(),
+
+        workflows: PROMPTS
+            .iter()
+            .map(|prompt| format!("{}: {}", prompt.name, prompt.description))
+            .collect(),
 
         tips: vec![
-            "Use ucm_now first to establish the current date context.".to_string(),
+            "Multi-step tasks (meetings across time zones, business-day deadlines, ages and \
+                anniversaries, countdowns) are MCP prompts, listed under workflows: get the \
+                prompt for the tool calls to make, in order.".to_string(),
             "Natural language parsing supports: today, tomorrow, yesterday, next/last weekday, \
                 month names, relative expressions (in X days, X ago).".to_string(),
             "ISO dates (YYYY-MM-DD) are always accepted and recommended for precision.".to_string(),
+            "ISO week dates ('2026-W43-4', '2026-W43') are accepted anywhere a date is.".to_string(),
+            "Japanese era dates ('令和8年10月18日', 'R8.10.18', 'Heisei 31') are accepted \
+                anywhere a date is.".to_string(),
+            "Fiscal expressions ('FY27 Q2', 'end of fiscal Q3', 'start of next fiscal year') \
+                use the UCM_FISCAL_CALENDAR profile unless a fiscal_calendar is given.".to_string(),
+            "Dates saved with ucm_mark_set can be used anywhere a date is as '@name', \
+                including in ucm_eval ('@launch - 2 weeks').".to_string(),
             "Duration units can be singular or plural (day/days, week/weeks).".to_string(),
-            "Negative durations work with ucm_add: '-5 days' subtracts 5 days.".to_string(),
-            "ucm_diff returns both exact total_days and human-friendly breakdown.".to_string(),
+            "MCP resources give context without tool calls: ucm://now, ucm://timezones, \
+                ucm://calendar/{year}/{month}, ucm://holidays/{country}/{year} (US, GB, CA, AU).".to_string(),
         ],
     }
 }
@@ -88,7 +312,8 @@ mod tests {
     fn test_instructions_returns_valid_response() {
         let response = ucm_instructions();
         assert!(!response.overview.is_empty());
-        assert_eq!(response.tools.len(), 8);
+        assert_eq!(response.tools.len(), 32);
+        assert_eq!(response.workflows.len(), PROMPTS.len());
         assert!(!response.tips.is_empty());
     }
 }
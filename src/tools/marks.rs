@@ -0,0 +1,89 @@
+use crate::engine::Ucm;
+use crate::store::Mark;
+use crate::types::{DurationBreakdown, MarkDeleteResponse, MarkInfo, MarkListResponse, MarkSetResponse, UcmError};
+
+/// Save a named date, replacing any date already saved under the name
+pub fn ucm_mark_set(ucm: &Ucm, name: &str, date: &str, note: Option<&str>) -> Result<MarkSetResponse, UcmError> {
+    let mark = Mark {
+        name: name.to_string(),
+        date: ucm.parse_date(date)?,
+        note: note.map(str::trim).filter(|n| !n.is_empty()).map(str::to_string),
+    };
+    let marks = ucm.marks()?;
+    let replaced = marks.set(mark)?;
+    Ok(MarkSetResponse {
+        mark: mark_info(ucm, marks.get(name)?),
+        replaced: replaced.map(|previous| previous.date.format("%Y-%m-%d").to_string()),
+    })
+}
+
+/// Every saved date with its countdown
+pub fn ucm_mark_list(ucm: &Ucm) -> Result<MarkListResponse, UcmError> {
+    let marks = ucm.marks()?;
+    let list: Vec<MarkInfo> = marks.list()?.into_iter().map(|mark| mark_info(ucm, mark)).collect();
+    Ok(MarkListResponse {
+        data_file: marks.path().display().to_string(),
+        count: list.len(),
+        marks: list,
+    })
+}
+
+pub fn ucm_mark_delete(ucm: &Ucm, name: &str) -> Result<MarkDeleteResponse, UcmError> {
+    let deleted = ucm.marks()?.delete(name)?;
+    Ok(MarkDeleteResponse {
+        deleted: mark_info(ucm, deleted),
+    })
+}
+
+fn mark_info(ucm: &Ucm, mark: Mark) -> MarkInfo {
+    let today = ucm.today();
+    let days_from_now = (mark.date - today).num_days();
+    let span = DurationBreakdown::between_dates(today, mark.date).to_string_breakdown();
+    let countdown = match days_from_now {
+        0 => "today".to_string(),
+        d if d > 0 => format!("in {}", span),
+        _ => format!("{} ago", span),
+    };
+    MarkInfo {
+        name: mark.name,
+        date: mark.date.format("%Y-%m-%d").to_string(),
+        day_of_week: mark.date.format("%A").to_string(),
+        days_from_now,
+        is_past: days_from_now < 0,
+        countdown,
+        note: mark.note,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    use crate::engine::Value;
+    use crate::tools::ucm_diff;
+    use crate::types::ErrorKind;
+
+    #[test]
+    fn test_marks_resolve_in_other_tools() {
+        let dir = std::env::temp_dir().join(format!("ucm-mark-tools-{}", std::process::id()));
+        let ucm = Ucm::new().at_reference(Some("2026-10-18")).unwrap().with_data_dir(&dir);
+
+        let set = ucm_mark_set(&ucm, "launch", "2026-12-25", Some("v2 launch")).unwrap();
+        assert_eq!(set.mark.days_from_now, 68);
+        assert_eq!(set.mark.countdown, "in 2 months, 1 week");
+        assert_eq!(ucm_mark_set(&ucm, "@Launch", "2026-12-24", None).unwrap().replaced.as_deref(), Some("2026-12-25"));
+
+        assert_eq!(ucm_diff(&ucm, "today", "@launch").unwrap().total_days, 67);
+        let week_before = NaiveDate::from_ymd_opt(2026, 12, 17).unwrap();
+        assert!(matches!(ucm.eval("@launch - 1 week").unwrap(), Value::Date(date) if date == week_before));
+        assert_eq!(ucm_mark_list(&ucm).unwrap().count, 1);
+
+        ucm_mark_delete(&ucm, "launch").unwrap();
+        assert_eq!(ucm.parse_date("@launch").unwrap_err().error, ErrorKind::InvalidReference);
+        std::fs::remove_dir_all(dir).unwrap();
+
+        let error = Ucm::new().parse_date("@launch").unwrap_err();
+        assert_eq!(error.error, ErrorKind::StorageError);
+    }
+}
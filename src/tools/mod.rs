@@ -6,6 +6,23 @@ pub mod convert;
 pub mod info;
 pub mod status;
 pub mod instructions;
+pub mod era;
+pub mod retail;
+pub mod week;
+pub mod sun;
+pub mod astro_events;
+pub mod batch;
+pub mod eval;
+pub mod period;
+pub mod nth_weekday;
+pub mod age;
+pub mod marks;
+pub mod events;
+pub mod free_slots;
+pub mod reminders;
+pub mod cron;
+pub mod schedule_translate;
+pub mod timestamp;
 
 pub use now::ucm_now;
 pub use parse::ucm_parse;
@@ -15,3 +32,20 @@ pub use convert::ucm_convert;
 pub use info::ucm_info;
 pub use status::ucm_status;
 pub use instructions::ucm_instructions;
+pub use era::ucm_era;
+pub use retail::ucm_retail;
+pub use week::ucm_week;
+pub use sun::ucm_sun;
+pub use astro_events::ucm_astro_events;
+pub use batch::ucm_batch;
+pub use eval::ucm_eval;
+pub use period::ucm_period;
+pub use nth_weekday::ucm_nth_weekday;
+pub use age::ucm_age;
+pub use marks::{ucm_mark_delete, ucm_mark_list, ucm_mark_set};
+pub use events::{ucm_event_create, ucm_event_delete, ucm_event_export, ucm_event_list, ucm_event_update};
+pub use free_slots::ucm_free_slots;
+pub use reminders::{ucm_remind, ucm_reminders};
+pub use cron::ucm_cron;
+pub use schedule_translate::ucm_schedule_translate;
+pub use timestamp::ucm_timestamp;
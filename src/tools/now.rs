@@ -1,12 +1,19 @@
-use chrono::{Datelike, Local};
+use chrono::Datelike;
 
-use crate::types::NowResponse;
+use crate::engine::Ucm;
+use crate::tools::info::{fiscal_info, resolve_fiscal_calendar};
+use crate::types::{NowResponse, UcmError};
 
-/// Get the current date and time
-pub fn ucm_now() -> NowResponse {
-    let now = Local::now();
+/// Get the current date and time, optionally within a fiscal calendar
+pub fn ucm_now(ucm: &Ucm, fiscal_calendar: Option<&str>) -> Result<NowResponse, UcmError> {
+    let fiscal = fiscal_calendar.map(resolve_fiscal_calendar).transpose()?;
+    let now = ucm.now();
+    let fiscal = fiscal
+        .map(|calendar| calendar.locate(now.date_naive()).map(|located| fiscal_info(&calendar.name, &located)))
+        .transpose()
+        .map_err(|e| UcmError::out_of_range(&now.format("%Y-%m-%d").to_string(), &e.to_string()))?;
 
-    NowResponse {
+    Ok(NowResponse {
         iso: now.to_rfc3339(),
         unix: now.timestamp(),
         timezone: now.format("%Z").to_string(),
@@ -15,8 +22,10 @@ pub fn ucm_now() -> NowResponse {
         day_of_week: now.format("%A").to_string(),
         day_of_year: now.ordinal(),
         week_of_year: now.iso_week().week(),
+        iso_week_year: now.iso_week().year(),
         quarter: ((now.month() - 1) / 3) + 1,
-    }
+        fiscal,
+    })
 }
 
 #[cfg(test)]
@@ -25,10 +34,21 @@ mod tests {
 
     #[test]
     fn test_now_returns_valid_response() {
-        let response = ucm_now();
-        assert!(!response.iso.is_empty());
-        assert!(response.unix > 0);
-        assert!(response.day_of_year >= 1 && response.day_of_year <= 366);
-        assert!(response.quarter >= 1 && response.quarter <= 4);
+        let ucm = Ucm::new().at_reference(Some("2026-10-19T09:30:00+00:00")).unwrap();
+        let response = ucm_now(&ucm, None).unwrap();
+        assert_eq!(response.iso, "2026-10-19T09:30:00+00:00");
+        assert_eq!(response.unix, 1792402200);
+        assert_eq!((response.day_of_week.as_str(), response.day_of_year), ("Monday", 292));
+        assert_eq!((response.week_of_year, response.quarter), (43, 4));
+        assert!(response.fiscal.is_none());
+    }
+
+    #[test]
+    fn test_now_fiscal_calendar() {
+        let ucm = Ucm::new().at_reference(Some("2026-03-01T09:00:00+11:00")).unwrap();
+        let response = ucm_now(&ucm, Some("australia")).unwrap();
+        assert_eq!(response.iso, "2026-03-01T09:00:00+11:00");
+        assert_eq!(response.fiscal.unwrap().fiscal_quarter, 3);
+        assert!(ucm_now(&ucm, Some("lunar")).is_err());
     }
 }
@@ -0,0 +1,154 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::calendar::holidays::find_country;
+use crate::calendar::nth::{nth_from, nth_in_range, ordinal_name};
+use crate::calendar::period::PeriodUnit;
+use crate::engine::Ucm;
+use crate::parser::fiscal::parse_fiscal_range;
+use crate::parser::nth::{ordinal, parse_nth, NthScope};
+use crate::parser::period::parse_period;
+use crate::types::{NthDate, NthWeekdayResponse, UcmError};
+
+/// Most periods a single "each month" query may cover
+const MAX_PERIODS: i32 = 520;
+
+/// Find the nth weekday, business day or day of a period, of each period in
+/// a range, or counted from a date
+pub fn ucm_nth_weekday(ucm: &Ucm, expression: &str, country: Option<&str>) -> Result<NthWeekdayResponse, UcmError> {
+    let query = parse_nth(expression).map_err(|e| UcmError::parse_error(expression, &e.to_string()))?;
+    let holidays = country
+        .map(|code| find_country(code).map_err(|e| UcmError::invalid_calendar(code, &e.to_string())))
+        .transpose()?;
+    let matches = |date: NaiveDate| query.kind.matches(date, holidays);
+    let missing = |within: &str| {
+        UcmError::out_of_range(
+            expression,
+            &format!("{} has no {} {}", within, ordinal_name(query.n), query.kind.name()),
+        )
+    };
+
+    let mut dates = Vec::new();
+    let mut truncated = false;
+    match &query.scope {
+        NthScope::Period(period) => {
+            let (start, end) = period_range(ucm, period)?;
+            let date = nth_in_range(start, end, query.n, matches).ok_or_else(|| missing(period))?;
+            dates.push(nth_date(ucm, date, None));
+        }
+        NthScope::Each { unit, within } => {
+            let (start, end) = match within {
+                Some(within) => period_range(ucm, within)?,
+                None => ucm.period(PeriodUnit::Year, ucm.today(), 0)?,
+            };
+            for offset in 0..MAX_PERIODS {
+                let (period_start, period_end) = ucm.period(*unit, start, offset)?;
+                if period_start > end {
+                    break;
+                }
+                let label = unit.label(period_start, ucm.fiscal_calendar());
+                if let Some(date) = nth_in_range(period_start.max(start), period_end.min(end), query.n, matches) {
+                    dates.push(nth_date(ucm, date, Some(label)));
+                }
+            }
+            truncated = ucm.period(*unit, start, MAX_PERIODS).is_ok_and(|(next, _)| next <= end);
+        }
+        NthScope::From { date, forwards, inclusive } => {
+            let from = anchor_date(ucm, date)?;
+            let n = if *forwards { query.n.abs() } else { -query.n.abs() };
+            let date = nth_from(from, n, *inclusive, matches).ok_or_else(|| missing(date))?;
+            dates.push(nth_date(ucm, date, None));
+        }
+    }
+
+    Ok(NthWeekdayResponse {
+        expression: expression.to_string(),
+        count: dates.len(),
+        truncated,
+        dates,
+    })
+}
+
+/// First and last day of a fiscal expression, a period ("November 2026",
+/// "the quarter"), or the month containing a date
+fn period_range(ucm: &Ucm, text: &str) -> Result<(NaiveDate, NaiveDate), UcmError> {
+    let invalid = |e: anyhow::Error| UcmError::parse_error(text, &e.to_string());
+    if let Some(range) = parse_fiscal_range(text, ucm.fiscal_calendar(), ucm.today()).map_err(invalid)? {
+        return Ok(range);
+    }
+    if let Some(period) = parse_period(text, ucm.fiscal_calendar(), ucm.today(), Weekday::Mon).map_err(invalid)? {
+        return Ok((period.start, period.end));
+    }
+    ucm.period(PeriodUnit::Month, ucm.parse_date(text)?, 0)
+}
+
+/// "the 15th" (this month), "15th of March 2027", or any date expression
+fn anchor_date(ucm: &Ucm, text: &str) -> Result<NaiveDate, UcmError> {
+    let tokens: Vec<&str> = text.split_whitespace().filter(|t| *t != "the").collect();
+    let Some(day) = tokens.first().and_then(|t| ordinal(t)) else {
+        return ucm.parse_date(text);
+    };
+
+    let month_start = match &tokens[1..] {
+        [] => ucm.period(PeriodUnit::Month, ucm.today(), 0)?.0,
+        ["of", month @ ..] | month => period_range(ucm, &month.join(" "))?.0,
+    };
+    NaiveDate::from_ymd_opt(month_start.year(), month_start.month(), day as u32).ok_or_else(|| {
+        UcmError::out_of_range(text, &format!("{} has no day {}", month_start.format("%B %Y"), day))
+    })
+}
+
+fn nth_date(ucm: &Ucm, date: NaiveDate, period: Option<String>) -> NthDate {
+    let days_from_now = (date - ucm.today()).num_days();
+    NthDate {
+        iso: date.format("%Y-%m-%d").to_string(),
+        unix: ucm.start_of_day(date).timestamp(),
+        date: date.format("%Y-%m-%d").to_string(),
+        day_of_week: date.format("%A").to_string(),
+        days_from_now,
+        is_past: days_from_now < 0,
+        period,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::fiscal::FiscalCalendar;
+    use crate::types::ErrorKind;
+
+    fn ucm() -> Ucm {
+        Ucm::new().at_reference(Some("2026-10-18")).unwrap()
+    }
+
+    fn first(expression: &str) -> String {
+        ucm_nth_weekday(&ucm(), expression, None).unwrap().dates[0].date.clone()
+    }
+
+    #[test]
+    fn test_nth_weekday_queries() {
+        assert_eq!(first("3rd Thursday of November 2026"), "2026-11-19");
+        assert_eq!(first("second-to-last business day of the quarter"), "2026-12-30");
+        assert_eq!(first("first Monday after the 15th"), "2026-10-19");
+
+        let federal = ucm().with_fiscal_calendar(FiscalCalendar::from_name("us_federal").unwrap());
+        let result = ucm_nth_weekday(&federal, "last day of FY27 Q2", None).unwrap();
+        assert_eq!(result.dates[0].date, "2027-03-31");
+
+        let result = ucm_nth_weekday(&ucm(), "last Friday of each month in 2027", None).unwrap();
+        assert_eq!((result.count, result.truncated), (12, false));
+        assert_eq!(result.dates[0].date, "2027-01-29");
+        assert_eq!(result.dates[11].period.as_deref(), Some("2027-12"));
+    }
+
+    #[test]
+    fn test_nth_weekday_holidays_and_errors() {
+        let result = ucm_nth_weekday(&ucm(), "last business day of December 2027", Some("US")).unwrap();
+        // New Year's Day 2028 is a Saturday, observed on Friday 2027-12-31
+        assert_eq!(result.dates[0].date, "2027-12-30");
+
+        let error = ucm_nth_weekday(&ucm(), "5th Thursday of November 2026", None).unwrap_err();
+        assert_eq!(error.error, ErrorKind::OutOfRange);
+        let error = ucm_nth_weekday(&ucm(), "3rd Thursday", None).unwrap_err();
+        assert_eq!(error.error, ErrorKind::ParseError);
+    }
+}
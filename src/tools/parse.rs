@@ -1,23 +1,14 @@
-use chrono::{Local, NaiveTime};
-
-use crate::parser::parse_to_date;
+use crate::engine::Ucm;
 use crate::types::{ParseResponse, UcmError};
 
 /// Parse a natural language date expression
-pub fn ucm_parse(expression: &str) -> Result<ParseResponse, UcmError> {
-    let parsed_date = parse_to_date(expression).map_err(|e| {
-        UcmError::parse_error(expression, &e.to_string())
-    })?;
-
-    let today = Local::now().naive_local().date();
-    let days_from_now = (parsed_date - today).num_days();
-
-    // Create datetime at midnight for unix timestamp
-    let datetime = parsed_date.and_time(NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+pub fn ucm_parse(ucm: &Ucm, expression: &str) -> Result<ParseResponse, UcmError> {
+    let parsed_date = ucm.parse_date(expression)?;
+    let days_from_now = (parsed_date - ucm.today()).num_days();
 
     Ok(ParseResponse {
         iso: parsed_date.format("%Y-%m-%d").to_string(),
-        unix: datetime.and_local_timezone(Local).unwrap().timestamp(),
+        unix: ucm.start_of_day(parsed_date).timestamp(),
         date: parsed_date.format("%Y-%m-%d").to_string(),
         day_of_week: parsed_date.format("%A").to_string(),
         days_from_now,
@@ -30,23 +21,34 @@ pub fn ucm_parse(expression: &str) -> Result<ParseResponse, UcmError> {
 mod tests {
     use super::*;
 
+    fn ucm() -> Ucm {
+        Ucm::new().at_reference(Some("2026-03-01T12:00:00+00:00")).unwrap()
+    }
+
     #[test]
     fn test_parse_today() {
-        let result = ucm_parse("today").unwrap();
+        let result = ucm_parse(&ucm(), "today").unwrap();
+        assert_eq!(result.date, "2026-03-01");
         assert_eq!(result.days_from_now, 0);
         assert!(!result.is_past);
     }
 
     #[test]
     fn test_parse_yesterday() {
-        let result = ucm_parse("yesterday").unwrap();
+        let result = ucm_parse(&ucm(), "yesterday").unwrap();
+        assert_eq!(result.date, "2026-02-28");
         assert_eq!(result.days_from_now, -1);
         assert!(result.is_past);
     }
 
+    #[test]
+    fn test_parse_as_of_reference() {
+        assert_eq!(ucm_parse(&ucm(), "2026-12-25").unwrap().days_from_now, 299);
+    }
+
     #[test]
     fn test_parse_invalid() {
-        let result = ucm_parse("flurbnesday");
+        let result = ucm_parse(&ucm(), "flurbnesday");
         assert!(result.is_err());
     }
 }
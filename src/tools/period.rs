@@ -0,0 +1,99 @@
+use chrono::Weekday;
+
+use crate::calendar::period::PeriodUnit;
+use crate::engine::Ucm;
+use crate::parser::period::parse_period;
+use crate::tools::info::resolve_fiscal_calendar;
+use crate::types::{PeriodResponse, UcmError};
+
+/// Start and end of the period containing a date, or one `offset` periods
+/// away. Without a unit, `date` must name a period ("next quarter",
+/// "end of this month", "Q3 2027").
+pub fn ucm_period(
+    ucm: &Ucm,
+    date: Option<&str>,
+    unit: Option<&str>,
+    offset: Option<i32>,
+    week_start: Option<&str>,
+    fiscal_calendar: Option<&str>,
+) -> Result<PeriodResponse, UcmError> {
+    let week_start = match week_start {
+        Some(name) => name.trim().parse::<Weekday>().map_err(|_| {
+            UcmError::parse_error(name, "Week start must be a weekday name, e.g. 'monday' or 'sunday'")
+        })?,
+        None => Weekday::Mon,
+    };
+    let ucm = match fiscal_calendar {
+        Some(name) => ucm.clone().with_fiscal_calendar(resolve_fiscal_calendar(name)?),
+        None => ucm.clone(),
+    };
+    let offset = offset.unwrap_or(0);
+
+    let (unit, date) = match unit {
+        Some(name) => {
+            let unit = PeriodUnit::from_name(name, week_start)
+                .map_err(|e| UcmError { message: e.to_string(), ..UcmError::invalid_unit(name) })?;
+            (unit, ucm.parse_date(date.unwrap_or("today"))?)
+        }
+        None => {
+            let expression = date.ok_or_else(|| {
+                UcmError::missing_parameter("unit", "Provide a unit, or a date naming a period such as 'next quarter'")
+            })?;
+            let period = parse_period(expression, ucm.fiscal_calendar(), ucm.today(), week_start)
+                .map_err(|e| UcmError::parse_error(expression, &e.to_string()))?
+                .ok_or_else(|| {
+                    UcmError::missing_parameter(
+                        "unit",
+                        &format!("'{}' does not name a period; give a unit such as 'month' or 'quarter'", expression),
+                    )
+                })?;
+            (period.unit, period.start)
+        }
+    };
+
+    let (start, end) = ucm.period(unit, date, offset)?;
+    let today = ucm.today();
+
+    Ok(PeriodResponse {
+        unit: unit.name().to_string(),
+        label: unit.label(start, ucm.fiscal_calendar()),
+        start: start.format("%Y-%m-%d").to_string(),
+        end: end.format("%Y-%m-%d").to_string(),
+        days: (end - start).num_days() + 1,
+        start_day_of_week: start.format("%A").to_string(),
+        end_day_of_week: end.format("%A").to_string(),
+        is_current: (start..=end).contains(&today),
+        days_until_start: (start - today).num_days(),
+        days_until_end: (end - today).num_days(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ErrorKind;
+
+    fn ucm() -> Ucm {
+        Ucm::new().at_reference(Some("2026-10-18")).unwrap()
+    }
+
+    #[test]
+    fn test_period_by_unit() {
+        let result = ucm_period(&ucm(), Some("2026-10-18"), Some("week"), Some(1), Some("sunday"), None).unwrap();
+        assert_eq!((result.start.as_str(), result.end.as_str()), ("2026-10-25", "2026-10-31"));
+
+        let result = ucm_period(&ucm(), None, Some("fiscal_year"), None, None, Some("us_federal")).unwrap();
+        assert_eq!((result.label.as_str(), result.start.as_str()), ("FY2027", "2026-10-01"));
+        assert!(result.is_current);
+    }
+
+    #[test]
+    fn test_period_from_expression() {
+        let result = ucm_period(&ucm(), Some("next quarter"), None, None, None, None).unwrap();
+        assert_eq!((result.label.as_str(), result.end.as_str()), ("2027-Q1", "2027-03-31"));
+        assert_eq!(result.days, 90);
+
+        let error = ucm_period(&ucm(), Some("tomorrow"), None, None, None, None).unwrap_err();
+        assert_eq!(error.error, ErrorKind::MissingParameter);
+    }
+}
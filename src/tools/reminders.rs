@@ -0,0 +1,114 @@
+use chrono::{DateTime, FixedOffset};
+
+use crate::engine::Ucm;
+use crate::store::{Reminder, ReminderState};
+use crate::types::{RemindResponse, ReminderInfo, RemindersResponse, UcmError};
+
+/// Save a reminder for an absolute time ("tomorrow at 9am",
+/// "2026-10-20T09:00:00+02:00") or a relative one ("in 20 minutes")
+pub fn ucm_remind(ucm: &Ucm, when: &str, message: &str) -> Result<RemindResponse, UcmError> {
+    let message = message.trim();
+    if message.is_empty() {
+        return Err(UcmError::missing_parameter("message", "A reminder needs a message"));
+    }
+    let due = parse_due(ucm, when)?;
+    let now = ucm.now();
+    if due <= now {
+        return Err(UcmError::out_of_range(
+            when,
+            &format!("{} has already passed; reminders must be in the future", due.to_rfc3339()),
+        ));
+    }
+
+    let store = ucm.reminders()?;
+    let reminder = store.create(Reminder {
+        id: String::new(),
+        message: message.to_string(),
+        due,
+        created: now,
+        state: ReminderState::Pending,
+    })?;
+    Ok(RemindResponse {
+        reminder: reminder_info(ucm, &reminder),
+        pending: store.list()?.iter().filter(|r| r.state == ReminderState::Pending).count(),
+    })
+}
+
+/// Pending and missed reminders, optionally cancelling one first. Listing
+/// changes nothing: only delivery decides a reminder was missed, by the
+/// system clock rather than the reference time
+pub fn ucm_reminders(ucm: &Ucm, cancel: Option<&str>) -> Result<RemindersResponse, UcmError> {
+    let store = ucm.reminders()?;
+    let cancelled = cancel.map(|id| store.delete(id.trim())).transpose()?;
+    let reminders = store.list()?;
+    let in_state = |state| -> Vec<&Reminder> { reminders.iter().filter(|r| r.state == state).collect() };
+    let (missed, pending) = (in_state(ReminderState::Missed), in_state(ReminderState::Pending));
+    Ok(RemindersResponse {
+        cancelled: cancelled.map(|reminder| reminder_info(ucm, &reminder)),
+        missed: missed.iter().map(|reminder| reminder_info(ucm, reminder)).collect(),
+        count: pending.len(),
+        pending: pending.iter().map(|reminder| reminder_info(ucm, reminder)).collect(),
+    })
+}
+
+pub fn reminder_info(ucm: &Ucm, reminder: &Reminder) -> ReminderInfo {
+    ReminderInfo {
+        id: reminder.id.clone(),
+        message: reminder.message.clone(),
+        due: reminder.due.to_rfc3339(),
+        created: reminder.created.to_rfc3339(),
+        minutes_until: (reminder.due - ucm.now()).num_minutes(),
+    }
+}
+
+fn parse_due(ucm: &Ucm, when: &str) -> Result<DateTime<FixedOffset>, UcmError> {
+    let when = when.trim();
+    if let Ok(due) = DateTime::parse_from_rfc3339(when) {
+        return Ok(due);
+    }
+    if let Some(offset) = when.strip_prefix("in ").and_then(|rest| ucm.parse_duration(rest).ok()) {
+        return ucm.now().checked_add_signed(offset).ok_or_else(|| {
+            UcmError::out_of_range(when, "Result is outside the supported date range")
+        });
+    }
+    Ok(ucm.localize(ucm.parse_datetime(when)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ErrorKind;
+
+    #[test]
+    fn test_remind_cancel_and_list() {
+        let dir = std::env::temp_dir().join(format!("ucm-reminder-tools-{}", std::process::id()));
+        let ucm = Ucm::new().at_reference(Some("2026-10-18T09:00:00+00:00")).unwrap().with_data_dir(&dir);
+
+        let soon = ucm_remind(&ucm, "in 20 minutes", "Stand up").unwrap();
+        assert_eq!(soon.reminder.id, "rem-1");
+        assert_eq!(soon.reminder.due, "2026-10-18T09:20:00+00:00");
+        let later = ucm_remind(&ucm, "2026-10-19T08:00:00+00:00", "Call the bank").unwrap();
+        assert_eq!(later.pending, 2);
+        assert_eq!(ucm_remind(&ucm, "2026-10-17T08:00:00+00:00", "Too late").unwrap_err().error, ErrorKind::OutOfRange);
+        assert_eq!(ucm_remind(&ucm, "in 1000000000 days", "Too far").unwrap_err().error, ErrorKind::OutOfRange);
+
+        let list = ucm_reminders(&ucm, Some("rem-1")).unwrap();
+        assert_eq!(list.cancelled.unwrap().message, "Stand up");
+        assert_eq!(list.count, 1);
+        assert_eq!(ucm_reminders(&ucm, Some("rem-1")).unwrap_err().error, ErrorKind::InvalidReference);
+
+        // Listing as of a later time is only a view: the reminder stays pending
+        let tomorrow = ucm.clone().at_reference(Some("2026-10-20T12:00:00+00:00")).unwrap();
+        for _ in 0..2 {
+            let list = ucm_reminders(&tomorrow, None).unwrap();
+            assert_eq!((list.count, list.missed.len()), (1, 0));
+            assert_eq!(list.pending[0].minutes_until, -1680);
+        }
+
+        // Delivery found nobody to send it to
+        ucm.reminders().unwrap().claim_due(ucm.now() + chrono::Duration::days(2), false).unwrap();
+        let list = ucm_reminders(&ucm, None).unwrap();
+        assert_eq!((list.count, list.missed[0].message.as_str()), (0, "Call the bank"));
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
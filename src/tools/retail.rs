@@ -0,0 +1,124 @@
+use chrono::{Duration, NaiveDate};
+
+use crate::calendar::retail::RetailCalendar;
+use crate::engine::Ucm;
+use crate::types::{RetailInfo, RetailResponse, UcmError};
+
+/// Map a date to a retail 52/53-week calendar, or a retail year/period/week
+/// back to its dates
+pub fn ucm_retail(
+    ucm: &Ucm,
+    calendar: &str,
+    date: Option<&str>,
+    year: Option<i32>,
+    period: Option<u32>,
+    week: Option<u32>,
+) -> Result<RetailResponse, UcmError> {
+    let retail_calendar = resolve_retail_calendar(calendar)?;
+
+    let (start, end) = match (date, year) {
+        (Some(date), _) => {
+            let parsed_date = ucm.parse_date(date)?;
+            (parsed_date, parsed_date)
+        }
+        (None, Some(year)) => retail_range(&retail_calendar, year, period, week)
+            .map_err(|e| UcmError::out_of_range(&year.to_string(), &e.to_string()))?,
+        (None, None) => {
+            return Err(UcmError::missing_parameter(
+                "date",
+                "Provide either a date, or a retail year with optional period and week",
+            ))
+        }
+    };
+
+    let retail = retail_info(start, &retail_calendar)?;
+
+    Ok(RetailResponse {
+        start_date: start.format("%Y-%m-%d").to_string(),
+        end_date: end.format("%Y-%m-%d").to_string(),
+        retail,
+    })
+}
+
+/// Dates covered by a retail year, period, week of year, or week of period
+fn retail_range(
+    calendar: &RetailCalendar,
+    year: i32,
+    period: Option<u32>,
+    week: Option<u32>,
+) -> anyhow::Result<(NaiveDate, NaiveDate)> {
+    match (period, week) {
+        (Some(period), Some(week)) => {
+            let (period_start, period_end) = calendar.period_range(year, period)?;
+            let start = period_start + Duration::weeks(week.max(1) as i64 - 1);
+            if week == 0 || start > period_end {
+                return Err(anyhow::anyhow!(
+                    "Retail period {} of {} has no week {}",
+                    period,
+                    year,
+                    week
+                ));
+            }
+            Ok((start, start + Duration::days(6)))
+        }
+        (Some(period), None) => calendar.period_range(year, period),
+        (None, Some(week)) => calendar.week_range(year, week),
+        (None, None) => calendar.year_range(year),
+    }
+}
+
+/// Look up a retail calendar by name or spec
+pub fn resolve_retail_calendar(spec: &str) -> Result<RetailCalendar, UcmError> {
+    RetailCalendar::from_name(spec).map_err(|e| {
+        UcmError::invalid_calendar(spec, &e.to_string())
+    })
+}
+
+/// Build the retail fields for a date
+pub fn retail_info(date: NaiveDate, calendar: &RetailCalendar) -> Result<RetailInfo, UcmError> {
+    let retail = calendar.locate(date).map_err(|e| {
+        UcmError::out_of_range(&date.format("%Y-%m-%d").to_string(), &e.to_string())
+    })?;
+
+    Ok(RetailInfo {
+        calendar: calendar.name.clone(),
+        retail_year: retail.retail_year,
+        retail_quarter: retail.quarter,
+        retail_period: retail.period,
+        retail_week: retail.week,
+        week_of_period: retail.week_of_period,
+        weeks_in_year: retail.weeks_in_year,
+        is_53_week_year: retail.weeks_in_year == 53,
+        retail_year_start: retail.year_start.format("%Y-%m-%d").to_string(),
+        retail_year_end: retail.year_end.format("%Y-%m-%d").to_string(),
+        period_start: retail.period_start.format("%Y-%m-%d").to_string(),
+        period_end: retail.period_end.format("%Y-%m-%d").to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retail_from_date() {
+        let result = ucm_retail(&Ucm::new(), "4-5-4 last saturday january", Some("2026-10-18"), None, None, None).unwrap();
+        assert_eq!(result.retail.retail_year, 2026);
+        assert_eq!(result.retail.retail_period, 9);
+        assert!(!result.retail.is_53_week_year);
+    }
+
+    #[test]
+    fn test_retail_period_week_to_dates() {
+        let result = ucm_retail(&Ucm::new(), "nrf", None, Some(2023), Some(12), Some(5)).unwrap();
+        assert_eq!(result.start_date, "2024-01-28");
+        assert_eq!(result.end_date, "2024-02-03");
+        assert!(result.retail.is_53_week_year);
+    }
+
+    #[test]
+    fn test_retail_missing_input() {
+        assert!(ucm_retail(&Ucm::new(), "nrf", None, None, None, None).is_err());
+        assert!(ucm_retail(&Ucm::new(), "lunar", Some("2026-10-18"), None, None, None).is_err());
+    }
+}
@@ -0,0 +1,226 @@
+use chrono::{DateTime, Duration, FixedOffset, Local, NaiveDateTime};
+
+use crate::calendar::cron::{cron_for, CronRun, CronSchedule};
+use crate::calendar::schedule::Schedule;
+use crate::engine::Ucm;
+use crate::tools::sun::resolve_timezone;
+use crate::types::{ScheduleTranslateResponse, ScheduleTranslation, UcmError};
+
+const DEFAULT_SAMPLES: usize = 5;
+const MAX_SAMPLES: usize = 50;
+const NOTATIONS: [&str; 3] = ["cron", "rrule", "english"];
+
+/// Local times with their instants
+type Samples = Vec<(NaiveDateTime, DateTime<FixedOffset>)>;
+
+/// Translate a schedule between cron, RRULE and English, with the first
+/// occurrences of each to show whether they agree
+pub fn ucm_schedule_translate(
+    ucm: &Ucm,
+    schedule: &str,
+    source: Option<&str>,
+    target: Option<&str>,
+    timezone: Option<&str>,
+    start: Option<&str>,
+    samples: Option<usize>,
+) -> Result<ScheduleTranslateResponse, UcmError> {
+    let text = schedule.trim();
+    let source = match source {
+        Some(source) => notation(source)?,
+        None => detect(text),
+    };
+    let targets = match target {
+        Some(target) => vec![notation(target)?],
+        None => NOTATIONS.into_iter().filter(|n| *n != source).collect(),
+    };
+    let count = samples.unwrap_or(DEFAULT_SAMPLES);
+    if count == 0 || count > MAX_SAMPLES {
+        return Err(UcmError::out_of_range(&count.to_string(), &format!("samples must be 1 to {}", MAX_SAMPLES)));
+    }
+    let mut ucm = match resolve_timezone(timezone)? {
+        Some(tz) => ucm.clone().with_timezone(tz),
+        None => ucm.clone(),
+    };
+    let from = match start.map(str::trim) {
+        Some(text) => match DateTime::parse_from_rfc3339(text) {
+            Ok(instant) => instant,
+            Err(_) => ucm.localize(ucm.parse_datetime(text)?),
+        },
+        None => ucm.now(),
+    };
+    let never = || UcmError::out_of_range(text, &format!("The schedule never occurs after {}", from.to_rfc3339()));
+
+    // Every notation goes through a recurrence rule; `lossy` says what the rule misses of a cron source
+    let mut notes = Vec::new();
+    let (canonical, lossy, source_samples) = match source {
+        "cron" => {
+            let cron = CronSchedule::parse(text).map_err(|e| UcmError::parse_error(text, &e.to_string()))?;
+            let runs = cron_runs(&ucm, &cron, from - Duration::seconds(1), count);
+            let first = runs.first().ok_or_else(never)?;
+            let (rule, lossy) = cron.to_recurrence();
+            // The first run need not fit the rule when the rule is not exact
+            let start = rule.first_from(first.scheduled).unwrap_or(first.scheduled);
+            let canonical = Schedule { rule, start, timezone: ucm.timezone() };
+            (canonical, lossy, runs.iter().map(|run| (run.scheduled, run.at)).collect())
+        }
+        "rrule" => {
+            let mut canonical = Schedule::parse_ical(text, ucm.in_zone(from).naive_local().date())
+                .map_err(|e| UcmError::parse_error(text, &e.to_string()))?;
+            if !text.to_uppercase().contains("DTSTART") {
+                notes.push(format!("No DTSTART was given, so the rule starts {}", canonical.start.format("%Y-%m-%d %H:%M:%S")));
+            }
+            canonical.timezone = canonical.timezone.or(ucm.timezone());
+            ucm = zoned(&ucm, &canonical);
+            let samples = rule_samples(&ucm, &canonical, count);
+            (canonical, Vec::new(), samples)
+        }
+        _ => {
+            let mut canonical = Schedule::parse_english(text, ucm.in_zone(from).naive_local(), |date| Ok(ucm.parse_date(date)?))
+                .map_err(|e| UcmError::parse_error(text, &e.to_string()))?;
+            canonical.timezone = canonical.timezone.or(ucm.timezone());
+            ucm = zoned(&ucm, &canonical);
+            let samples = rule_samples(&ucm, &canonical, count);
+            (canonical, Vec::new(), samples)
+        }
+    };
+    if source_samples.is_empty() {
+        return Err(never());
+    }
+
+    let mut translations = Vec::new();
+    for notation in targets {
+        let mut translation_notes = lossy.clone();
+        let (text, samples) = match notation {
+            "cron" => {
+                let (expression, cron_notes) = cron_for(&canonical.rule, canonical.start);
+                translation_notes.extend(cron_notes);
+                let cron = CronSchedule::parse(&expression).map_err(|e| UcmError::parse_error(&expression, &e.to_string()))?;
+                let samples = cron_runs(&ucm, &cron, source_samples[0].1 - Duration::seconds(1), count);
+                (expression, samples.iter().map(|run| (run.scheduled, run.at)).collect())
+            }
+            "rrule" => (canonical.to_ical(), rule_samples(&ucm, &canonical, count)),
+            _ => {
+                let english = canonical.describe();
+                match Schedule::parse_english(&english, canonical.start, |date| Ok(ucm.parse_date(date)?)) {
+                    Ok(read_back) => (english, rule_samples(&ucm, &read_back, count)),
+                    Err(e) => {
+                        translation_notes.push(format!("The English cannot be read back exactly: {}", e));
+                        (english, Vec::new())
+                    }
+                }
+            }
+        };
+        let samples_match = samples.iter().map(|(local, _)| local).eq(source_samples.iter().map(|(local, _)| local));
+        translations.push(ScheduleTranslation {
+            notation: notation.to_string(),
+            text,
+            exact: samples_match && translation_notes.is_empty(),
+            notes: translation_notes,
+            samples: samples.iter().map(|(_, at)| at.to_rfc3339()).collect(),
+            samples_match,
+        });
+    }
+
+    Ok(ScheduleTranslateResponse {
+        input: text.to_string(),
+        source: source.to_string(),
+        timezone: ucm.timezone_name(),
+        notes,
+        samples: source_samples.iter().map(|(_, at)| at.to_rfc3339()).collect(),
+        translations,
+    })
+}
+
+fn notation(text: &str) -> Result<&'static str, UcmError> {
+    let lower = text.trim().to_lowercase();
+    NOTATIONS.into_iter().find(|n| *n == lower).ok_or_else(|| {
+        UcmError::parse_error(text, "Unknown notation; expected 'cron', 'rrule' or 'english'")
+    })
+}
+
+/// RRULE text starts with RRULE:, FREQ= or DTSTART; cron has 5 to 7 fields
+/// and starts with a number, `*` or `?`, or is an @macro
+fn detect(text: &str) -> &'static str {
+    let upper = text.to_uppercase();
+    let fields: Vec<&str> = text.split_whitespace().collect();
+    if ["RRULE", "FREQ=", "DTSTART"].iter().any(|prefix| upper.starts_with(prefix)) {
+        "rrule"
+    } else if text.starts_with('@')
+        || ((5..=7).contains(&fields.len()) && fields[0].chars().all(|c| c.is_ascii_digit() || "*/,-?".contains(c)))
+    {
+        "cron"
+    } else {
+        "english"
+    }
+}
+
+/// The engine in the schedule's zone, when it has one
+fn zoned(ucm: &Ucm, schedule: &Schedule) -> Ucm {
+    match schedule.timezone {
+        Some(tz) => ucm.clone().with_timezone(tz),
+        None => ucm.clone(),
+    }
+}
+
+fn cron_runs(ucm: &Ucm, cron: &CronSchedule, after: DateTime<FixedOffset>, count: usize) -> Vec<CronRun> {
+    match ucm.timezone() {
+        Some(tz) => cron.next_runs(&tz, after, count),
+        None => cron.next_runs(&Local, after, count),
+    }
+}
+
+fn rule_samples(ucm: &Ucm, schedule: &Schedule, count: usize) -> Samples {
+    schedule.rule.occurrences(schedule.start).take(count).map(|local| (local, ucm.localize(local))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ucm() -> Ucm {
+        Ucm::new().at_reference(Some("2026-10-19T12:00:00+00:00")).unwrap()
+    }
+
+    #[test]
+    fn test_translate_cron_and_english() {
+        let result = ucm_schedule_translate(&ucm(), "0 9 * * 1-5", None, None, Some("Europe/London"), None, Some(3)).unwrap();
+        assert_eq!(result.source, "cron");
+        assert_eq!(result.samples[0], "2026-10-20T09:00:00+01:00");
+        let texts: Vec<(&str, bool)> = result.translations.iter().map(|t| (t.text.as_str(), t.exact)).collect();
+        assert_eq!(
+            texts,
+            [
+                ("DTSTART;TZID=Europe/London:20261020T090000\nRRULE:FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR", true),
+                ("every weekday at 09:00 Europe/London", true),
+            ]
+        );
+
+        let result =
+            ucm_schedule_translate(&ucm(), "every 2nd Tuesday at 09:00 Europe/London", None, Some("cron"), None, None, None)
+                .unwrap();
+        let cron = &result.translations[0];
+        assert_eq!(cron.text, "0 9 * * TUE");
+        assert!(!cron.exact && !cron.samples_match);
+        assert_eq!(cron.notes, ["cron cannot repeat every 2 weeks; this runs every week"]);
+    }
+
+    #[test]
+    fn test_translate_rrule_and_lossy_cron() {
+        let rrule = "DTSTART;TZID=America/New_York:20261110T090000\nRRULE:FREQ=MONTHLY;BYDAY=2TU";
+        let result = ucm_schedule_translate(&ucm(), rrule, None, None, None, None, Some(2)).unwrap();
+        assert_eq!(result.source, "rrule");
+        assert_eq!(result.timezone, "America/New_York");
+        let texts: Vec<(&str, bool)> = result.translations.iter().map(|t| (t.text.as_str(), t.exact)).collect();
+        assert_eq!(
+            texts,
+            [("0 0 9 ? * 3#2", true), ("the 2nd Tuesday of every month at 09:00 America/New_York", true)]
+        );
+
+        // Vixie cron runs on the 13th or on Fridays
+        let result = ucm_schedule_translate(&ucm(), "0 9 13 * FRI", None, Some("rrule"), Some("UTC"), None, None).unwrap();
+        assert!(!result.translations[0].exact);
+        assert!(result.translations[0].notes[0].contains("or the day of week"));
+        assert!(ucm_schedule_translate(&ucm(), "every so often", None, None, None, None, None).is_err());
+        assert!(ucm_schedule_translate(&ucm(), "@daily", None, Some("json"), None, None, None).is_err());
+    }
+}
@@ -18,6 +18,30 @@ pub fn ucm_status() -> StatusResponse {
             "ucm_add".to_string(),
             "ucm_convert".to_string(),
             "ucm_info".to_string(),
+            "ucm_era".to_string(),
+            "ucm_retail".to_string(),
+            "ucm_week".to_string(),
+            "ucm_sun".to_string(),
+            "ucm_astro_events".to_string(),
+            "ucm_batch".to_string(),
+            "ucm_eval".to_string(),
+            "ucm_period".to_string(),
+            "ucm_nth_weekday".to_string(),
+            "ucm_age".to_string(),
+            "ucm_mark_set".to_string(),
+            "ucm_mark_list".to_string(),
+            "ucm_mark_delete".to_string(),
+            "ucm_event_create".to_string(),
+            "ucm_event_update".to_string(),
+            "ucm_event_delete".to_string(),
+            "ucm_event_list".to_string(),
+            "ucm_event_export".to_string(),
+            "ucm_free_slots".to_string(),
+            "ucm_remind".to_string(),
+            "ucm_reminders".to_string(),
+            "ucm_cron".to_string(),
+            "ucm_schedule_translate".to_string(),
+            "ucm_timestamp".to_string(),
             "ucm_status".to_string(),
             "ucm_instructions".to_string(),
         ],
@@ -0,0 +1,154 @@
+use chrono::{Local, NaiveDateTime, TimeZone};
+use chrono_tz::Tz;
+
+use crate::astro::cities::find_city;
+use crate::astro::sun::{
+    crossing, solar_noon, Crossing, ASTRONOMICAL_ZENITH, CIVIL_ZENITH, NAUTICAL_ZENITH,
+    SUNRISE_ZENITH,
+};
+use crate::engine::Ucm;
+use crate::types::{SunResponse, TwilightInfo, UcmError};
+
+/// Get sunrise, sunset, solar noon, day length and twilight times for a location
+pub fn ucm_sun(
+    ucm: &Ucm,
+    date: &str,
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+    city: Option<&str>,
+    timezone: Option<&str>,
+) -> Result<SunResponse, UcmError> {
+    let parsed_date = ucm.parse_date(date)?;
+
+    let (location, latitude, longitude, city_timezone) = match (city, latitude, longitude) {
+        (Some(name), Some(_), _) | (Some(name), _, Some(_)) => {
+            return Err(UcmError::invalid_location(name, "Give either a city or latitude and longitude, not both"));
+        }
+        (Some(name), None, None) => {
+            let city = find_city(name).ok_or_else(|| {
+                UcmError::invalid_location(name, "Unknown city. Pass latitude and longitude instead")
+            })?;
+            (city.name.to_string(), city.latitude, city.longitude, Some(city.timezone))
+        }
+        (None, Some(lat), Some(lon)) => {
+            if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
+                return Err(UcmError::invalid_location(
+                    &format!("{}, {}", lat, lon),
+                    "Latitude must be -90..90 and longitude -180..180",
+                ));
+            }
+            (format!("{:.4}, {:.4}", lat, lon), lat, lon, None)
+        }
+        _ => {
+            return Err(UcmError::missing_parameter(
+                "latitude",
+                "Provide a city, or both latitude and longitude",
+            ))
+        }
+    };
+
+    let tz = resolve_timezone(timezone.or(city_timezone))?;
+
+    let sun = crossing(parsed_date, latitude, longitude, SUNRISE_ZENITH);
+    let (daylight, sunrise, sunset, day_length_seconds) = match sun {
+        Crossing::Times(rise, set) => ("normal", Some(rise), Some(set), (set - rise).num_seconds()),
+        Crossing::AlwaysAbove => ("polar_day", None, None, 86400),
+        Crossing::AlwaysBelow => ("polar_night", None, None, 0),
+    };
+
+    Ok(SunResponse {
+        date: parsed_date.format("%Y-%m-%d").to_string(),
+        location,
+        latitude,
+        longitude,
+        timezone: tz.map_or_else(|| ucm.clone(), |tz| ucm.clone().with_timezone(tz)).timezone_name(),
+        daylight: daylight.to_string(),
+        sunrise: sunrise.map(|t| to_local(t, tz)),
+        sunset: sunset.map(|t| to_local(t, tz)),
+        solar_noon: to_local(solar_noon(parsed_date, longitude), tz),
+        day_length: format!(
+            "{:02}:{:02}:{:02}",
+            day_length_seconds / 3600,
+            day_length_seconds % 3600 / 60,
+            day_length_seconds % 60
+        ),
+        day_length_seconds,
+        civil_twilight: twilight(crossing(parsed_date, latitude, longitude, CIVIL_ZENITH), tz),
+        nautical_twilight: twilight(crossing(parsed_date, latitude, longitude, NAUTICAL_ZENITH), tz),
+        astronomical_twilight: twilight(
+            crossing(parsed_date, latitude, longitude, ASTRONOMICAL_ZENITH),
+            tz,
+        ),
+    })
+}
+
+/// "continuous" when the sun never sinks below the twilight angle,
+/// "none" when it never rises above it
+fn twilight(crossing: Crossing, tz: Option<Tz>) -> TwilightInfo {
+    match crossing {
+        Crossing::Times(begin, end) => TwilightInfo {
+            status: "normal".to_string(),
+            begin: Some(to_local(begin, tz)),
+            end: Some(to_local(end, tz)),
+        },
+        Crossing::AlwaysAbove => TwilightInfo {
+            status: "continuous".to_string(),
+            begin: None,
+            end: None,
+        },
+        Crossing::AlwaysBelow => TwilightInfo {
+            status: "none".to_string(),
+            begin: None,
+            end: None,
+        },
+    }
+}
+
+/// Parse an optional IANA time zone name
+pub fn resolve_timezone(name: Option<&str>) -> Result<Option<Tz>, UcmError> {
+    name.map(|name| name.parse::<Tz>().map_err(|_| UcmError::invalid_timezone(name)))
+        .transpose()
+}
+
+/// Format a UTC instant in the given zone, or local time when none is given
+pub fn to_local(utc: NaiveDateTime, tz: Option<Tz>) -> String {
+    match tz {
+        Some(tz) => tz.from_utc_datetime(&utc).to_rfc3339(),
+        None => Local.from_utc_datetime(&utc).to_rfc3339(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ucm() -> Ucm {
+        Ucm::new().at_reference(Some("2026-10-19T09:30:00+00:00")).unwrap()
+    }
+
+    #[test]
+    fn test_sun_city() {
+        let result = ucm_sun(&ucm(), "2026-06-21", None, None, Some("london"), None).unwrap();
+        assert_eq!(result.timezone, "Europe/London");
+        assert_eq!(result.daylight, "normal");
+        assert!(result.sunrise.unwrap().starts_with("2026-06-21T04:4"));
+        assert!(result.day_length_seconds > 16 * 3600);
+    }
+
+    #[test]
+    fn test_sun_polar_night() {
+        let result = ucm_sun(&ucm(), "2026-12-21", Some(69.6492), Some(18.9553), None, Some("Europe/Oslo")).unwrap();
+        assert_eq!(result.daylight, "polar_night");
+        assert_eq!(result.day_length_seconds, 0);
+        assert_eq!(result.civil_twilight.status, "normal");
+    }
+
+    #[test]
+    fn test_sun_invalid_input() {
+        assert!(ucm_sun(&ucm(), "today", None, None, None, None).is_err());
+        assert!(ucm_sun(&ucm(), "today", Some(95.0), Some(0.0), None, None).is_err());
+        assert!(ucm_sun(&ucm(), "today", None, None, Some("London"), Some("Mars/Olympus")).is_err());
+        let both = ucm_sun(&ucm(), "today", Some(51.5), Some(-0.1), Some("London"), None).unwrap_err();
+        assert_eq!(both.error, crate::types::ErrorKind::InvalidLocation);
+    }
+}
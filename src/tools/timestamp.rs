@@ -0,0 +1,158 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+
+use crate::calendar::epoch::{parse_number, TimestampUnit, UNITS};
+use crate::engine::Ucm;
+use crate::tools::sun::resolve_timezone;
+use crate::types::{TimestampAlternative, TimestampResponse, TimestampValue, UcmError};
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// Read a raw timestamp in any unit, detected from its size unless given,
+/// or a date and time; either way give the instant in every unit
+pub fn ucm_timestamp(
+    ucm: &Ucm,
+    value: &str,
+    unit: Option<&str>,
+    timezone: Option<&str>,
+) -> Result<TimestampResponse, UcmError> {
+    let ucm = match resolve_timezone(timezone)? {
+        Some(tz) => ucm.clone().with_timezone(tz),
+        None => ucm.clone(),
+    };
+    let unit = unit
+        .map(|name| {
+            TimestampUnit::parse(name).ok_or_else(|| {
+                let names: Vec<&str> = UNITS.iter().map(|unit| unit.name()).collect();
+                UcmError::parse_error(name, &format!("Unknown unit; expected one of {}", names.join(", ")))
+            })
+        })
+        .transpose()?;
+    let text = value.trim();
+
+    let (instant, read_as, alternatives) = match parse_number(text) {
+        Some((mantissa, scale)) => {
+            let detected = unit.is_none();
+            let unit = unit.unwrap_or_else(|| TimestampUnit::detect(mantissa, scale));
+            let nanos = unit.to_nanos(mantissa, scale).map_err(|e| UcmError::out_of_range(text, &e.to_string()))?;
+            let instant = instant_of(&ucm, unit, nanos).ok_or_else(|| unrepresentable(text))?;
+            let alternatives = match detected {
+                true => alternatives(&ucm, unit, mantissa, scale),
+                false => Vec::new(),
+            };
+            (instant, Some((unit, detected)), alternatives)
+        }
+        None => {
+            let instant = match DateTime::parse_from_rfc3339(text) {
+                Ok(instant) => instant,
+                Err(_) => ucm.localize(ucm.parse_datetime(text)?),
+            };
+            (instant, None, Vec::new())
+        }
+    };
+
+    let instant = ucm.in_zone(instant);
+    let utc = nanos_of(instant.naive_utc().and_utc());
+    let local = nanos_of(instant.naive_local().and_utc());
+    Ok(TimestampResponse {
+        input: text.to_string(),
+        unit: read_as.map(|(unit, _)| unit.name().to_string()),
+        detected: read_as.is_some_and(|(_, detected)| detected),
+        datetime: instant.to_rfc3339(),
+        utc: instant.with_timezone(&Utc).to_rfc3339(),
+        timezone: ucm.timezone_name(),
+        timestamps: UNITS
+            .iter()
+            .filter_map(|unit| {
+                let value = unit.format_nanos(if unit.is_local() { local } else { utc })?;
+                Some(TimestampValue {
+                    unit: unit.name().to_string(),
+                    value,
+                    description: unit.description().to_string(),
+                })
+            })
+            .collect(),
+        alternatives,
+    })
+}
+
+/// The instant `nanos` stands for; local units are read in the engine's zone
+fn instant_of(ucm: &Ucm, unit: TimestampUnit, nanos: i128) -> Option<DateTime<FixedOffset>> {
+    let seconds = i64::try_from(nanos.div_euclid(NANOS_PER_SECOND)).ok()?;
+    let time = DateTime::from_timestamp(seconds, nanos.rem_euclid(NANOS_PER_SECOND) as u32)?;
+    Some(match unit.is_local() {
+        true => ucm.localize(time.naive_utc()),
+        false => time.fixed_offset(),
+    })
+}
+
+fn nanos_of(time: DateTime<Utc>) -> i128 {
+    time.timestamp() as i128 * NANOS_PER_SECOND + time.timestamp_subsec_nanos() as i128
+}
+
+/// The number read in each other unit that gives a date from 1900 to 2199
+fn alternatives(ucm: &Ucm, detected: TimestampUnit, mantissa: i128, scale: u32) -> Vec<TimestampAlternative> {
+    let year = |year| NaiveDate::from_ymd_opt(year, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap().and_utc();
+    let (earliest, latest) = (year(1900), year(2200));
+    UNITS
+        .iter()
+        .filter(|unit| **unit != detected)
+        .filter_map(|unit| {
+            let instant = instant_of(ucm, *unit, unit.to_nanos(mantissa, scale).ok()?)?;
+            (instant >= earliest && instant < latest).then(|| TimestampAlternative {
+                unit: unit.name().to_string(),
+                datetime: ucm.in_zone(instant).to_rfc3339(),
+            })
+        })
+        .collect()
+}
+
+fn unrepresentable(text: &str) -> UcmError {
+    UcmError::out_of_range(text, "The timestamp is outside the years -262143 to 262142")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ErrorKind;
+
+    fn ucm() -> Ucm {
+        Ucm::new().at_reference(Some("2026-10-19T12:00:00+00:00")).unwrap()
+    }
+
+    fn value<'a>(response: &'a TimestampResponse, unit: &str) -> &'a str {
+        &response.timestamps.iter().find(|t| t.unit == unit).unwrap().value
+    }
+
+    #[test]
+    fn test_timestamp_detects_units() {
+        let seconds = ucm_timestamp(&ucm(), "1792402200", None, Some("Europe/London")).unwrap();
+        assert_eq!((seconds.unit.as_deref(), seconds.detected), (Some("unix_s"), true));
+        assert_eq!(seconds.datetime, "2026-10-19T10:30:00+01:00");
+        assert_eq!(value(&seconds, "unix_ms"), "1792402200000");
+        assert_eq!(value(&seconds, "excel"), "46314.4375");
+        assert!(seconds.alternatives.iter().any(|a| a.unit == "ntp" && a.datetime.starts_with("1956-10-19")));
+
+        let filetime = ucm_timestamp(&ucm(), "134368758002500000", None, Some("UTC")).unwrap();
+        assert_eq!(filetime.unit.as_deref(), Some("filetime"));
+        assert_eq!(filetime.utc, "2026-10-19T09:30:00.250+00:00");
+
+        // Excel serials are wall-clock days in the zone
+        let excel = ucm_timestamp(&ucm(), "46314.5", None, Some("America/New_York")).unwrap();
+        assert_eq!(excel.datetime, "2026-10-19T12:00:00-04:00");
+    }
+
+    #[test]
+    fn test_timestamp_from_date_and_errors() {
+        let date = ucm_timestamp(&ucm(), "2026-10-19T09:30:00Z", None, Some("UTC")).unwrap();
+        assert_eq!(date.unit, None);
+        assert_eq!(value(&date, "gps"), "1476437418");
+        assert_eq!(value(&date, "dotnet_ticks"), "639279990000000000");
+
+        let ntp = ucm_timestamp(&ucm(), "4001391000", Some("ntp"), Some("UTC")).unwrap();
+        assert_eq!((ntp.utc.as_str(), ntp.detected), ("2026-10-19T09:30:00+00:00", false));
+        assert!(ntp.alternatives.is_empty());
+
+        assert_eq!(ucm_timestamp(&ucm(), "60", Some("excel"), None).unwrap_err().error, ErrorKind::OutOfRange);
+        assert_eq!(ucm_timestamp(&ucm(), "5", Some("fortnights"), None).unwrap_err().error, ErrorKind::ParseError);
+    }
+}
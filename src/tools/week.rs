@@ -0,0 +1,115 @@
+use chrono::{Duration, NaiveDate, Weekday};
+
+use crate::calendar::week::{iso_week_date, WeekSystem};
+use crate::engine::Ucm;
+use crate::types::{UcmError, WeekInfo, WeekResponse};
+
+/// Convert dates to and from ISO, US and broadcast week numbers, with
+/// optional week-aligned arithmetic
+pub fn ucm_week(
+    ucm: &Ucm,
+    date: Option<&str>,
+    year: Option<i32>,
+    week: Option<u32>,
+    system: Option<&str>,
+    add_weeks: Option<i64>,
+    align: Option<&str>,
+) -> Result<WeekResponse, UcmError> {
+    let system = match system {
+        Some(name) => WeekSystem::from_name(name).map_err(|e| {
+            UcmError::invalid_calendar(name, &e.to_string())
+        })?,
+        None => WeekSystem::Iso,
+    };
+
+    let base_date = match (date, year, week) {
+        (Some(date), _, _) => ucm.parse_date(date)?,
+        (None, Some(year), Some(week)) => system
+            .week_range(year, week)
+            .map_err(|e| UcmError::out_of_range(&format!("{}-W{:02}", year, week), &e.to_string()))?
+            .0,
+        _ => {
+            return Err(UcmError::missing_parameter(
+                "date",
+                "Provide either a date, or a year and week number",
+            ))
+        }
+    };
+
+    let weeks = add_weeks.unwrap_or(0);
+    let mut target = Duration::try_weeks(weeks)
+        .and_then(|delta| base_date.checked_add_signed(delta))
+        .ok_or_else(|| UcmError::out_of_range(&weeks.to_string(), "Adding that many weeks leaves the supported dates"))?;
+    if let Some(align) = align {
+        target = system.align(target, parse_alignment(align, system)?);
+    }
+
+    Ok(WeekResponse {
+        date: target.format("%Y-%m-%d").to_string(),
+        day_of_week: target.format("%A").to_string(),
+        iso_week_date: iso_week_date(target),
+        iso: week_info(target, WeekSystem::Iso)?,
+        us: week_info(target, WeekSystem::Us)?,
+        broadcast: week_info(target, WeekSystem::Broadcast)?,
+    })
+}
+
+/// "monday", "fri", "start" (first day of the week) or "end" (last day)
+fn parse_alignment(align: &str, system: WeekSystem) -> Result<Weekday, UcmError> {
+    match align.trim().to_lowercase().as_str() {
+        "start" => Ok(system.first_weekday()),
+        "end" => Ok(system.first_weekday().pred()),
+        other => other.parse().map_err(|_| {
+            UcmError::parse_error(align, "Alignment must be a weekday name, 'start' or 'end'")
+        }),
+    }
+}
+
+fn week_info(date: NaiveDate, system: WeekSystem) -> Result<WeekInfo, UcmError> {
+    let week = system.locate(date).map_err(|e| {
+        UcmError::out_of_range(&date.format("%Y-%m-%d").to_string(), &e.to_string())
+    })?;
+
+    Ok(WeekInfo {
+        system: system.name().to_string(),
+        week_year: week.week_year,
+        week: week.week,
+        day_of_week_number: week.day,
+        label: format!("{:04}-W{:02}-{}", week.week_year, week.week, week.day),
+        week_start: week.week_start.format("%Y-%m-%d").to_string(),
+        week_end: week.week_end.format("%Y-%m-%d").to_string(),
+        weeks_in_year: week.weeks_in_year,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_week_from_date() {
+        let result = ucm_week(&Ucm::new(), Some("2027-01-01"), None, None, None, None, None).unwrap();
+        assert_eq!(result.iso_week_date, "2026-W53-5");
+        assert_eq!(result.iso.week_year, 2026);
+        assert_eq!(result.us.week_year, 2027);
+        assert_eq!(result.us.week, 1);
+    }
+
+    #[test]
+    fn test_week_to_date_range() {
+        let result = ucm_week(&Ucm::new(), None, Some(2026), Some(43), Some("iso"), None, None).unwrap();
+        assert_eq!(result.date, "2026-10-19");
+        assert_eq!(result.iso.week_start, "2026-10-19");
+        assert_eq!(result.iso.week_end, "2026-10-25");
+    }
+
+    #[test]
+    fn test_week_aligned_arithmetic() {
+        let result = ucm_week(&Ucm::new(), Some("2026-10-22"), None, None, None, Some(3), Some("monday")).unwrap();
+        assert_eq!(result.date, "2026-11-09");
+        assert_eq!(result.day_of_week, "Monday");
+        assert!(ucm_week(&Ucm::new(), None, None, None, None, None, None).is_err());
+        let far = ucm_week(&Ucm::new(), Some("2026-10-22"), None, None, None, Some(i64::MAX / 7), None);
+        assert_eq!(far.unwrap_err().error, crate::types::ErrorKind::OutOfRange);
+    }
+}
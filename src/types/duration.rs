@@ -1,7 +1,8 @@
 use chrono::{Datelike, NaiveDate};
+use schemars::JsonSchema;
 use serde::Serialize;
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Clone, JsonSchema)]
 pub struct DurationBreakdown {
     pub years: i32,
     pub months: i32,
@@ -126,9 +127,10 @@ impl DurationBreakdown {
     }
 }
 
-fn add_months(date: NaiveDate, months: i32) -> Option<NaiveDate> {
-    let total_months = date.month0() as i32 + months;
-    let year = date.year() + total_months.div_euclid(12);
+/// Add calendar months, clamping the day to the end of shorter months
+pub fn add_months(date: NaiveDate, months: i32) -> Option<NaiveDate> {
+    let total_months = (date.month0() as i32).checked_add(months)?;
+    let year = date.year().checked_add(total_months.div_euclid(12))?;
     let month = (total_months.rem_euclid(12) + 1) as u32;
     let day = date.day().min(days_in_month(year, month));
     NaiveDate::from_ymd_opt(year, month, day)
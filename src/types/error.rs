@@ -0,0 +1,191 @@
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// What went wrong; serialized as the snake_case error code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+    ParseError,
+    InvalidUnit,
+    InvalidCalendar,
+    MissingParameter,
+    InvalidLocation,
+    InvalidTimezone,
+    OutOfRange,
+    UnknownResource,
+    UnknownPrompt,
+    InvalidReference,
+    StorageError,
+}
+
+/// The error code, as serialized
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.serialize(f)
+    }
+}
+
+/// Character range of the offending part of an expression, end exclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct ErrorSpan {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Error response
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct UcmError {
+    pub error: ErrorKind,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span: Option<ErrorSpan>,
+}
+
+impl UcmError {
+    /// Process exit code for this error when UCM runs as a command-line tool
+    pub fn exit_code(&self) -> u8 {
+        match self.error {
+            ErrorKind::ParseError => 3,
+            ErrorKind::InvalidUnit => 4,
+            ErrorKind::InvalidCalendar => 5,
+            ErrorKind::MissingParameter => 6,
+            ErrorKind::InvalidLocation => 7,
+            ErrorKind::InvalidTimezone => 8,
+            ErrorKind::OutOfRange => 9,
+            ErrorKind::InvalidReference => 10,
+            ErrorKind::StorageError => 11,
+            ErrorKind::UnknownResource | ErrorKind::UnknownPrompt => 1,
+        }
+    }
+
+    pub fn parse_error(input: &str, message: &str) -> Self {
+        Self {
+            error: ErrorKind::ParseError,
+            message: message.to_string(),
+            input: Some(input.to_string()),
+            span: None,
+        }
+    }
+
+    pub fn invalid_unit(input: &str) -> Self {
+        Self {
+            error: ErrorKind::InvalidUnit,
+            message: format!("Invalid unit: '{}'. Valid units: days, weeks, months, years, hours, minutes, seconds", input),
+            input: Some(input.to_string()),
+            span: None,
+        }
+    }
+
+    pub fn invalid_calendar(input: &str, message: &str) -> Self {
+        Self {
+            error: ErrorKind::InvalidCalendar,
+            message: message.to_string(),
+            input: Some(input.to_string()),
+            span: None,
+        }
+    }
+
+    pub fn missing_parameter(name: &str, message: &str) -> Self {
+        Self {
+            error: ErrorKind::MissingParameter,
+            message: message.to_string(),
+            input: Some(name.to_string()),
+            span: None,
+        }
+    }
+
+    pub fn invalid_location(input: &str, message: &str) -> Self {
+        Self {
+            error: ErrorKind::InvalidLocation,
+            message: message.to_string(),
+            input: Some(input.to_string()),
+            span: None,
+        }
+    }
+
+    pub fn invalid_timezone(input: &str) -> Self {
+        Self {
+            error: ErrorKind::InvalidTimezone,
+            message: format!("Unknown time zone: '{}'. Use an IANA name such as 'America/Chicago'", input),
+            input: Some(input.to_string()),
+            span: None,
+        }
+    }
+
+    pub fn unknown_resource(uri: &str) -> Self {
+        Self {
+            error: ErrorKind::UnknownResource,
+            message: format!("Unknown resource: '{}'. Use resources/list to see available resources", uri),
+            input: Some(uri.to_string()),
+            span: None,
+        }
+    }
+
+    pub fn unknown_prompt(name: &str) -> Self {
+        Self {
+            error: ErrorKind::UnknownPrompt,
+            message: format!("Unknown prompt: '{}'. Use prompts/list to see available prompts", name),
+            input: Some(name.to_string()),
+            span: None,
+        }
+    }
+
+    pub fn out_of_range(input: &str, message: &str) -> Self {
+        Self {
+            error: ErrorKind::OutOfRange,
+            message: message.to_string(),
+            input: Some(input.to_string()),
+            span: None,
+        }
+    }
+
+    pub fn invalid_reference(reference: &str, message: &str) -> Self {
+        Self {
+            error: ErrorKind::InvalidReference,
+            message: message.to_string(),
+            input: Some(reference.to_string()),
+            span: None,
+        }
+    }
+
+    pub fn storage_error(path: &str, message: &str) -> Self {
+        Self {
+            error: ErrorKind::StorageError,
+            message: message.to_string(),
+            input: Some(path.to_string()),
+            span: None,
+        }
+    }
+
+    /// Point the error at characters `start..end` of the input
+    pub fn at(mut self, start: usize, end: usize) -> Self {
+        self.span = Some(ErrorSpan { start, end });
+        self
+    }
+}
+
+impl fmt::Display for UcmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.error, self.message)
+    }
+}
+
+impl std::error::Error for UcmError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_serializes_code() {
+        let error = UcmError::parse_error("blah", "Could not parse 'blah'");
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["error"], "parse_error");
+        assert_eq!(error.to_string(), "parse_error: Could not parse 'blah'");
+        assert_eq!(ErrorKind::OutOfRange.to_string(), "out_of_range");
+    }
+}
@@ -1,5 +1,7 @@
 pub mod duration;
+pub mod error;
 pub mod responses;
 
 pub use duration::DurationBreakdown;
+pub use error::{ErrorKind, UcmError};
 pub use responses::*;
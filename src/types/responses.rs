@@ -1,9 +1,10 @@
+use schemars::JsonSchema;
 use serde::Serialize;
 
-use super::DurationBreakdown;
+use super::{DurationBreakdown, UcmError};
 
 /// Response for ucm_now
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct NowResponse {
     pub iso: String,
     pub unix: i64,
@@ -13,11 +14,29 @@ pub struct NowResponse {
     pub day_of_week: String,
     pub day_of_year: u32,
     pub week_of_year: u32,
+    pub iso_week_year: i32,
     pub quarter: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fiscal: Option<FiscalInfo>,
+}
+
+/// Fiscal calendar position of a date
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct FiscalInfo {
+    pub calendar: String,
+    pub fiscal_year: i32,
+    pub fiscal_year_label: String,
+    pub fiscal_quarter: u32,
+    pub fiscal_period: u32,
+    pub fiscal_week: u32,
+    pub fiscal_year_start: String,
+    pub fiscal_year_end: String,
+    pub fiscal_quarter_start: String,
+    pub fiscal_quarter_end: String,
 }
 
 /// Response for ucm_parse
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct ParseResponse {
     pub iso: String,
     pub unix: i64,
@@ -29,7 +48,7 @@ pub struct ParseResponse {
 }
 
 /// Response for ucm_diff
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct DiffResponse {
     pub from_date: String,
     pub to_date: String,
@@ -40,7 +59,7 @@ pub struct DiffResponse {
     pub alt_breakdowns: AltBreakdowns,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct AltBreakdowns {
     pub total_weeks: f64,
     pub total_months: f64,
@@ -48,7 +67,7 @@ pub struct AltBreakdowns {
 }
 
 /// Response for ucm_add
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct AddResponse {
     pub base_date: String,
     pub added: String,
@@ -58,7 +77,7 @@ pub struct AddResponse {
 }
 
 /// Response for ucm_convert
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct ConvertResponse {
     pub input_value: f64,
     pub input_unit: String,
@@ -66,7 +85,7 @@ pub struct ConvertResponse {
     pub breakdown_string: String,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct Conversions {
     pub years: i32,
     pub months: i32,
@@ -79,13 +98,15 @@ pub struct Conversions {
 }
 
 /// Response for ucm_info
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct InfoResponse {
     pub iso: String,
     pub day_of_week: String,
     pub day_of_month: u32,
     pub day_of_year: u32,
     pub week_of_year: u32,
+    pub iso_week_year: i32,
+    pub iso_week_date: String,
     pub month: u32,
     pub month_name: String,
     pub year: i32,
@@ -95,10 +116,316 @@ pub struct InfoResponse {
     pub is_weekend: bool,
     pub days_from_now: i64,
     pub is_past: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fiscal: Option<FiscalInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retail: Option<RetailInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub japanese_era: Option<JapaneseEraInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moon_phase: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moon_illumination: Option<f64>,
+}
+
+/// Retail 52/53-week calendar position of a date
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct RetailInfo {
+    pub calendar: String,
+    pub retail_year: i32,
+    pub retail_quarter: u32,
+    pub retail_period: u32,
+    pub retail_week: u32,
+    pub week_of_period: u32,
+    pub weeks_in_year: u32,
+    pub is_53_week_year: bool,
+    pub retail_year_start: String,
+    pub retail_year_end: String,
+    pub period_start: String,
+    pub period_end: String,
+}
+
+/// Response for ucm_retail
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct RetailResponse {
+    pub start_date: String,
+    pub end_date: String,
+    pub retail: RetailInfo,
+}
+
+/// A date's position in one week numbering system
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct WeekInfo {
+    pub system: String,
+    pub week_year: i32,
+    pub week: u32,
+    pub day_of_week_number: u32,
+    pub label: String,
+    pub week_start: String,
+    pub week_end: String,
+    pub weeks_in_year: u32,
+}
+
+/// Response for ucm_week
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct WeekResponse {
+    pub date: String,
+    pub day_of_week: String,
+    pub iso_week_date: String,
+    pub iso: WeekInfo,
+    pub us: WeekInfo,
+    pub broadcast: WeekInfo,
+}
+
+/// Response for ucm_sun
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct SunResponse {
+    pub date: String,
+    pub location: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub timezone: String,
+    pub daylight: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sunrise: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sunset: Option<String>,
+    pub solar_noon: String,
+    pub day_length: String,
+    pub day_length_seconds: i64,
+    pub civil_twilight: TwilightInfo,
+    pub nautical_twilight: TwilightInfo,
+    pub astronomical_twilight: TwilightInfo,
+}
+
+/// Dawn and dusk for one twilight depression angle
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct TwilightInfo {
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub begin: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<String>,
+}
+
+/// Response for ucm_astro_events
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct AstroEventsResponse {
+    pub from: String,
+    pub to: String,
+    pub timezone: String,
+    pub count: usize,
+    pub events: Vec<AstroEvent>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct AstroEvent {
+    pub event: String,
+    pub name: String,
+    pub category: String,
+    pub datetime: String,
+    pub days_from_now: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eclipse_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub magnitude: Option<f64>,
+}
+
+/// Content of the ucm://calendar/{year}/{month} resource
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct CalendarMonthResponse {
+    pub year: i32,
+    pub month: u32,
+    pub month_name: String,
+    pub days_in_month: u32,
+    pub first_day_of_week: String,
+    /// ISO weeks (Monday first); days outside the month are null
+    pub weeks: Vec<CalendarWeek>,
+    /// The month laid out as plain text, like `cal`
+    pub grid: String,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct CalendarWeek {
+    pub iso_week: u32,
+    pub days: Vec<Option<u32>>,
+}
+
+/// Content of the ucm://holidays/{country}/{year} resource
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct HolidaysResponse {
+    pub country: String,
+    pub country_name: String,
+    pub year: i32,
+    pub count: usize,
+    pub holidays: Vec<HolidayInfo>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct HolidayInfo {
+    pub name: String,
+    pub date: String,
+    pub day_of_week: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observed: Option<String>,
+}
+
+/// Content of the ucm://timezones resource
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct TimezonesResponse {
+    pub as_of: String,
+    pub count: usize,
+    pub timezones: Vec<TimezoneInfo>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct TimezoneInfo {
+    pub id: String,
+    pub abbreviation: String,
+    pub utc_offset: String,
+}
+
+/// Japanese imperial era representation of a date
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct JapaneseEraInfo {
+    pub era: String,
+    pub era_kanji: String,
+    pub era_abbreviation: String,
+    pub era_year: i32,
+    pub era_start: String,
+    pub formatted: String,
+    pub formatted_short: String,
+}
+
+/// Response for ucm_era
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct EraResponse {
+    pub iso: String,
+    pub gregorian_year: i32,
+    pub day_of_week: String,
+    pub japanese_era: JapaneseEraInfo,
+    pub parsed_expression: String,
+}
+
+/// Response for ucm_batch
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct BatchResponse {
+    pub count: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    /// One entry per operation, in request order
+    pub results: Vec<BatchItem>,
+}
+
+/// Outcome of one batch operation
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct BatchItem {
+    /// 1-based position, as used in `$N` references
+    pub index: usize,
+    pub tool: String,
+    pub ok: bool,
+    /// The tool's response, when it succeeded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<UcmError>,
+}
+
+/// Response for ucm_age
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct AgeResponse {
+    /// Birth or start date
+    pub date: String,
+    pub as_of: String,
+    /// Where February 29 anniversaries fall in common years
+    pub leap_day_policy: String,
+    /// Completed years: the age, or tenure in years
+    pub years: i32,
+    pub breakdown: DurationBreakdown,
+    pub breakdown_string: String,
+    pub total_days: i64,
+    /// Completed months
+    pub total_months: i64,
+    /// Whether the as-of date is a birthday or anniversary
+    pub is_anniversary: bool,
+    pub next_anniversary: AnniversaryInfo,
+    /// The date of the requested age, when `turns` was given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub turns: Option<AnniversaryInfo>,
+    pub upcoming: Vec<AnniversaryInfo>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct AnniversaryInfo {
+    /// Years since the start date
+    pub number: i32,
+    pub date: String,
+    pub day_of_week: String,
+    pub days_from_as_of: i64,
+}
+
+/// Response for ucm_nth_weekday
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct NthWeekdayResponse {
+    pub expression: String,
+    pub count: usize,
+    /// Whether the range held more periods than one query covers
+    pub truncated: bool,
+    pub dates: Vec<NthDate>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct NthDate {
+    pub iso: String,
+    pub unix: i64,
+    pub date: String,
+    pub day_of_week: String,
+    pub days_from_now: i64,
+    pub is_past: bool,
+    /// The period the date was found in, for "each month" queries
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub period: Option<String>,
+}
+
+/// Response for ucm_period
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct PeriodResponse {
+    pub unit: String,
+    /// e.g. "2026-Q4", "2026-W43", "2026-H2", "FY2027"
+    pub label: String,
+    pub start: String,
+    pub end: String,
+    pub days: i64,
+    pub start_day_of_week: String,
+    pub end_day_of_week: String,
+    /// Whether today falls inside the period
+    pub is_current: bool,
+    pub days_until_start: i64,
+    pub days_until_end: i64,
+}
+
+/// Response for ucm_eval
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct EvalResponse {
+    pub expression: String,
+    /// date, datetime, duration or number
+    pub kind: String,
+    /// ISO date or datetime, duration text (e.g. "1 month 3 days") or number
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day_of_week: Option<String>,
+    /// Signed days from today, for dates and datetimes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_from_today: Option<i64>,
+    /// Length of a duration in days; approximate when it has months or business days
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_days: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<f64>,
 }
 
 /// Response for ucm_status
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct StatusResponse {
     pub name: String,
     pub version: String,
@@ -108,15 +435,17 @@ pub struct StatusResponse {
 }
 
 /// Response for ucm_instructions
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct InstructionsResponse {
     pub overview: String,
     pub tools: Vec<ToolInstruction>,
     pub response_format: String,
+    /// MCP prompts for multi-step workflows, as "name: description"
+    pub workflows: Vec<String>,
     pub tips: Vec<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct ToolInstruction {
     pub name: String,
     pub description: String,
@@ -124,29 +453,292 @@ pub struct ToolInstruction {
     pub example: String,
 }
 
-/// Error response
-#[derive(Debug, Serialize)]
-pub struct UcmError {
-    pub error: String,
+/// A saved date with its countdown
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct MarkInfo {
+    /// Name to reference the date by, as `@name`
+    pub name: String,
+    pub date: String,
+    pub day_of_week: String,
+    pub days_from_now: i64,
+    pub is_past: bool,
+    /// "in 2 months, 1 week", "3 days ago" or "today"
+    pub countdown: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// Response for ucm_mark_set
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct MarkSetResponse {
+    pub mark: MarkInfo,
+    /// The date previously saved under this name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaced: Option<String>,
+}
+
+/// Response for ucm_mark_list
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct MarkListResponse {
+    /// File the dates are stored in
+    pub data_file: String,
+    pub count: usize,
+    /// Saved dates, soonest first
+    pub marks: Vec<MarkInfo>,
+}
+
+/// Response for ucm_mark_delete
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct MarkDeleteResponse {
+    pub deleted: MarkInfo,
+}
+
+/// A stored event
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct EventInfo {
+    pub id: String,
+    pub title: String,
+    /// Local start, "2026-10-20T09:00", or the first day of an all-day event
+    pub start: String,
+    /// Local end, or the last day of an all-day event
+    pub end: String,
+    pub all_day: bool,
+    /// IANA zone the times are in, or "local"
+    pub timezone: String,
+    /// RRULE, for recurring events
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<String>,
+    /// Start of the next occurrence from now
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_start: Option<String>,
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// One occurrence of an event
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct EventOccurrence {
+    pub id: String,
+    pub title: String,
+    /// RFC 3339 in the event's time zone
+    pub start: String,
+    pub end: String,
+    pub day_of_week: String,
+    pub all_day: bool,
+    pub recurring: bool,
+    pub tags: Vec<String>,
+}
+
+/// Two overlapping event occurrences
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct EventConflict {
+    pub first_id: String,
+    pub first_title: String,
+    pub second_id: String,
+    pub second_title: String,
+    /// When the overlap starts and ends
+    pub start: String,
+    pub end: String,
+    pub minutes: i64,
+}
+
+/// Response for ucm_event_create and ucm_event_update
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct EventResponse {
+    pub event: EventInfo,
+    /// Overlaps with other timed events over the next year
+    pub conflicts: Vec<EventConflict>,
+}
+
+/// Response for ucm_event_delete
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct EventDeleteResponse {
+    pub deleted: EventInfo,
+}
+
+/// Response for ucm_event_list
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct EventListResponse {
+    pub from: String,
+    pub to: String,
+    pub count: usize,
+    /// Occurrences by start time
+    pub occurrences: Vec<EventOccurrence>,
+    pub conflicts: Vec<EventConflict>,
+}
+
+/// Response for ucm_event_export
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct EventExportResponse {
+    pub count: usize,
+    /// iCalendar (.ics) text
+    pub ics: String,
+}
+
+/// A span of time
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct TimeBlock {
+    pub start: String,
+    pub end: String,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct FreeSlot {
+    pub start: String,
+    pub end: String,
+    pub minutes: i64,
+    pub day_of_week: String,
+}
+
+/// Response for ucm_free_slots
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct FreeSlotsResponse {
+    pub from: String,
+    pub to: String,
+    /// Zone of the working hours
+    pub timezone: String,
+    pub duration_minutes: i64,
+    pub working_hours: String,
+    pub working_days: Vec<String>,
+    /// Busy time in the range, merged, before buffers
+    pub busy: Vec<TimeBlock>,
+    pub count: usize,
+    /// Whether more slots were found than the limit
+    pub truncated: bool,
+    /// Free windows at least duration_minutes long, earliest first
+    pub slots: Vec<FreeSlot>,
+}
+
+/// A saved reminder
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ReminderInfo {
+    pub id: String,
     pub message: String,
+    /// RFC 3339
+    pub due: String,
+    pub created: String,
+    /// Negative once the reminder is overdue
+    pub minutes_until: i64,
+}
+
+/// Response for ucm_remind
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct RemindResponse {
+    pub reminder: ReminderInfo,
+    /// Reminders now waiting, including this one
+    pub pending: usize,
+}
+
+/// Response for ucm_reminders
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct RemindersResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub input: Option<String>,
-}
-
-impl UcmError {
-    pub fn parse_error(input: &str, message: &str) -> Self {
-        Self {
-            error: "parse_error".to_string(),
-            message: message.to_string(),
-            input: Some(input.to_string()),
-        }
-    }
-
-    pub fn invalid_unit(input: &str) -> Self {
-        Self {
-            error: "invalid_unit".to_string(),
-            message: format!("Invalid unit: '{}'. Valid units: days, weeks, months, years, hours, minutes, seconds", input),
-            input: Some(input.to_string()),
-        }
-    }
+    pub cancelled: Option<ReminderInfo>,
+    /// Reminders that fell due with no client connected to receive them;
+    /// the next client to connect is sent them
+    pub missed: Vec<ReminderInfo>,
+    pub count: usize,
+    /// Not yet delivered, soonest first; overdue ones wait for a server
+    pub pending: Vec<ReminderInfo>,
+}
+
+/// A cron field and its text
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct CronField {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct CronRunInfo {
+    /// RFC 3339 in the requested zone
+    pub time: String,
+    pub day_of_week: String,
+    /// How a daylight saving change moved or repeated this run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dst_note: Option<String>,
+}
+
+/// Response for ucm_cron
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct CronResponse {
+    pub expression: String,
+    /// "standard" (5 fields), "seconds" (6 fields) or "quartz"
+    pub dialect: String,
+    /// The schedule in English
+    pub description: String,
+    /// Fields from seconds on; 5-field expressions run at second 0
+    pub fields: Vec<CronField>,
+    pub timezone: String,
+    /// "next" or "previous"
+    pub direction: String,
+    pub from: String,
+    pub runs: Vec<CronRunInfo>,
+}
+
+/// One notation of a translated schedule
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ScheduleTranslation {
+    /// "cron", "rrule" or "english"
+    pub notation: String,
+    pub text: String,
+    /// Whether this runs at exactly the source's times
+    pub exact: bool,
+    /// What this notation cannot express of the source
+    pub notes: Vec<String>,
+    /// First occurrences, RFC 3339
+    pub samples: Vec<String>,
+    /// Whether the samples fall at the source's local times
+    pub samples_match: bool,
+}
+
+/// Response for ucm_schedule_translate
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ScheduleTranslateResponse {
+    pub input: String,
+    /// Notation of the input: "cron", "rrule" or "english"
+    pub source: String,
+    pub timezone: String,
+    /// Assumptions made reading the input
+    pub notes: Vec<String>,
+    /// The input's first occurrences, RFC 3339
+    pub samples: Vec<String>,
+    pub translations: Vec<ScheduleTranslation>,
+}
+
+/// An instant as a raw timestamp
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct TimestampValue {
+    /// e.g. "unix_ms", "filetime" or "excel"
+    pub unit: String,
+    /// Exact decimal value
+    pub value: String,
+    pub description: String,
+}
+
+/// Another reading of an ambiguous number
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct TimestampAlternative {
+    pub unit: String,
+    /// RFC 3339 in the requested zone
+    pub datetime: String,
+}
+
+/// Response for ucm_timestamp
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct TimestampResponse {
+    pub input: String,
+    /// Unit the input was read in, when it is a number
+    pub unit: Option<String>,
+    /// Whether the unit was chosen from the number's size
+    pub detected: bool,
+    /// RFC 3339 in the requested zone
+    pub datetime: String,
+    pub utc: String,
+    pub timezone: String,
+    /// The instant in every unit that can express it
+    pub timestamps: Vec<TimestampValue>,
+    /// Other units a detected number could be, each read as a date from 1900 to 2199
+    pub alternatives: Vec<TimestampAlternative>,
 }